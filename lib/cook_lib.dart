@@ -14,7 +14,7 @@ export 'src/audio_processor.dart';
 export 'src/video_processor.dart';
 
 // Rust 层（高级用户直接使用）
-export 'src/rust/api/audio.dart' show AudioRecognizer;
+export 'src/rust/api/audio.dart' show AudioRecognizer, validateModels;
 export 'src/rust/api/video.dart' show VideoFrameExtractor;
 
 // Video types
@@ -23,7 +23,15 @@ export 'src/rust/core/video/manager.dart' show YFrameData, FrameExtractedInfo, E
 // XHS API (小红书解析)
 export 'src/rust/api/xhs.dart';
 
+// Recipe importer (菜谱导入)
+export 'src/rust/api/importer.dart';
+
 // Models
+export 'src/rust/api/models/asr.dart';
+export 'src/rust/api/models/manifest.dart';
+export 'src/rust/api/models/recipe.dart';
+export 'src/rust/api/models/source.dart';
+export 'src/rust/api/models/transcript.dart';
 export 'src/rust/api/models/xhs.dart';
 
 // Error types
@@ -5,26 +5,76 @@
 
 import '../core/audio/error.dart';
 import '../frb_generated.dart';
+import 'models/asr.dart';
+import 'models/manifest.dart';
+import 'models/transcript.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `format_timestamp`, `transcribe_pcm`
+// These functions are ignored because they are not marked as `pub`: `awaiting_probe`, `check_manifest`, `finish`, `flush`, `format_timestamp`, `format_transcript`, `has_segments`, `keep_from`, `new`, `push`, `select_pack`, `speech`, `transcribe_pcm`, `transcribe_stream`, `validate_fallback`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PackSelection`, `Session`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
 
+/// 按 models_dir/models.json 校验模型文件
+///
+/// verify_checksums 为 true 时计算每个文件的 SHA-256（模型较大，耗时数秒）；
+/// 为 false 时只比较大小。models.json 不存在或格式错误时返回错误
+Future<ModelValidationReport> validateModels({
+  required String modelsDir,
+  required bool verifyChecksums,
+}) => RustLib.instance.api.crateApiAudioValidateModels(
+  modelsDir: modelsDir,
+  verifyChecksums: verifyChecksums,
+);
+
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>
 abstract class AudioRecognizer implements RustOpaqueInterface {
   /// 创建音频识别器并加载模型
   ///
   /// models_dir 下需要包含：
-  /// - sherpa-ncnn/ (ASR 模型)
+  /// - sherpa-ncnn/ (默认 ASR 模型包，可通过 config.model_packs 配置多个)
   /// - silero-vad/ (VAD 模型)
-  static Future<AudioRecognizer> create({required String modelsDir}) => RustLib
-      .instance
-      .api
-      .crateApiAudioAudioRecognizerCreate(modelsDir: modelsDir);
+  /// - models.json (可选，模型清单；存在时先按大小校验，提前发现缺失或被截断的文件)
+  ///
+  /// config 为 None 时使用默认配置（不启用热词）；非法参数在加载模型前报错
+  static Future<AudioRecognizer> create({
+    required String modelsDir,
+    AudioRecognizerConfig? config,
+  }) => RustLib.instance.api.crateApiAudioAudioRecognizerCreate(
+    modelsDir: modelsDir,
+    config: config,
+  );
 
   /// 获取模型目录
   String get modelsDir;
 
   /// 转录音频文件（WAV 格式）
+  ///
+  /// 每个语音片段一行：`HH:MM:SS:mm - HH:MM:SS:mm  --  文本`，启用说话人分离时文本前带 `[S1] ` 标签
   Future<String> transcribeAudio({required String path, String? language});
+
+  /// 转录音频文件，返回带逐字时间戳与置信度的结构化结果
+  ///
+  /// 文件按块解码、重采样和做 VAD，内存中只保留尚未转录完的音频窗口，峰值内存与时长无关
+  Future<Transcript> transcribeAudioDetailed({
+    required String path,
+    String? language,
+  });
+
+  /// 转录内存中的 PCM 数据，平台解码器无需先写临时 WAV
+  ///
+  /// samples 为交错排列的多声道样本（范围 [-1.0, 1.0]），多声道混为单声道，非 16kHz 时重采样
+  Future<Transcript> transcribeSamples({
+    required List<double> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  });
+
+  /// 同 [`Self::transcribe_samples`]，输入为 16 位整型 PCM
+  Future<Transcript> transcribeSamplesI16({
+    required List<int> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  });
 }
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/video/manager.dart';
+import '../frb_generated.dart';
+import 'models/recipe.dart';
+import 'models/xhs.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `report`, `video_file_name`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `from`
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>
+abstract class ImportError implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>
+abstract class RecipeImporter implements RustOpaqueInterface {
+  /// 创建导入器并加载音频模型
+  ///
+  /// work_dir 用于存放下载的视频与热词文件；client_config 为 None 时使用默认网络配置
+  static Future<RecipeImporter> create({
+    required String modelsDir,
+    required String workDir,
+    XhsClientConfig? clientConfig,
+  }) => RustLib.instance.api.crateApiImporterRecipeImporterCreate(
+    modelsDir: modelsDir,
+    workDir: workDir,
+    clientConfig: clientConfig,
+  );
+
+  /// 从分享文本导入菜谱
+  ///
+  /// - `decode_audio`: 视频路径 → 解码后的 WAV 路径
+  /// - `extract_frames`: 视频路径 → Y 平面帧列表（建议在 Dart 侧先按时间抽样）
+  /// - `on_progress`: 阶段进度回调
+  Future<ImportedRecipe> importFromText({
+    required String shareText,
+    String? language,
+    required FutureOr<String> Function(String) decodeAudio,
+    required FutureOr<List<YFrameData>> Function(String) extractFrames,
+    required FutureOr<void> Function(ImportProgress) onProgress,
+  });
+}
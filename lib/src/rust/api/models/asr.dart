@@ -0,0 +1,666 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'asr.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `all`, `as_str`, `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// ASR 解码配置，低端机可选贪心搜索、减少线程以节省电量
+class AsrConfig {
+  final DecodingMethod decodingMethod;
+
+  /// 束搜索保留的路径数（beam size），仅 ModifiedBeamSearch 生效
+  final int numActivePaths;
+
+  /// 推理线程数，0 表示自动（CPU 核数，最多 4）
+  final int numThreads;
+  final EndpointConfig endpoint;
+  final AsrModelFiles modelFiles;
+
+  const AsrConfig({
+    required this.decodingMethod,
+    required this.numActivePaths,
+    required this.numThreads,
+    required this.endpoint,
+    required this.modelFiles,
+  });
+
+  static Future<AsrConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrAsrConfigDefault();
+
+  @override
+  int get hashCode =>
+      decodingMethod.hashCode ^
+      numActivePaths.hashCode ^
+      numThreads.hashCode ^
+      endpoint.hashCode ^
+      modelFiles.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AsrConfig &&
+          runtimeType == other.runtimeType &&
+          decodingMethod == other.decodingMethod &&
+          numActivePaths == other.numActivePaths &&
+          numThreads == other.numThreads &&
+          endpoint == other.endpoint &&
+          modelFiles == other.modelFiles;
+}
+
+/// 模型目录下的文件名
+class AsrModelFiles {
+  final String encoderParam;
+  final String encoderBin;
+  final String decoderParam;
+  final String decoderBin;
+  final String joinerParam;
+  final String joinerBin;
+  final String tokens;
+
+  const AsrModelFiles({
+    required this.encoderParam,
+    required this.encoderBin,
+    required this.decoderParam,
+    required this.decoderBin,
+    required this.joinerParam,
+    required this.joinerBin,
+    required this.tokens,
+  });
+
+  static Future<AsrModelFiles> default_() =>
+      RustLib.instance.api.crateApiModelsAsrAsrModelFilesDefault();
+
+  @override
+  int get hashCode =>
+      encoderParam.hashCode ^
+      encoderBin.hashCode ^
+      decoderParam.hashCode ^
+      decoderBin.hashCode ^
+      joinerParam.hashCode ^
+      joinerBin.hashCode ^
+      tokens.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AsrModelFiles &&
+          runtimeType == other.runtimeType &&
+          encoderParam == other.encoderParam &&
+          encoderBin == other.encoderBin &&
+          decoderParam == other.decoderParam &&
+          decoderBin == other.decoderBin &&
+          joinerParam == other.joinerParam &&
+          joinerBin == other.joinerBin &&
+          tokens == other.tokens;
+}
+
+/// 音频识别器配置
+class AudioRecognizerConfig {
+  /// 热词配置，不为 None 时按各模型包的词表生成热词文件并启用热词加权
+  final HotwordConfig? hotwords;
+  final AsrConfig asr;
+
+  /// 模型包列表，第一个为默认包且必须存在；其余包目录不存在时跳过
+  final List<ModelPackConfig> modelPacks;
+
+  /// 识别器池配置，内存上限由所有模型包均分
+  final RecognizerPoolConfig pool;
+  final VadConfig vad;
+  final VadFallback vadFallback;
+
+  /// 说话人分离，不为 None 时为每个片段标注说话人
+  final DiarizationConfig? diarization;
+  final PostprocessConfig postprocess;
+
+  /// 非 16kHz 输入的重采样质量
+  final ResampleQuality resampleQuality;
+
+  /// 音频预处理，不为 None 时在 VAD 与 ASR 之前对 16kHz 音频执行
+  final PreprocessConfig? preprocess;
+
+  /// 声音事件检测，不为 None 时在非语音区间标注油爆、水开、提示音等事件
+  final EventDetectionConfig? events;
+
+  const AudioRecognizerConfig({
+    this.hotwords,
+    required this.asr,
+    required this.modelPacks,
+    required this.pool,
+    required this.vad,
+    required this.vadFallback,
+    this.diarization,
+    required this.postprocess,
+    required this.resampleQuality,
+    this.preprocess,
+    this.events,
+  });
+
+  static Future<AudioRecognizerConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrAudioRecognizerConfigDefault();
+
+  @override
+  int get hashCode =>
+      hotwords.hashCode ^
+      asr.hashCode ^
+      modelPacks.hashCode ^
+      pool.hashCode ^
+      vad.hashCode ^
+      vadFallback.hashCode ^
+      diarization.hashCode ^
+      postprocess.hashCode ^
+      resampleQuality.hashCode ^
+      preprocess.hashCode ^
+      events.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioRecognizerConfig &&
+          runtimeType == other.runtimeType &&
+          hotwords == other.hotwords &&
+          asr == other.asr &&
+          modelPacks == other.modelPacks &&
+          pool == other.pool &&
+          vad == other.vad &&
+          vadFallback == other.vadFallback &&
+          diarization == other.diarization &&
+          postprocess == other.postprocess &&
+          resampleQuality == other.resampleQuality &&
+          preprocess == other.preprocess &&
+          events == other.events;
+}
+
+/// 解码方式
+enum DecodingMethod {
+  /// 贪心搜索，最快、最省电
+  greedySearch,
+
+  /// 改进的束搜索，更准确，热词加权依赖该方式
+  modifiedBeamSearch,
+}
+
+/// 说话人分离配置
+class DiarizationConfig {
+  /// 最多区分的说话人数
+  final int maxSpeakers;
+
+  /// 聚类合并阈值（余弦距离，0.0 ~ 2.0），越小越容易分出新的说话人
+  final double threshold;
+
+  /// 短于该时长的片段声纹不可靠，不参与聚类，沿用时间上最近片段的说话人（毫秒）
+  final int minSegmentMs;
+
+  const DiarizationConfig({
+    required this.maxSpeakers,
+    required this.threshold,
+    required this.minSegmentMs,
+  });
+
+  static Future<DiarizationConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrDiarizationConfigDefault();
+
+  @override
+  int get hashCode =>
+      maxSpeakers.hashCode ^ threshold.hashCode ^ minSegmentMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DiarizationConfig &&
+          runtimeType == other.runtimeType &&
+          maxSpeakers == other.maxSpeakers &&
+          threshold == other.threshold &&
+          minSegmentMs == other.minSegmentMs;
+}
+
+/// 端点检测规则（秒）
+class EndpointConfig {
+  final bool enable;
+
+  /// 尚未识别出内容时，尾部静音超过该值即判定为端点
+  final double rule1MinTrailingSilence;
+
+  /// 已识别出内容后，尾部静音超过该值即判定为端点
+  final double rule2MinTrailingSilence;
+
+  /// 单句长度超过该值即强制断句
+  final double rule3MinUtteranceLength;
+
+  const EndpointConfig({
+    required this.enable,
+    required this.rule1MinTrailingSilence,
+    required this.rule2MinTrailingSilence,
+    required this.rule3MinUtteranceLength,
+  });
+
+  static Future<EndpointConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrEndpointConfigDefault();
+
+  @override
+  int get hashCode =>
+      enable.hashCode ^
+      rule1MinTrailingSilence.hashCode ^
+      rule2MinTrailingSilence.hashCode ^
+      rule3MinUtteranceLength.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EndpointConfig &&
+          runtimeType == other.runtimeType &&
+          enable == other.enable &&
+          rule1MinTrailingSilence == other.rule1MinTrailingSilence &&
+          rule2MinTrailingSilence == other.rule2MinTrailingSilence &&
+          rule3MinUtteranceLength == other.rule3MinUtteranceLength;
+}
+
+/// 声音事件检测配置
+class EventDetectionConfig {
+  /// 短于该时长的事件被丢弃（毫秒），检测以 512ms 为一块
+  final int minEventMs;
+
+  /// 置信度低于该值的块不标注事件
+  final double minConfidence;
+
+  const EventDetectionConfig({
+    required this.minEventMs,
+    required this.minConfidence,
+  });
+
+  static Future<EventDetectionConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrEventDetectionConfigDefault();
+
+  @override
+  int get hashCode => minEventMs.hashCode ^ minConfidence.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventDetectionConfig &&
+          runtimeType == other.runtimeType &&
+          minEventMs == other.minEventMs &&
+          minConfidence == other.minConfidence;
+}
+
+/// 热词，用于提升菜名、食材等专有词的识别率
+class Hotword {
+  final String phrase;
+
+  /// 加权分数，None 时使用 [`HotwordConfig::default_score`]
+  final double? score;
+
+  const Hotword({required this.phrase, this.score});
+
+  @override
+  int get hashCode => phrase.hashCode ^ score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Hotword &&
+          runtimeType == other.runtimeType &&
+          phrase == other.phrase &&
+          score == other.score;
+}
+
+/// 热词配置，启用后解码方式切换为 modified beam search
+class HotwordConfig {
+  /// 自定义热词，与内置词表重复时以自定义分数为准
+  final List<Hotword> hotwords;
+
+  /// 是否合并内置的烹饪词表（调料、食材、烹饪手法）
+  final bool useCookingLexicon;
+  final double defaultScore;
+
+  /// 热词文件的存放目录，需可写；None 时使用系统临时目录
+  ///
+  /// 每个识别器生成自己的热词文件，识别器释放时删除
+  final String? workDir;
+
+  const HotwordConfig({
+    required this.hotwords,
+    required this.useCookingLexicon,
+    required this.defaultScore,
+    this.workDir,
+  });
+
+  static Future<HotwordConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrHotwordConfigDefault();
+
+  @override
+  int get hashCode =>
+      hotwords.hashCode ^
+      useCookingLexicon.hashCode ^
+      defaultScore.hashCode ^
+      workDir.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HotwordConfig &&
+          runtimeType == other.runtimeType &&
+          hotwords == other.hotwords &&
+          useCookingLexicon == other.useCookingLexicon &&
+          defaultScore == other.defaultScore &&
+          workDir == other.workDir;
+}
+
+/// 响度归一化配置，按最近一段时间的响度平滑调整增益，静音不会被放大
+class LoudnessConfig {
+  final LoudnessMeasure measure;
+
+  /// 目标响度（dBFS 或 LUFS）
+  final double targetDb;
+
+  /// 最大增益（dB），避免把远处的微弱声音放大成噪声
+  final double maxGainDb;
+
+  /// 响度测量窗口（毫秒）
+  final int windowMs;
+
+  const LoudnessConfig({
+    required this.measure,
+    required this.targetDb,
+    required this.maxGainDb,
+    required this.windowMs,
+  });
+
+  static Future<LoudnessConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrLoudnessConfigDefault();
+
+  @override
+  int get hashCode =>
+      measure.hashCode ^
+      targetDb.hashCode ^
+      maxGainDb.hashCode ^
+      windowMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoudnessConfig &&
+          runtimeType == other.runtimeType &&
+          measure == other.measure &&
+          targetDb == other.targetDb &&
+          maxGainDb == other.maxGainDb &&
+          windowMs == other.windowMs;
+}
+
+/// 响度测量方式
+enum LoudnessMeasure {
+  /// 直接计算均方根电平（dBFS）
+  rms,
+
+  /// ITU-R BS.1770 K 加权响度（LUFS），更接近人耳感受
+  lufs,
+}
+
+/// ASR 模型包，对应 models_dir 下的一个子目录
+///
+/// 各模型包共用 [`AsrConfig`]（包括模型文件名）
+class ModelPackConfig {
+  /// 子目录名，如 `sherpa-ncnn`、`sherpa-ncnn-yue`
+  final String dir;
+
+  /// 支持的语言代码，如 `zh`、`en`、`yue`
+  final List<String> languages;
+
+  const ModelPackConfig({required this.dir, required this.languages});
+
+  @override
+  int get hashCode => dir.hashCode ^ languages.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModelPackConfig &&
+          runtimeType == other.runtimeType &&
+          dir == other.dir &&
+          languages == other.languages;
+}
+
+/// 谱减降噪配置，噪声谱持续跟踪，适合抽油烟机等平稳噪声
+class NoiseReductionConfig {
+  /// 过减因子，越大降噪越狠，语音失真也越明显
+  final double overSubtraction;
+
+  /// 每个频点的最小增益（dB），保留少量底噪以减轻“音乐噪声”
+  final double floorDb;
+
+  const NoiseReductionConfig({
+    required this.overSubtraction,
+    required this.floorDb,
+  });
+
+  static Future<NoiseReductionConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrNoiseReductionConfigDefault();
+
+  @override
+  int get hashCode => overSubtraction.hashCode ^ floorDb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoiseReductionConfig &&
+          runtimeType == other.runtimeType &&
+          overSubtraction == other.overSubtraction &&
+          floorDb == other.floorDb;
+}
+
+/// 识别结果后处理，按 标点 → 中英混排 → 数字规整 的顺序对每个片段执行，每一步单独开启
+///
+/// 默认全部关闭，输出与识别器原文一致。只修改片段的 `text`，`tokens` 保留识别器的原始输出
+class PostprocessConfig {
+  final PunctuationMode punctuation;
+
+  /// 模型输出的全大写英文转小写（热词中的缩写保持大写），规范中英文之间的空格
+  final bool mixedEnglish;
+
+  /// 中文数字与单位、时间转写为阿拉伯数字（三百克 → 300克，八点十五分 → 8:15）
+  final bool inverseTextNormalization;
+
+  const PostprocessConfig({
+    required this.punctuation,
+    required this.mixedEnglish,
+    required this.inverseTextNormalization,
+  });
+
+  static Future<PostprocessConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrPostprocessConfigDefault();
+
+  @override
+  int get hashCode =>
+      punctuation.hashCode ^
+      mixedEnglish.hashCode ^
+      inverseTextNormalization.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PostprocessConfig &&
+          runtimeType == other.runtimeType &&
+          punctuation == other.punctuation &&
+          mixedEnglish == other.mixedEnglish &&
+          inverseTextNormalization == other.inverseTextNormalization;
+}
+
+/// 音频预处理，按 去直流 → 高通 → 降噪 → 响度归一化 的顺序在 VAD 与 ASR 之前执行，每一步可单独关闭
+class PreprocessConfig {
+  final bool removeDc;
+
+  /// 高通截止频率（Hz），滤掉抽油烟机、冰箱等低频嗡声，0 表示关闭
+  final int highPassHz;
+  final NoiseReductionConfig? noiseReduction;
+  final LoudnessConfig? loudness;
+
+  const PreprocessConfig({
+    required this.removeDc,
+    required this.highPassHz,
+    this.noiseReduction,
+    this.loudness,
+  });
+
+  static Future<PreprocessConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrPreprocessConfigDefault();
+
+  @override
+  int get hashCode =>
+      removeDc.hashCode ^
+      highPassHz.hashCode ^
+      noiseReduction.hashCode ^
+      loudness.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PreprocessConfig &&
+          runtimeType == other.runtimeType &&
+          removeDc == other.removeDc &&
+          highPassHz == other.highPassHz &&
+          noiseReduction == other.noiseReduction &&
+          loudness == other.loudness;
+}
+
+/// 标点恢复方式
+@freezed
+sealed class PunctuationMode with _$PunctuationMode {
+  const factory PunctuationMode.off() = PunctuationMode_Off;
+  /// 按 token 间的停顿加逗号，句末加句号或问号
+  const factory PunctuationMode.rules({required int pauseMs}) =
+      PunctuationMode_Rules;
+}
+
+/// 识别器池配置，多个识别器实例并行转录 VAD 片段
+///
+/// 每个实例都会完整加载一份模型，实例数同时受 CPU 核数与内存上限约束，至少加载 1 个
+class RecognizerPoolConfig {
+  /// 实例数上限，0 表示自动（CPU 核数的一半，最多 4）
+  final int maxInstances;
+
+  /// 所有实例的内存上限（MB），按模型文件大小估算，0 表示不限制
+  final int maxMemoryMb;
+
+  const RecognizerPoolConfig({
+    required this.maxInstances,
+    required this.maxMemoryMb,
+  });
+
+  static Future<RecognizerPoolConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrRecognizerPoolConfigDefault();
+
+  @override
+  int get hashCode => maxInstances.hashCode ^ maxMemoryMb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecognizerPoolConfig &&
+          runtimeType == other.runtimeType &&
+          maxInstances == other.maxInstances &&
+          maxMemoryMb == other.maxMemoryMb;
+}
+
+/// 重采样到 16kHz 的质量档位，档位越高滤波器越长、混叠越少、越耗电
+enum ResampleQuality {
+  /// 阻带衰减约 60dB，适合低端设备
+  fast,
+
+  /// 阻带衰减约 80dB
+  balanced,
+
+  /// 阻带衰减约 100dB，过渡带最窄
+  high;
+
+  static Future<ResampleQuality> default_() =>
+      RustLib.instance.api.crateApiModelsAsrResampleQualityDefault();
+}
+
+/// Silero VAD 参数与片段后处理
+class VadConfig {
+  /// 语音概率阈值，0.0 ~ 1.0，越大越严格
+  final double threshold;
+
+  /// 静音超过该时长才切分片段（毫秒）
+  final int minSilenceDurationMs;
+
+  /// 短于该时长的语音被丢弃（毫秒）
+  final int minSpeechDurationMs;
+  final int numThreads;
+
+  /// VAD 内部缓冲区长度（秒）
+  final double bufferSizeSecs;
+
+  /// 片段前后各外扩的时长（毫秒），避免切掉首尾的字
+  final int padMs;
+
+  /// 间隔短于该时长的相邻片段合并（毫秒），0 表示不合并
+  final int mergeGapMs;
+
+  /// 长于该时长的片段被均分（毫秒），0 表示不切分；过长的片段会明显降低识别质量
+  final int maxSegmentMs;
+
+  const VadConfig({
+    required this.threshold,
+    required this.minSilenceDurationMs,
+    required this.minSpeechDurationMs,
+    required this.numThreads,
+    required this.bufferSizeSecs,
+    required this.padMs,
+    required this.mergeGapMs,
+    required this.maxSegmentMs,
+  });
+
+  static Future<VadConfig> default_() =>
+      RustLib.instance.api.crateApiModelsAsrVadConfigDefault();
+
+  @override
+  int get hashCode =>
+      threshold.hashCode ^
+      minSilenceDurationMs.hashCode ^
+      minSpeechDurationMs.hashCode ^
+      numThreads.hashCode ^
+      bufferSizeSecs.hashCode ^
+      padMs.hashCode ^
+      mergeGapMs.hashCode ^
+      maxSegmentMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VadConfig &&
+          runtimeType == other.runtimeType &&
+          threshold == other.threshold &&
+          minSilenceDurationMs == other.minSilenceDurationMs &&
+          minSpeechDurationMs == other.minSpeechDurationMs &&
+          numThreads == other.numThreads &&
+          bufferSizeSecs == other.bufferSizeSecs &&
+          padMs == other.padMs &&
+          mergeGapMs == other.mergeGapMs &&
+          maxSegmentMs == other.maxSegmentMs;
+}
+
+/// VAD 失败或未检测到语音时的处理方式
+@freezed
+sealed class VadFallback with _$VadFallback {
+  const VadFallback._();
+
+  /// 直接报错
+  const factory VadFallback.error() = VadFallback_Error;
+  /// 按固定窗口切分整段音频，相邻窗口重叠以免切断字词（重叠处可能重复少量文字）
+  const factory VadFallback.fixedWindow({
+    required int windowMs,
+    required int overlapMs,
+  }) = VadFallback_FixedWindow;
+  /// 整段音频作为一个片段识别，长音频会很慢且质量明显下降
+  const factory VadFallback.wholeFile() = VadFallback_WholeFile;
+
+  static Future<VadFallback> default_() =>
+      RustLib.instance.api.crateApiModelsAsrVadFallbackDefault();
+}
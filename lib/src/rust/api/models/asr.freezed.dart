@@ -0,0 +1,294 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'asr.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$PunctuationMode {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PunctuationMode);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PunctuationMode()';
+}
+
+
+}
+
+/// @nodoc
+class $PunctuationModeCopyWith<$Res>  {
+$PunctuationModeCopyWith(PunctuationMode _, $Res Function(PunctuationMode) __);
+}
+
+/// @nodoc
+
+
+class PunctuationMode_Off implements PunctuationMode {
+  const PunctuationMode_Off();
+  
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PunctuationMode_Off);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PunctuationMode.off()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class PunctuationMode_Rules implements PunctuationMode {
+  const PunctuationMode_Rules({required this.pauseMs});
+  
+
+/// 相邻 token 起始时间间隔超过该值时加逗号（毫秒）
+ final  int pauseMs;
+
+/// Create a copy of PunctuationMode
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PunctuationMode_RulesCopyWith<PunctuationMode_Rules> get copyWith => _$PunctuationMode_RulesCopyWithImpl<PunctuationMode_Rules>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PunctuationMode_Rules&&(identical(other.pauseMs, pauseMs) || other.pauseMs == pauseMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,pauseMs);
+
+@override
+String toString() {
+  return 'PunctuationMode.rules(pauseMs: $pauseMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PunctuationMode_RulesCopyWith<$Res> implements $PunctuationModeCopyWith<$Res> {
+  factory $PunctuationMode_RulesCopyWith(PunctuationMode_Rules value, $Res Function(PunctuationMode_Rules) _then) = _$PunctuationMode_RulesCopyWithImpl;
+@useResult
+$Res call({
+ int pauseMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$PunctuationMode_RulesCopyWithImpl<$Res>
+    implements $PunctuationMode_RulesCopyWith<$Res> {
+  _$PunctuationMode_RulesCopyWithImpl(this._self, this._then);
+
+  final PunctuationMode_Rules _self;
+  final $Res Function(PunctuationMode_Rules) _then;
+
+/// Create a copy of PunctuationMode
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? pauseMs = null,}) {
+  return _then(PunctuationMode_Rules(
+pauseMs: null == pauseMs ? _self.pauseMs : pauseMs // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$VadFallback {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VadFallback);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'VadFallback()';
+}
+
+
+}
+
+/// @nodoc
+class $VadFallbackCopyWith<$Res>  {
+$VadFallbackCopyWith(VadFallback _, $Res Function(VadFallback) __);
+}
+
+/// @nodoc
+
+
+class VadFallback_Error extends VadFallback {
+  const VadFallback_Error(): super._();
+  
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VadFallback_Error);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'VadFallback.error()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class VadFallback_FixedWindow extends VadFallback {
+  const VadFallback_FixedWindow({required this.windowMs, required this.overlapMs}): super._();
+  
+
+ final  int windowMs;
+ final  int overlapMs;
+
+/// Create a copy of VadFallback
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$VadFallback_FixedWindowCopyWith<VadFallback_FixedWindow> get copyWith => _$VadFallback_FixedWindowCopyWithImpl<VadFallback_FixedWindow>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VadFallback_FixedWindow&&(identical(other.windowMs, windowMs) || other.windowMs == windowMs)&&(identical(other.overlapMs, overlapMs) || other.overlapMs == overlapMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,windowMs,overlapMs);
+
+@override
+String toString() {
+  return 'VadFallback.fixedWindow(windowMs: $windowMs, overlapMs: $overlapMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $VadFallback_FixedWindowCopyWith<$Res> implements $VadFallbackCopyWith<$Res> {
+  factory $VadFallback_FixedWindowCopyWith(VadFallback_FixedWindow value, $Res Function(VadFallback_FixedWindow) _then) = _$VadFallback_FixedWindowCopyWithImpl;
+@useResult
+$Res call({
+ int windowMs,
+ int overlapMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$VadFallback_FixedWindowCopyWithImpl<$Res>
+    implements $VadFallback_FixedWindowCopyWith<$Res> {
+  _$VadFallback_FixedWindowCopyWithImpl(this._self, this._then);
+
+  final VadFallback_FixedWindow _self;
+  final $Res Function(VadFallback_FixedWindow) _then;
+
+/// Create a copy of VadFallback
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? windowMs = null,Object? overlapMs = null,}) {
+  return _then(VadFallback_FixedWindow(
+windowMs: null == windowMs ? _self.windowMs : windowMs // ignore: cast_nullable_to_non_nullable
+as int,
+overlapMs: null == overlapMs ? _self.overlapMs : overlapMs // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class VadFallback_WholeFile extends VadFallback {
+  const VadFallback_WholeFile(): super._();
+  
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is VadFallback_WholeFile);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'VadFallback.wholeFile()';
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,86 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'manifest.freezed.dart';
+
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ModelFileEntry`, `ModelManifest`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+class ModelFileIssue {
+  final String path;
+  final ModelIssueKind kind;
+
+  const ModelFileIssue({required this.path, required this.kind});
+
+  @override
+  int get hashCode => path.hashCode ^ kind.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModelFileIssue &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          kind == other.kind;
+}
+
+/// 单个文件的校验问题
+@freezed
+sealed class ModelIssueKind with _$ModelIssueKind {
+  /// 文件不存在
+  const factory ModelIssueKind.missing() = ModelIssueKind_Missing;
+  /// 路径为绝对路径或跳出了 models_dir
+  const factory ModelIssueKind.invalidPath() = ModelIssueKind_InvalidPath;
+  /// 大小不符，通常是下载被截断
+  const factory ModelIssueKind.sizeMismatch({
+    required BigInt expected,
+    required BigInt actual,
+  }) = ModelIssueKind_SizeMismatch;
+  /// 大小一致但内容不符
+  const factory ModelIssueKind.checksumMismatch({
+    required String expected,
+    required String actual,
+  }) = ModelIssueKind_ChecksumMismatch;
+}
+
+/// 模型校验结果
+class ModelValidationReport {
+  /// 清单中的文件数
+  final int checked;
+
+  /// 是否计算了 SHA-256；为 false 时只比较了大小
+  final bool checksumsVerified;
+  final List<ModelFileIssue> issues;
+
+  const ModelValidationReport({
+    required this.checked,
+    required this.checksumsVerified,
+    required this.issues,
+  });
+
+  static Future<ModelValidationReport> default_() =>
+      RustLib.instance.api.crateApiModelsManifestModelValidationReportDefault();
+
+  Future<bool> isValid() => RustLib
+      .instance
+      .api
+      .crateApiModelsManifestModelValidationReportIsValid(that: this);
+
+  @override
+  int get hashCode =>
+      checked.hashCode ^ checksumsVerified.hashCode ^ issues.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModelValidationReport &&
+          runtimeType == other.runtimeType &&
+          checked == other.checked &&
+          checksumsVerified == other.checksumsVerified &&
+          issues == other.issues;
+}
@@ -0,0 +1,240 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'manifest.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ModelIssueKind {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModelIssueKind);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ModelIssueKind()';
+}
+
+
+}
+
+/// @nodoc
+class $ModelIssueKindCopyWith<$Res>  {
+$ModelIssueKindCopyWith(ModelIssueKind _, $Res Function(ModelIssueKind) __);
+}
+
+/// @nodoc
+
+
+class ModelIssueKind_Missing implements ModelIssueKind {
+  const ModelIssueKind_Missing();
+  
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModelIssueKind_Missing);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ModelIssueKind.missing()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModelIssueKind_InvalidPath implements ModelIssueKind {
+  const ModelIssueKind_InvalidPath();
+  
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModelIssueKind_InvalidPath);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ModelIssueKind.invalidPath()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModelIssueKind_SizeMismatch implements ModelIssueKind {
+  const ModelIssueKind_SizeMismatch({required this.expected, required this.actual});
+  
+
+ final  BigInt expected;
+ final  BigInt actual;
+
+/// Create a copy of ModelIssueKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModelIssueKind_SizeMismatchCopyWith<ModelIssueKind_SizeMismatch> get copyWith => _$ModelIssueKind_SizeMismatchCopyWithImpl<ModelIssueKind_SizeMismatch>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModelIssueKind_SizeMismatch&&(identical(other.expected, expected) || other.expected == expected)&&(identical(other.actual, actual) || other.actual == actual));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,expected,actual);
+
+@override
+String toString() {
+  return 'ModelIssueKind.sizeMismatch(expected: $expected, actual: $actual)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModelIssueKind_SizeMismatchCopyWith<$Res> implements $ModelIssueKindCopyWith<$Res> {
+  factory $ModelIssueKind_SizeMismatchCopyWith(ModelIssueKind_SizeMismatch value, $Res Function(ModelIssueKind_SizeMismatch) _then) = _$ModelIssueKind_SizeMismatchCopyWithImpl;
+@useResult
+$Res call({
+ BigInt expected,
+ BigInt actual
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModelIssueKind_SizeMismatchCopyWithImpl<$Res>
+    implements $ModelIssueKind_SizeMismatchCopyWith<$Res> {
+  _$ModelIssueKind_SizeMismatchCopyWithImpl(this._self, this._then);
+
+  final ModelIssueKind_SizeMismatch _self;
+  final $Res Function(ModelIssueKind_SizeMismatch) _then;
+
+/// Create a copy of ModelIssueKind
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? expected = null,Object? actual = null,}) {
+  return _then(ModelIssueKind_SizeMismatch(
+expected: null == expected ? _self.expected : expected // ignore: cast_nullable_to_non_nullable
+as BigInt,
+actual: null == actual ? _self.actual : actual // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ModelIssueKind_ChecksumMismatch implements ModelIssueKind {
+  const ModelIssueKind_ChecksumMismatch({required this.expected, required this.actual});
+  
+
+ final  String expected;
+ final  String actual;
+
+/// Create a copy of ModelIssueKind
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ModelIssueKind_ChecksumMismatchCopyWith<ModelIssueKind_ChecksumMismatch> get copyWith => _$ModelIssueKind_ChecksumMismatchCopyWithImpl<ModelIssueKind_ChecksumMismatch>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ModelIssueKind_ChecksumMismatch&&(identical(other.expected, expected) || other.expected == expected)&&(identical(other.actual, actual) || other.actual == actual));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,expected,actual);
+
+@override
+String toString() {
+  return 'ModelIssueKind.checksumMismatch(expected: $expected, actual: $actual)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ModelIssueKind_ChecksumMismatchCopyWith<$Res> implements $ModelIssueKindCopyWith<$Res> {
+  factory $ModelIssueKind_ChecksumMismatchCopyWith(ModelIssueKind_ChecksumMismatch value, $Res Function(ModelIssueKind_ChecksumMismatch) _then) = _$ModelIssueKind_ChecksumMismatchCopyWithImpl;
+@useResult
+$Res call({
+ String expected,
+ String actual
+});
+
+
+
+
+}
+/// @nodoc
+class _$ModelIssueKind_ChecksumMismatchCopyWithImpl<$Res>
+    implements $ModelIssueKind_ChecksumMismatchCopyWith<$Res> {
+  _$ModelIssueKind_ChecksumMismatchCopyWithImpl(this._self, this._then);
+
+  final ModelIssueKind_ChecksumMismatch _self;
+  final $Res Function(ModelIssueKind_ChecksumMismatch) _then;
+
+/// Create a copy of ModelIssueKind
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? expected = null,Object? actual = null,}) {
+  return _then(ModelIssueKind_ChecksumMismatch(
+expected: null == expected ? _self.expected : expected // ignore: cast_nullable_to_non_nullable
+as String,
+actual: null == actual ? _self.actual : actual // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
@@ -0,0 +1,148 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../core/video/manager.dart';
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'source.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 导入进度
+class ImportProgress {
+  final ImportStage stage;
+  final String message;
+
+  const ImportProgress({required this.stage, required this.message});
+
+  @override
+  int get hashCode => stage.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportProgress &&
+          runtimeType == other.runtimeType &&
+          stage == other.stage &&
+          message == other.message;
+}
+
+/// 导入流程阶段
+enum ImportStage {
+  /// 解析分享链接并获取笔记或菜谱页面
+  fetchingNote,
+
+  /// 下载笔记视频
+  downloadingVideo,
+
+  /// 原生解码音轨
+  decodingAudio,
+
+  /// 语音转写
+  transcribing,
+
+  /// 关键帧提取
+  extractingFrames,
+
+  /// 解析菜谱结构
+  parsingRecipe,
+
+  /// 完成
+  complete,
+}
+
+/// 一次导入的完整结果
+class ImportedRecipe {
+  /// 规范化后的分享链接
+  final String sourceUrl;
+  final SourceArticle article;
+
+  /// 语音转写文本，没有视频时为 None
+  final String? transcript;
+  final List<FrameExtractedInfo> keyframes;
+
+  /// 平台自带结构化菜谱时直接使用，否则从正文与转写中解析
+  final ParsedRecipe recipe;
+
+  const ImportedRecipe({
+    required this.sourceUrl,
+    required this.article,
+    this.transcript,
+    required this.keyframes,
+    required this.recipe,
+  });
+
+  @override
+  int get hashCode =>
+      sourceUrl.hashCode ^
+      article.hashCode ^
+      transcript.hashCode ^
+      keyframes.hashCode ^
+      recipe.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportedRecipe &&
+          runtimeType == other.runtimeType &&
+          sourceUrl == other.sourceUrl &&
+          article == other.article &&
+          transcript == other.transcript &&
+          keyframes == other.keyframes &&
+          recipe == other.recipe;
+}
+
+/// 从笔记文本中解析出的菜谱结构
+class ParsedRecipe {
+  final String title;
+  final List<RecipeIngredient> ingredients;
+  final List<String> steps;
+  final List<String> tips;
+
+  const ParsedRecipe({
+    required this.title,
+    required this.ingredients,
+    required this.steps,
+    required this.tips,
+  });
+
+  static Future<ParsedRecipe> default_() =>
+      RustLib.instance.api.crateApiModelsRecipeParsedRecipeDefault();
+
+  @override
+  int get hashCode =>
+      title.hashCode ^ ingredients.hashCode ^ steps.hashCode ^ tips.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ParsedRecipe &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          ingredients == other.ingredients &&
+          steps == other.steps &&
+          tips == other.tips;
+}
+
+/// 食材条目
+class RecipeIngredient {
+  final String name;
+
+  /// 用量，如 "300克"、"适量"
+  final String? amount;
+
+  const RecipeIngredient({required this.name, this.amount});
+
+  @override
+  int get hashCode => name.hashCode ^ amount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecipeIngredient &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          amount == other.amount;
+}
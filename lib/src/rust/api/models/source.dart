@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'recipe.dart';
+import 'xhs.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+
+/// 内容来源平台
+enum Platform {
+  /// 小红书
+  xiaohongshu,
+
+  /// 下厨房
+  xiachufang,
+}
+
+/// 平台无关的内容模型
+class SourceArticle {
+  final Platform platform;
+  final String title;
+  final String desc;
+  final SourceAuthor author;
+  final List<String> images;
+  final SourceVideo? video;
+  final NoteType noteType;
+
+  /// 平台自带的结构化菜谱（如下厨房的用料与步骤），没有时为 None
+  final ParsedRecipe? recipe;
+
+  const SourceArticle({
+    required this.platform,
+    required this.title,
+    required this.desc,
+    required this.author,
+    required this.images,
+    this.video,
+    required this.noteType,
+    this.recipe,
+  });
+
+  @override
+  int get hashCode =>
+      platform.hashCode ^
+      title.hashCode ^
+      desc.hashCode ^
+      author.hashCode ^
+      images.hashCode ^
+      video.hashCode ^
+      noteType.hashCode ^
+      recipe.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SourceArticle &&
+          runtimeType == other.runtimeType &&
+          platform == other.platform &&
+          title == other.title &&
+          desc == other.desc &&
+          author == other.author &&
+          images == other.images &&
+          video == other.video &&
+          noteType == other.noteType &&
+          recipe == other.recipe;
+}
+
+class SourceAuthor {
+  final String name;
+  final String id;
+  final String avatar;
+
+  const SourceAuthor({
+    required this.name,
+    required this.id,
+    required this.avatar,
+  });
+
+  static Future<SourceAuthor> default_() =>
+      RustLib.instance.api.crateApiModelsSourceSourceAuthorDefault();
+
+  @override
+  int get hashCode => name.hashCode ^ id.hashCode ^ avatar.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SourceAuthor &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          id == other.id &&
+          avatar == other.avatar;
+}
+
+class SourceVideo {
+  /// 时长（秒）
+  final PlatformInt64 duration;
+  final String cover;
+  final String playUrl;
+
+  const SourceVideo({
+    required this.duration,
+    required this.cover,
+    required this.playUrl,
+  });
+
+  @override
+  int get hashCode => duration.hashCode ^ cover.hashCode ^ playUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SourceVideo &&
+          runtimeType == other.runtimeType &&
+          duration == other.duration &&
+          cover == other.cover &&
+          playUrl == other.playUrl;
+}
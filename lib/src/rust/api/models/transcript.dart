@@ -0,0 +1,194 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 非语音区间内检测到的声音事件
+class AudioEvent {
+  final AudioEventKind kind;
+  final BigInt startMs;
+  final BigInt endMs;
+
+  /// 置信度，0.0 ~ 1.0
+  final double confidence;
+
+  const AudioEvent({
+    required this.kind,
+    required this.startMs,
+    required this.endMs,
+    required this.confidence,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ startMs.hashCode ^ endMs.hashCode ^ confidence.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AudioEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          confidence == other.confidence;
+}
+
+/// 烹饪相关的非语音声音
+enum AudioEventKind {
+  /// 热油下锅的滋滋声
+  sizzle,
+
+  /// 水开后翻滚的咕嘟声
+  boiling,
+
+  /// 定时器、烤箱的提示音
+  timerBeep,
+
+  /// 料理机、破壁机等电机噪声
+  blender,
+}
+
+/// 语音片段的切分方式
+enum SegmentationPath {
+  /// VAD 检测到的语音片段
+  vad,
+
+  /// VAD 不可用，按固定窗口切分
+  fixedWindow,
+
+  /// VAD 不可用，整段识别
+  wholeFile;
+
+  static Future<SegmentationPath> default_() =>
+      RustLib.instance.api.crateApiModelsTranscriptSegmentationPathDefault();
+}
+
+/// 整段音频的识别结果
+class Transcript {
+  final List<TranscriptSegment> segments;
+  final SegmentationPath segmentation;
+
+  /// 没有走 VAD 时的原因（VAD 出错或未检测到语音）
+  final String? fallbackReason;
+
+  /// 实际使用的模型包目录
+  final String modelPack;
+
+  /// 规范化后的语言代码：调用方指定的语言，或语言识别的结果
+  final String? language;
+
+  /// 语言是否由语言识别得出
+  final bool languageDetected;
+
+  /// 非语音区间的声音事件（按时间排序），未启用事件检测时为空
+  final List<AudioEvent> events;
+
+  const Transcript({
+    required this.segments,
+    required this.segmentation,
+    this.fallbackReason,
+    required this.modelPack,
+    this.language,
+    required this.languageDetected,
+    required this.events,
+  });
+
+  static Future<Transcript> default_() =>
+      RustLib.instance.api.crateApiModelsTranscriptTranscriptDefault();
+
+  @override
+  int get hashCode =>
+      segments.hashCode ^
+      segmentation.hashCode ^
+      fallbackReason.hashCode ^
+      modelPack.hashCode ^
+      language.hashCode ^
+      languageDetected.hashCode ^
+      events.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Transcript &&
+          runtimeType == other.runtimeType &&
+          segments == other.segments &&
+          segmentation == other.segmentation &&
+          fallbackReason == other.fallbackReason &&
+          modelPack == other.modelPack &&
+          language == other.language &&
+          languageDetected == other.languageDetected &&
+          events == other.events;
+}
+
+/// 一个语音片段的识别结果
+class TranscriptSegment {
+  final BigInt startMs;
+  final BigInt endMs;
+  final String text;
+  final List<TranscriptToken> tokens;
+
+  /// 说话人编号（从 0 开始），未启用说话人分离时为 None
+  final int? speaker;
+
+  const TranscriptSegment({
+    required this.startMs,
+    required this.endMs,
+    required this.text,
+    required this.tokens,
+    this.speaker,
+  });
+
+  @override
+  int get hashCode =>
+      startMs.hashCode ^
+      endMs.hashCode ^
+      text.hashCode ^
+      tokens.hashCode ^
+      speaker.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TranscriptSegment &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          text == other.text &&
+          tokens == other.tokens &&
+          speaker == other.speaker;
+}
+
+/// 带时间戳的识别单元（中文通常为单字，英文为 BPE 子词）
+class TranscriptToken {
+  final String token;
+
+  /// 相对整段音频起点的毫秒数
+  final BigInt startMs;
+
+  /// 置信度，0.0 ~ 1.0；识别器没有输出 token 分数时为 None
+  final double? confidence;
+
+  const TranscriptToken({
+    required this.token,
+    required this.startMs,
+    this.confidence,
+  });
+
+  @override
+  int get hashCode => token.hashCode ^ startMs.hashCode ^ confidence.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TranscriptToken &&
+          runtimeType == other.runtimeType &&
+          token == other.token &&
+          startMs == other.startMs &&
+          confidence == other.confidence;
+}
@@ -6,8 +6,9 @@
 import '../../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `from_media`, `note_id`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ImageItem`, `NoteDetail`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 /// 笔记类型枚举
 enum NoteType {
@@ -92,6 +93,298 @@ class XhsAuthor {
           avatar == other.avatar;
 }
 
+/// 作者主页信息
+class XhsAuthorProfile {
+  final XhsAuthor author;
+
+  /// 小红书号
+  final String redId;
+
+  /// 个人简介
+  final String bio;
+  final String? ipLocation;
+
+  /// 关注数
+  final BigInt follows;
+
+  /// 粉丝数
+  final BigInt fans;
+
+  /// 获赞与收藏数
+  final BigInt likedAndCollected;
+
+  /// 主页首屏的笔记列表，后续分页不会自动抓取
+  final XhsNotePage notes;
+
+  const XhsAuthorProfile({
+    required this.author,
+    required this.redId,
+    required this.bio,
+    this.ipLocation,
+    required this.follows,
+    required this.fans,
+    required this.likedAndCollected,
+    required this.notes,
+  });
+
+  @override
+  int get hashCode =>
+      author.hashCode ^
+      redId.hashCode ^
+      bio.hashCode ^
+      ipLocation.hashCode ^
+      follows.hashCode ^
+      fans.hashCode ^
+      likedAndCollected.hashCode ^
+      notes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XhsAuthorProfile &&
+          runtimeType == other.runtimeType &&
+          author == other.author &&
+          redId == other.redId &&
+          bio == other.bio &&
+          ipLocation == other.ipLocation &&
+          follows == other.follows &&
+          fans == other.fans &&
+          likedAndCollected == other.likedAndCollected &&
+          notes == other.notes;
+}
+
+/// 笔记磁盘缓存配置，以规范化的笔记 id 为键
+class XhsCacheConfig {
+  /// 缓存目录，不存在时自动创建
+  final String cacheDir;
+
+  /// 缓存有效期（秒），0 表示永不过期
+  final BigInt ttlSecs;
+
+  /// 缓存目录总大小上限（字节），超出时按写入时间淘汰最旧的条目，0 表示不限制
+  final BigInt maxSizeBytes;
+
+  /// 跳过缓存读取，总是重新抓取（结果仍会写回缓存）
+  final bool bypass;
+
+  const XhsCacheConfig({
+    required this.cacheDir,
+    required this.ttlSecs,
+    required this.maxSizeBytes,
+    required this.bypass,
+  });
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+  static Future<XhsCacheConfig> newInstance({required String cacheDir}) =>
+      RustLib.instance.api.crateApiModelsXhsXhsCacheConfigNew(
+        cacheDir: cacheDir,
+      );
+
+  @override
+  int get hashCode =>
+      cacheDir.hashCode ^
+      ttlSecs.hashCode ^
+      maxSizeBytes.hashCode ^
+      bypass.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XhsCacheConfig &&
+          runtimeType == other.runtimeType &&
+          cacheDir == other.cacheDir &&
+          ttlSecs == other.ttlSecs &&
+          maxSizeBytes == other.maxSizeBytes &&
+          bypass == other.bypass;
+}
+
+/// 小红书 HTTP 客户端配置
+class XhsClientConfig {
+  /// 连接超时（毫秒）
+  final BigInt connectTimeoutMs;
+
+  /// 读取超时（毫秒）；阻塞客户端没有单独的读取超时，作为整体请求超时使用
+  final BigInt readTimeoutMs;
+
+  /// 代理地址，如 `http://127.0.0.1:7890`
+  final String? proxy;
+
+  /// 是否保存 Cookie，短链跳转时部分 Cookie 是后续请求所必需的
+  final bool cookieStore;
+
+  /// 初始 Cookie，格式同 `Set-Cookie`，如 `web_session=xxx`；未指定 Domain 时对所有
+  /// xiaohongshu.com 子域生效。不为空时总会启用 Cookie 存储
+  final List<String> cookies;
+
+  /// 最多跟随的跳转次数，0 表示不跟随
+  final int maxRedirects;
+
+  /// 笔记磁盘缓存，None 表示不缓存
+  final XhsCacheConfig? cache;
+
+  const XhsClientConfig({
+    required this.connectTimeoutMs,
+    required this.readTimeoutMs,
+    this.proxy,
+    required this.cookieStore,
+    required this.cookies,
+    required this.maxRedirects,
+    this.cache,
+  });
+
+  static Future<XhsClientConfig> default_() =>
+      RustLib.instance.api.crateApiModelsXhsXhsClientConfigDefault();
+
+  @override
+  int get hashCode =>
+      connectTimeoutMs.hashCode ^
+      readTimeoutMs.hashCode ^
+      proxy.hashCode ^
+      cookieStore.hashCode ^
+      cookies.hashCode ^
+      maxRedirects.hashCode ^
+      cache.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XhsClientConfig &&
+          runtimeType == other.runtimeType &&
+          connectTimeoutMs == other.connectTimeoutMs &&
+          readTimeoutMs == other.readTimeoutMs &&
+          proxy == other.proxy &&
+          cookieStore == other.cookieStore &&
+          cookies == other.cookies &&
+          maxRedirects == other.maxRedirects &&
+          cache == other.cache;
+}
+
+/// 规范化后的小红书链接
+class XhsLink {
+  final XhsLinkKind kind;
+
+  /// 笔记 id；短链为短链编码
+  final String id;
+  final String? xsecToken;
+  final String? xsecSource;
+
+  /// 笔记直链统一为 `https://www.xiaohongshu.com/explore/<id>`（保留 xsec 参数），短链保持原样
+  final String url;
+
+  const XhsLink({
+    required this.kind,
+    required this.id,
+    this.xsecToken,
+    this.xsecSource,
+    required this.url,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      id.hashCode ^
+      xsecToken.hashCode ^
+      xsecSource.hashCode ^
+      url.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XhsLink &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          id == other.id &&
+          xsecToken == other.xsecToken &&
+          xsecSource == other.xsecSource &&
+          url == other.url;
+}
+
+/// 分享链接类型
+enum XhsLinkKind {
+  /// xhslink.com 短链，需要跟随跳转才能拿到笔记 id
+  shortLink,
+
+  /// www.xiaohongshu.com/explore/<id>
+  explore,
+
+  /// www.xiaohongshu.com/discovery/item/<id>
+  discoveryItem,
+}
+
+/// 作者主页中的笔记卡片
+class XhsNoteCard {
+  final String noteId;
+  final String title;
+  final String cover;
+
+  /// 卡片只区分视频与图文，图文笔记统一为 Images
+  final NoteType noteType;
+  final BigInt likedCount;
+
+  /// 打开笔记详情需要携带的 xsec_token
+  final String? xsecToken;
+
+  const XhsNoteCard({
+    required this.noteId,
+    required this.title,
+    required this.cover,
+    required this.noteType,
+    required this.likedCount,
+    this.xsecToken,
+  });
+
+  @override
+  int get hashCode =>
+      noteId.hashCode ^
+      title.hashCode ^
+      cover.hashCode ^
+      noteType.hashCode ^
+      likedCount.hashCode ^
+      xsecToken.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XhsNoteCard &&
+          runtimeType == other.runtimeType &&
+          noteId == other.noteId &&
+          title == other.title &&
+          cover == other.cover &&
+          noteType == other.noteType &&
+          likedCount == other.likedCount &&
+          xsecToken == other.xsecToken;
+}
+
+/// 一页笔记卡片
+class XhsNotePage {
+  final List<XhsNoteCard> notes;
+
+  /// 下一页游标，没有更多时为空
+  final String cursor;
+  final bool hasMore;
+
+  const XhsNotePage({
+    required this.notes,
+    required this.cursor,
+    required this.hasMore,
+  });
+
+  static Future<XhsNotePage> default_() =>
+      RustLib.instance.api.crateApiModelsXhsXhsNotePageDefault();
+
+  @override
+  int get hashCode => notes.hashCode ^ cursor.hashCode ^ hasMore.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XhsNotePage &&
+          runtimeType == other.runtimeType &&
+          notes == other.notes &&
+          cursor == other.cursor &&
+          hasMore == other.hasMore;
+}
+
 class XhsVideo {
   final PlatformInt64 duration;
   final String cover;
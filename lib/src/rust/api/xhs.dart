@@ -6,40 +6,95 @@
 import '../frb_generated.dart';
 import 'models/xhs.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'xhs.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `extract_xhs_url`, `regex_error`, `url_not_found`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `extract_xhs_url`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+
+/// 识别并规范化文本中的小红书链接（短链、explore、discovery/item）
+XhsLink classifyXhsLink({required String text}) =>
+    RustLib.instance.api.crateApiXhsClassifyXhsLink(text: text);
 
 /// 从混合文本中提取小红书 URL 并解析
 ///
 /// # 示例
 /// ```ignore
 /// let text = "家庭版馄饨｜早餐自制馄饨 真的太好吃了～好吃到汤都... http://xhslink.com/o/5ZMAfpDOokl 复制后打开【小红书】查看笔记！";
-/// let article = parse_xhs_from_text(text)?;
+/// let article = parse_xhs_from_text(text, None).await?;
 /// println!("标题: {}", article.title);
 /// ```
-XhsArticle parseXhsFromText({required String text}) =>
-    RustLib.instance.api.crateApiXhsParseXhsFromText(text: text);
+Future<XhsArticle> parseXhsFromText({
+  required String text,
+  XhsClientConfig? config,
+}) => RustLib.instance.api.crateApiXhsParseXhsFromText(
+  text: text,
+  config: config,
+);
 
 /// 直接从 URL 解析小红书笔记
-XhsArticle parseXhsFromUrl({required String url}) =>
-    RustLib.instance.api.crateApiXhsParseXhsFromUrl(url: url);
-
-/// 小红书 API 错误类型，FRB 友好的设计
-class XhsApiError implements FrbException {
-  final String errorType;
-  final String message;
-
-  const XhsApiError({required this.errorType, required this.message});
-
-  @override
-  int get hashCode => errorType.hashCode ^ message.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is XhsApiError &&
-          runtimeType == other.runtimeType &&
-          errorType == other.errorType &&
-          message == other.message;
+///
+/// config 为 None 时使用默认超时、不走代理
+Future<XhsArticle> parseXhsFromUrl({
+  required String url,
+  XhsClientConfig? config,
+}) => RustLib.instance.api.crateApiXhsParseXhsFromUrl(url: url, config: config);
+
+/// 解析作者笔记分页接口返回的 JSON
+XhsNotePage parseXhsNotePage({required String json}) =>
+    RustLib.instance.api.crateApiXhsParseXhsNotePage(json: json);
+
+/// 从文本中的作者主页链接解析作者信息与首屏笔记
+///
+/// 链接中的 `xsec_token`、`xsec_source` 会原样带上。
+///
+/// 只返回主页 HTML 里的首屏笔记，不会自动翻页：`notes.has_more` 为 true 时，
+/// 调用方需带着 `notes.cursor` 自行请求（需要签名的）`user_posted` 接口，
+/// 再把响应交给 [`parse_xhs_note_page`] 解析。
+Future<XhsAuthorProfile> parseXhsProfile({
+  required String text,
+  XhsClientConfig? config,
+}) => RustLib.instance.api.crateApiXhsParseXhsProfile(
+  text: text,
+  config: config,
+);
+
+/// 小红书 API 错误，Dart 侧可按变体穷举处理
+@freezed
+sealed class XhsApiError with _$XhsApiError implements FrbException {
+  const XhsApiError._();
+
+  const factory XhsApiError.urlNotFound() = XhsApiError_UrlNotFound;
+  const factory XhsApiError.invalidLink({required String url}) =
+      XhsApiError_InvalidLink;
+  const factory XhsApiError.httpStatus({required int status, String? url}) =
+      XhsApiError_HttpStatus;
+  const factory XhsApiError.network({
+    required String message,
+    required bool timeout,
+  }) = XhsApiError_Network;
+  const factory XhsApiError.clientConfig({required String message}) =
+      XhsApiError_ClientConfig;
+  const factory XhsApiError.parse({required String message}) =
+      XhsApiError_Parse;
+  const factory XhsApiError.blocked({required XhsBlockReason reason}) =
+      XhsApiError_Blocked;
+  const factory XhsApiError.io({required String message}) = XhsApiError_Io;
+
+  /// 稳定的错误码，用于埋点与多语言文案映射，不随提示文案变化
+  String get code =>
+      RustLib.instance.api.crateApiXhsXhsApiErrorCode(that: this);
+
+  /// 面向用户的提示文案；不叫 message，避免与变体字段重名
+  String get userMessage =>
+      RustLib.instance.api.crateApiXhsXhsApiErrorUserMessage(that: this);
+}
+
+/// 无法拿到内容的页面类型
+enum XhsBlockReason {
+  noteDeleted,
+  loginRequired,
+  rateLimited,
+  captchaRequired,
+  regionBlocked,
 }
@@ -0,0 +1,535 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'xhs.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$XhsApiError {
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'XhsApiError()';
+}
+
+
+}
+
+/// @nodoc
+class $XhsApiErrorCopyWith<$Res>  {
+$XhsApiErrorCopyWith(XhsApiError _, $Res Function(XhsApiError) __);
+}
+
+/// @nodoc
+
+
+class XhsApiError_UrlNotFound extends XhsApiError {
+  const XhsApiError_UrlNotFound(): super._();
+  
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_UrlNotFound);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'XhsApiError.urlNotFound()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class XhsApiError_InvalidLink extends XhsApiError {
+  const XhsApiError_InvalidLink({required this.url}): super._();
+  
+
+ final  String url;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$XhsApiError_InvalidLinkCopyWith<XhsApiError_InvalidLink> get copyWith => _$XhsApiError_InvalidLinkCopyWithImpl<XhsApiError_InvalidLink>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_InvalidLink&&(identical(other.url, url) || other.url == url));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,url);
+
+@override
+String toString() {
+  return 'XhsApiError.invalidLink(url: $url)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $XhsApiError_InvalidLinkCopyWith<$Res> implements $XhsApiErrorCopyWith<$Res> {
+  factory $XhsApiError_InvalidLinkCopyWith(XhsApiError_InvalidLink value, $Res Function(XhsApiError_InvalidLink) _then) = _$XhsApiError_InvalidLinkCopyWithImpl;
+@useResult
+$Res call({
+ String url
+});
+
+
+
+
+}
+/// @nodoc
+class _$XhsApiError_InvalidLinkCopyWithImpl<$Res>
+    implements $XhsApiError_InvalidLinkCopyWith<$Res> {
+  _$XhsApiError_InvalidLinkCopyWithImpl(this._self, this._then);
+
+  final XhsApiError_InvalidLink _self;
+  final $Res Function(XhsApiError_InvalidLink) _then;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? url = null,}) {
+  return _then(XhsApiError_InvalidLink(
+url: null == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class XhsApiError_HttpStatus extends XhsApiError {
+  const XhsApiError_HttpStatus({required this.status, this.url}): super._();
+  
+
+ final  int status;
+ final  String? url;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$XhsApiError_HttpStatusCopyWith<XhsApiError_HttpStatus> get copyWith => _$XhsApiError_HttpStatusCopyWithImpl<XhsApiError_HttpStatus>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_HttpStatus&&(identical(other.status, status) || other.status == status)&&(identical(other.url, url) || other.url == url));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,status,url);
+
+@override
+String toString() {
+  return 'XhsApiError.httpStatus(status: $status, url: $url)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $XhsApiError_HttpStatusCopyWith<$Res> implements $XhsApiErrorCopyWith<$Res> {
+  factory $XhsApiError_HttpStatusCopyWith(XhsApiError_HttpStatus value, $Res Function(XhsApiError_HttpStatus) _then) = _$XhsApiError_HttpStatusCopyWithImpl;
+@useResult
+$Res call({
+ int status,
+ String? url
+});
+
+
+
+
+}
+/// @nodoc
+class _$XhsApiError_HttpStatusCopyWithImpl<$Res>
+    implements $XhsApiError_HttpStatusCopyWith<$Res> {
+  _$XhsApiError_HttpStatusCopyWithImpl(this._self, this._then);
+
+  final XhsApiError_HttpStatus _self;
+  final $Res Function(XhsApiError_HttpStatus) _then;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? status = null,Object? url = freezed,}) {
+  return _then(XhsApiError_HttpStatus(
+status: null == status ? _self.status : status // ignore: cast_nullable_to_non_nullable
+as int,
+url: freezed == url ? _self.url : url // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class XhsApiError_Network extends XhsApiError {
+  const XhsApiError_Network({required this.message, required this.timeout}): super._();
+  
+
+ final  String message;
+ final  bool timeout;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$XhsApiError_NetworkCopyWith<XhsApiError_Network> get copyWith => _$XhsApiError_NetworkCopyWithImpl<XhsApiError_Network>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_Network&&(identical(other.message, message) || other.message == message)&&(identical(other.timeout, timeout) || other.timeout == timeout));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message,timeout);
+
+@override
+String toString() {
+  return 'XhsApiError.network(message: $message, timeout: $timeout)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $XhsApiError_NetworkCopyWith<$Res> implements $XhsApiErrorCopyWith<$Res> {
+  factory $XhsApiError_NetworkCopyWith(XhsApiError_Network value, $Res Function(XhsApiError_Network) _then) = _$XhsApiError_NetworkCopyWithImpl;
+@useResult
+$Res call({
+ String message,
+ bool timeout
+});
+
+
+
+
+}
+/// @nodoc
+class _$XhsApiError_NetworkCopyWithImpl<$Res>
+    implements $XhsApiError_NetworkCopyWith<$Res> {
+  _$XhsApiError_NetworkCopyWithImpl(this._self, this._then);
+
+  final XhsApiError_Network _self;
+  final $Res Function(XhsApiError_Network) _then;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,Object? timeout = null,}) {
+  return _then(XhsApiError_Network(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+timeout: null == timeout ? _self.timeout : timeout // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class XhsApiError_ClientConfig extends XhsApiError {
+  const XhsApiError_ClientConfig({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$XhsApiError_ClientConfigCopyWith<XhsApiError_ClientConfig> get copyWith => _$XhsApiError_ClientConfigCopyWithImpl<XhsApiError_ClientConfig>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_ClientConfig&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'XhsApiError.clientConfig(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $XhsApiError_ClientConfigCopyWith<$Res> implements $XhsApiErrorCopyWith<$Res> {
+  factory $XhsApiError_ClientConfigCopyWith(XhsApiError_ClientConfig value, $Res Function(XhsApiError_ClientConfig) _then) = _$XhsApiError_ClientConfigCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$XhsApiError_ClientConfigCopyWithImpl<$Res>
+    implements $XhsApiError_ClientConfigCopyWith<$Res> {
+  _$XhsApiError_ClientConfigCopyWithImpl(this._self, this._then);
+
+  final XhsApiError_ClientConfig _self;
+  final $Res Function(XhsApiError_ClientConfig) _then;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(XhsApiError_ClientConfig(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class XhsApiError_Parse extends XhsApiError {
+  const XhsApiError_Parse({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$XhsApiError_ParseCopyWith<XhsApiError_Parse> get copyWith => _$XhsApiError_ParseCopyWithImpl<XhsApiError_Parse>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_Parse&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'XhsApiError.parse(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $XhsApiError_ParseCopyWith<$Res> implements $XhsApiErrorCopyWith<$Res> {
+  factory $XhsApiError_ParseCopyWith(XhsApiError_Parse value, $Res Function(XhsApiError_Parse) _then) = _$XhsApiError_ParseCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$XhsApiError_ParseCopyWithImpl<$Res>
+    implements $XhsApiError_ParseCopyWith<$Res> {
+  _$XhsApiError_ParseCopyWithImpl(this._self, this._then);
+
+  final XhsApiError_Parse _self;
+  final $Res Function(XhsApiError_Parse) _then;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(XhsApiError_Parse(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class XhsApiError_Blocked extends XhsApiError {
+  const XhsApiError_Blocked({required this.reason}): super._();
+  
+
+ final  XhsBlockReason reason;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$XhsApiError_BlockedCopyWith<XhsApiError_Blocked> get copyWith => _$XhsApiError_BlockedCopyWithImpl<XhsApiError_Blocked>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_Blocked&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'XhsApiError.blocked(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $XhsApiError_BlockedCopyWith<$Res> implements $XhsApiErrorCopyWith<$Res> {
+  factory $XhsApiError_BlockedCopyWith(XhsApiError_Blocked value, $Res Function(XhsApiError_Blocked) _then) = _$XhsApiError_BlockedCopyWithImpl;
+@useResult
+$Res call({
+ XhsBlockReason reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$XhsApiError_BlockedCopyWithImpl<$Res>
+    implements $XhsApiError_BlockedCopyWith<$Res> {
+  _$XhsApiError_BlockedCopyWithImpl(this._self, this._then);
+
+  final XhsApiError_Blocked _self;
+  final $Res Function(XhsApiError_Blocked) _then;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(XhsApiError_Blocked(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as XhsBlockReason,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class XhsApiError_Io extends XhsApiError {
+  const XhsApiError_Io({required this.message}): super._();
+  
+
+ final  String message;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$XhsApiError_IoCopyWith<XhsApiError_Io> get copyWith => _$XhsApiError_IoCopyWithImpl<XhsApiError_Io>(this, _$identity);
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is XhsApiError_Io&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,message);
+
+@override
+String toString() {
+  return 'XhsApiError.io(message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $XhsApiError_IoCopyWith<$Res> implements $XhsApiErrorCopyWith<$Res> {
+  factory $XhsApiError_IoCopyWith(XhsApiError_Io value, $Res Function(XhsApiError_Io) _then) = _$XhsApiError_IoCopyWithImpl;
+@useResult
+$Res call({
+ String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$XhsApiError_IoCopyWithImpl<$Res>
+    implements $XhsApiError_IoCopyWith<$Res> {
+  _$XhsApiError_IoCopyWithImpl(this._self, this._then);
+
+  final XhsApiError_Io _self;
+  final $Res Function(XhsApiError_Io) _then;
+
+/// Create a copy of XhsApiError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? message = null,}) {
+  return _then(XhsApiError_Io(
+message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+// dart format on
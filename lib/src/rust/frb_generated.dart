@@ -4,6 +4,12 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/audio.dart';
+import 'api/importer.dart';
+import 'api/models/asr.dart';
+import 'api/models/manifest.dart';
+import 'api/models/recipe.dart';
+import 'api/models/source.dart';
+import 'api/models/transcript.dart';
 import 'api/models/xhs.dart';
 import 'api/video.dart';
 import 'api/xhs.dart';
@@ -69,7 +75,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -223892292;
+  int get rustContentHash => -795597414;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -82,6 +88,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 abstract class RustLibApi extends BaseApi {
   Future<AudioRecognizer> crateApiAudioAudioRecognizerCreate({
     required String modelsDir,
+    AudioRecognizerConfig? config,
   });
 
   String crateApiAudioAudioRecognizerModelsDir({required AudioRecognizer that});
@@ -92,6 +99,43 @@ abstract class RustLibApi extends BaseApi {
     String? language,
   });
 
+  Future<Transcript> crateApiAudioAudioRecognizerTranscribeAudioDetailed({
+    required AudioRecognizer that,
+    required String path,
+    String? language,
+  });
+
+  Future<Transcript> crateApiAudioAudioRecognizerTranscribeSamples({
+    required AudioRecognizer that,
+    required List<double> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  });
+
+  Future<Transcript> crateApiAudioAudioRecognizerTranscribeSamplesI16({
+    required AudioRecognizer that,
+    required List<int> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  });
+
+  Future<RecipeImporter> crateApiImporterRecipeImporterCreate({
+    required String modelsDir,
+    required String workDir,
+    XhsClientConfig? clientConfig,
+  });
+
+  Future<ImportedRecipe> crateApiImporterRecipeImporterImportFromText({
+    required RecipeImporter that,
+    required String shareText,
+    String? language,
+    required FutureOr<String> Function(String) decodeAudio,
+    required FutureOr<List<YFrameData>> Function(String) extractFrames,
+    required FutureOr<void> Function(ImportProgress) onProgress,
+  });
+
   VideoFrameExtractor crateApiVideoVideoFrameExtractorCreate();
 
   Future<List<FrameExtractedInfo>>
@@ -108,11 +152,88 @@ abstract class RustLibApi extends BaseApi {
     required VideoFrameExtractor that,
   });
 
+  Future<AsrConfig> crateApiModelsAsrAsrConfigDefault();
+
+  Future<AsrModelFiles> crateApiModelsAsrAsrModelFilesDefault();
+
+  Future<AudioRecognizerConfig> crateApiModelsAsrAudioRecognizerConfigDefault();
+
+  XhsLink crateApiXhsClassifyXhsLink({required String text});
+
+  Future<DiarizationConfig> crateApiModelsAsrDiarizationConfigDefault();
+
+  Future<EndpointConfig> crateApiModelsAsrEndpointConfigDefault();
+
+  Future<EventDetectionConfig> crateApiModelsAsrEventDetectionConfigDefault();
+
+  Future<HotwordConfig> crateApiModelsAsrHotwordConfigDefault();
+
+  Future<LoudnessConfig> crateApiModelsAsrLoudnessConfigDefault();
+
+  Future<ModelValidationReport>
+  crateApiModelsManifestModelValidationReportDefault();
+
+  Future<bool> crateApiModelsManifestModelValidationReportIsValid({
+    required ModelValidationReport that,
+  });
+
+  Future<NoiseReductionConfig> crateApiModelsAsrNoiseReductionConfigDefault();
+
   Future<NoteType> crateApiModelsXhsNoteTypeDefault();
 
-  XhsArticle crateApiXhsParseXhsFromText({required String text});
+  Future<XhsArticle> crateApiXhsParseXhsFromText({
+    required String text,
+    XhsClientConfig? config,
+  });
+
+  Future<XhsArticle> crateApiXhsParseXhsFromUrl({
+    required String url,
+    XhsClientConfig? config,
+  });
+
+  XhsNotePage crateApiXhsParseXhsNotePage({required String json});
+
+  Future<XhsAuthorProfile> crateApiXhsParseXhsProfile({
+    required String text,
+    XhsClientConfig? config,
+  });
+
+  Future<ParsedRecipe> crateApiModelsRecipeParsedRecipeDefault();
+
+  Future<PostprocessConfig> crateApiModelsAsrPostprocessConfigDefault();
+
+  Future<PreprocessConfig> crateApiModelsAsrPreprocessConfigDefault();
+
+  Future<RecognizerPoolConfig> crateApiModelsAsrRecognizerPoolConfigDefault();
+
+  Future<ResampleQuality> crateApiModelsAsrResampleQualityDefault();
+
+  Future<SegmentationPath> crateApiModelsTranscriptSegmentationPathDefault();
+
+  Future<SourceAuthor> crateApiModelsSourceSourceAuthorDefault();
+
+  Future<Transcript> crateApiModelsTranscriptTranscriptDefault();
+
+  Future<VadConfig> crateApiModelsAsrVadConfigDefault();
+
+  Future<VadFallback> crateApiModelsAsrVadFallbackDefault();
 
-  XhsArticle crateApiXhsParseXhsFromUrl({required String url});
+  Future<ModelValidationReport> crateApiAudioValidateModels({
+    required String modelsDir,
+    required bool verifyChecksums,
+  });
+
+  String crateApiXhsXhsApiErrorCode({required XhsApiError that});
+
+  String crateApiXhsXhsApiErrorUserMessage({required XhsApiError that});
+
+  Future<XhsCacheConfig> crateApiModelsXhsXhsCacheConfigNew({
+    required String cacheDir,
+  });
+
+  Future<XhsClientConfig> crateApiModelsXhsXhsClientConfigDefault();
+
+  Future<XhsNotePage> crateApiModelsXhsXhsNotePageDefault();
 
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_AudioError;
@@ -131,6 +252,23 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_AudioRecognizerPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ImportError;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ImportError;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ImportErrorPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_RecipeImporter;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_RecipeImporter;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_RecipeImporterPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_VideoFrameExtractor;
 
@@ -152,12 +290,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @override
   Future<AudioRecognizer> crateApiAudioAudioRecognizerCreate({
     required String modelsDir,
+    AudioRecognizerConfig? config,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(modelsDir, serializer);
+          sse_encode_opt_box_autoadd_audio_recognizer_config(
+            config,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -172,7 +315,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError,
         ),
         constMeta: kCrateApiAudioAudioRecognizerCreateConstMeta,
-        argValues: [modelsDir],
+        argValues: [modelsDir, config],
         apiImpl: this,
       ),
     );
@@ -181,7 +324,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiAudioAudioRecognizerCreateConstMeta =>
       const TaskConstMeta(
         debugName: "AudioRecognizer_create",
-        argNames: ["modelsDir"],
+        argNames: ["modelsDir", "config"],
       );
 
   @override
@@ -256,13 +399,259 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["that", "path", "language"],
       );
 
+  @override
+  Future<Transcript> crateApiAudioAudioRecognizerTranscribeAudioDetailed({
+    required AudioRecognizer that,
+    required String path,
+    String? language,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+            that,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(language, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_transcript,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError,
+        ),
+        constMeta:
+            kCrateApiAudioAudioRecognizerTranscribeAudioDetailedConstMeta,
+        argValues: [that, path, language],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiAudioAudioRecognizerTranscribeAudioDetailedConstMeta =>
+      const TaskConstMeta(
+        debugName: "AudioRecognizer_transcribe_audio_detailed",
+        argNames: ["that", "path", "language"],
+      );
+
+  @override
+  Future<Transcript> crateApiAudioAudioRecognizerTranscribeSamples({
+    required AudioRecognizer that,
+    required List<double> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_f_32_loose(samples, serializer);
+          sse_encode_u_32(sampleRate, serializer);
+          sse_encode_u_16(channels, serializer);
+          sse_encode_opt_String(language, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_transcript,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError,
+        ),
+        constMeta: kCrateApiAudioAudioRecognizerTranscribeSamplesConstMeta,
+        argValues: [that, samples, sampleRate, channels, language],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioAudioRecognizerTranscribeSamplesConstMeta =>
+      const TaskConstMeta(
+        debugName: "AudioRecognizer_transcribe_samples",
+        argNames: ["that", "samples", "sampleRate", "channels", "language"],
+      );
+
+  @override
+  Future<Transcript> crateApiAudioAudioRecognizerTranscribeSamplesI16({
+    required AudioRecognizer that,
+    required List<int> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+            that,
+            serializer,
+          );
+          sse_encode_list_prim_i_16_loose(samples, serializer);
+          sse_encode_u_32(sampleRate, serializer);
+          sse_encode_u_16(channels, serializer);
+          sse_encode_opt_String(language, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_transcript,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError,
+        ),
+        constMeta: kCrateApiAudioAudioRecognizerTranscribeSamplesI16ConstMeta,
+        argValues: [that, samples, sampleRate, channels, language],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiAudioAudioRecognizerTranscribeSamplesI16ConstMeta =>
+      const TaskConstMeta(
+        debugName: "AudioRecognizer_transcribe_samples_i16",
+        argNames: ["that", "samples", "sampleRate", "channels", "language"],
+      );
+
+  @override
+  Future<RecipeImporter> crateApiImporterRecipeImporterCreate({
+    required String modelsDir,
+    required String workDir,
+    XhsClientConfig? clientConfig,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(modelsDir, serializer);
+          sse_encode_String(workDir, serializer);
+          sse_encode_opt_box_autoadd_xhs_client_config(
+            clientConfig,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 7,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError,
+        ),
+        constMeta: kCrateApiImporterRecipeImporterCreateConstMeta,
+        argValues: [modelsDir, workDir, clientConfig],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImporterRecipeImporterCreateConstMeta =>
+      const TaskConstMeta(
+        debugName: "RecipeImporter_create",
+        argNames: ["modelsDir", "workDir", "clientConfig"],
+      );
+
+  @override
+  Future<ImportedRecipe> crateApiImporterRecipeImporterImportFromText({
+    required RecipeImporter that,
+    required String shareText,
+    String? language,
+    required FutureOr<String> Function(String) decodeAudio,
+    required FutureOr<List<YFrameData>> Function(String) extractFrames,
+    required FutureOr<void> Function(ImportProgress) onProgress,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+            that,
+            serializer,
+          );
+          sse_encode_String(shareText, serializer);
+          sse_encode_opt_String(language, serializer);
+          sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
+            decodeAudio,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+            extractFrames,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+            onProgress,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_imported_recipe,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError,
+        ),
+        constMeta: kCrateApiImporterRecipeImporterImportFromTextConstMeta,
+        argValues: [
+          that,
+          shareText,
+          language,
+          decodeAudio,
+          extractFrames,
+          onProgress,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImporterRecipeImporterImportFromTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "RecipeImporter_import_from_text",
+        argNames: [
+          "that",
+          "shareText",
+          "language",
+          "decodeAudio",
+          "extractFrames",
+          "onProgress",
+        ],
+      );
+
   @override
   VideoFrameExtractor crateApiVideoVideoFrameExtractorCreate() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
         },
         codec: SseCodec(
           decodeSuccessData:
@@ -300,7 +689,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 10,
             port: port_,
           );
         },
@@ -333,7 +722,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -364,7 +753,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             that,
             serializer,
           );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_extraction_stats,
@@ -384,7 +773,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<NoteType> crateApiModelsXhsNoteTypeDefault() {
+  Future<AsrConfig> crateApiModelsAsrAsrConfigDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -392,989 +781,5259 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 13,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_note_type,
+          decodeSuccessData: sse_decode_asr_config,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiModelsXhsNoteTypeDefaultConstMeta,
+        constMeta: kCrateApiModelsAsrAsrConfigDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiModelsXhsNoteTypeDefaultConstMeta =>
-      const TaskConstMeta(debugName: "note_type_default", argNames: []);
+  TaskConstMeta get kCrateApiModelsAsrAsrConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "asr_config_default", argNames: []);
 
   @override
-  XhsArticle crateApiXhsParseXhsFromText({required String text}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<AsrModelFiles> crateApiModelsAsrAsrModelFilesDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_xhs_article,
-          decodeErrorData: sse_decode_xhs_api_error,
+          decodeSuccessData: sse_decode_asr_model_files,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiXhsParseXhsFromTextConstMeta,
-        argValues: [text],
+        constMeta: kCrateApiModelsAsrAsrModelFilesDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrAsrModelFilesDefaultConstMeta =>
+      const TaskConstMeta(debugName: "asr_model_files_default", argNames: []);
+
+  @override
+  Future<AudioRecognizerConfig>
+  crateApiModelsAsrAudioRecognizerConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_audio_recognizer_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrAudioRecognizerConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrAudioRecognizerConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "audio_recognizer_config_default",
+        argNames: [],
+      );
+
+  @override
+  XhsLink crateApiXhsClassifyXhsLink({required String text}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_link,
+          decodeErrorData: sse_decode_xhs_api_error,
+        ),
+        constMeta: kCrateApiXhsClassifyXhsLinkConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhsClassifyXhsLinkConstMeta =>
+      const TaskConstMeta(debugName: "classify_xhs_link", argNames: ["text"]);
+
+  @override
+  Future<DiarizationConfig> crateApiModelsAsrDiarizationConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_diarization_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrDiarizationConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrDiarizationConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "diarization_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<EndpointConfig> crateApiModelsAsrEndpointConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_endpoint_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrEndpointConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrEndpointConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "endpoint_config_default", argNames: []);
+
+  @override
+  Future<EventDetectionConfig> crateApiModelsAsrEventDetectionConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_event_detection_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrEventDetectionConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrEventDetectionConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "event_detection_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<HotwordConfig> crateApiModelsAsrHotwordConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_hotword_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrHotwordConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrHotwordConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "hotword_config_default", argNames: []);
+
+  @override
+  Future<LoudnessConfig> crateApiModelsAsrLoudnessConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_loudness_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrLoudnessConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrLoudnessConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "loudness_config_default", argNames: []);
+
+  @override
+  Future<ModelValidationReport>
+  crateApiModelsManifestModelValidationReportDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_model_validation_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsManifestModelValidationReportDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiModelsManifestModelValidationReportDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "model_validation_report_default",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiModelsManifestModelValidationReportIsValid({
+    required ModelValidationReport that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_model_validation_report(that, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsManifestModelValidationReportIsValidConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiModelsManifestModelValidationReportIsValidConstMeta =>
+      const TaskConstMeta(
+        debugName: "model_validation_report_is_valid",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<NoiseReductionConfig> crateApiModelsAsrNoiseReductionConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_noise_reduction_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrNoiseReductionConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrNoiseReductionConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "noise_reduction_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<NoteType> crateApiModelsXhsNoteTypeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_note_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsXhsNoteTypeDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiXhsParseXhsFromTextConstMeta =>
-      const TaskConstMeta(debugName: "parse_xhs_from_text", argNames: ["text"]);
+  TaskConstMeta get kCrateApiModelsXhsNoteTypeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "note_type_default", argNames: []);
+
+  @override
+  Future<XhsArticle> crateApiXhsParseXhsFromText({
+    required String text,
+    XhsClientConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_opt_box_autoadd_xhs_client_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_article,
+          decodeErrorData: sse_decode_xhs_api_error,
+        ),
+        constMeta: kCrateApiXhsParseXhsFromTextConstMeta,
+        argValues: [text, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhsParseXhsFromTextConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_xhs_from_text",
+        argNames: ["text", "config"],
+      );
+
+  @override
+  Future<XhsArticle> crateApiXhsParseXhsFromUrl({
+    required String url,
+    XhsClientConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(url, serializer);
+          sse_encode_opt_box_autoadd_xhs_client_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_article,
+          decodeErrorData: sse_decode_xhs_api_error,
+        ),
+        constMeta: kCrateApiXhsParseXhsFromUrlConstMeta,
+        argValues: [url, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhsParseXhsFromUrlConstMeta => const TaskConstMeta(
+    debugName: "parse_xhs_from_url",
+    argNames: ["url", "config"],
+  );
+
+  @override
+  XhsNotePage crateApiXhsParseXhsNotePage({required String json}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(json, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_note_page,
+          decodeErrorData: sse_decode_xhs_api_error,
+        ),
+        constMeta: kCrateApiXhsParseXhsNotePageConstMeta,
+        argValues: [json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhsParseXhsNotePageConstMeta =>
+      const TaskConstMeta(debugName: "parse_xhs_note_page", argNames: ["json"]);
+
+  @override
+  Future<XhsAuthorProfile> crateApiXhsParseXhsProfile({
+    required String text,
+    XhsClientConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(text, serializer);
+          sse_encode_opt_box_autoadd_xhs_client_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_author_profile,
+          decodeErrorData: sse_decode_xhs_api_error,
+        ),
+        constMeta: kCrateApiXhsParseXhsProfileConstMeta,
+        argValues: [text, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhsParseXhsProfileConstMeta => const TaskConstMeta(
+    debugName: "parse_xhs_profile",
+    argNames: ["text", "config"],
+  );
+
+  @override
+  Future<ParsedRecipe> crateApiModelsRecipeParsedRecipeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_parsed_recipe,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsRecipeParsedRecipeDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsRecipeParsedRecipeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "parsed_recipe_default", argNames: []);
+
+  @override
+  Future<PostprocessConfig> crateApiModelsAsrPostprocessConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_postprocess_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrPostprocessConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrPostprocessConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "postprocess_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<PreprocessConfig> crateApiModelsAsrPreprocessConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_preprocess_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrPreprocessConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrPreprocessConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "preprocess_config_default", argNames: []);
+
+  @override
+  Future<RecognizerPoolConfig> crateApiModelsAsrRecognizerPoolConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_recognizer_pool_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrRecognizerPoolConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrRecognizerPoolConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "recognizer_pool_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<ResampleQuality> crateApiModelsAsrResampleQualityDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_resample_quality,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrResampleQualityDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrResampleQualityDefaultConstMeta =>
+      const TaskConstMeta(debugName: "resample_quality_default", argNames: []);
+
+  @override
+  Future<SegmentationPath> crateApiModelsTranscriptSegmentationPathDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_segmentation_path,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsTranscriptSegmentationPathDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsTranscriptSegmentationPathDefaultConstMeta =>
+      const TaskConstMeta(debugName: "segmentation_path_default", argNames: []);
+
+  @override
+  Future<SourceAuthor> crateApiModelsSourceSourceAuthorDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_source_author,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsSourceSourceAuthorDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsSourceSourceAuthorDefaultConstMeta =>
+      const TaskConstMeta(debugName: "source_author_default", argNames: []);
+
+  @override
+  Future<Transcript> crateApiModelsTranscriptTranscriptDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_transcript,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsTranscriptTranscriptDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsTranscriptTranscriptDefaultConstMeta =>
+      const TaskConstMeta(debugName: "transcript_default", argNames: []);
+
+  @override
+  Future<VadConfig> crateApiModelsAsrVadConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_vad_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrVadConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrVadConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "vad_config_default", argNames: []);
+
+  @override
+  Future<VadFallback> crateApiModelsAsrVadFallbackDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_vad_fallback,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsAsrVadFallbackDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsAsrVadFallbackDefaultConstMeta =>
+      const TaskConstMeta(debugName: "vad_fallback_default", argNames: []);
+
+  @override
+  Future<ModelValidationReport> crateApiAudioValidateModels({
+    required String modelsDir,
+    required bool verifyChecksums,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(modelsDir, serializer);
+          sse_encode_bool(verifyChecksums, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_model_validation_report,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError,
+        ),
+        constMeta: kCrateApiAudioValidateModelsConstMeta,
+        argValues: [modelsDir, verifyChecksums],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAudioValidateModelsConstMeta =>
+      const TaskConstMeta(
+        debugName: "validate_models",
+        argNames: ["modelsDir", "verifyChecksums"],
+      );
+
+  @override
+  String crateApiXhsXhsApiErrorCode({required XhsApiError that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_xhs_api_error(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiXhsXhsApiErrorCodeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhsXhsApiErrorCodeConstMeta =>
+      const TaskConstMeta(debugName: "xhs_api_error_code", argNames: ["that"]);
+
+  @override
+  String crateApiXhsXhsApiErrorUserMessage({required XhsApiError that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_xhs_api_error(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiXhsXhsApiErrorUserMessageConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiXhsXhsApiErrorUserMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhs_api_error_user_message",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<XhsCacheConfig> crateApiModelsXhsXhsCacheConfigNew({
+    required String cacheDir,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(cacheDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_cache_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsXhsXhsCacheConfigNewConstMeta,
+        argValues: [cacheDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsXhsXhsCacheConfigNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "xhs_cache_config_new",
+        argNames: ["cacheDir"],
+      );
+
+  @override
+  Future<XhsClientConfig> crateApiModelsXhsXhsClientConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_client_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsXhsXhsClientConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsXhsXhsClientConfigDefaultConstMeta =>
+      const TaskConstMeta(debugName: "xhs_client_config_default", argNames: []);
+
+  @override
+  Future<XhsNotePage> crateApiModelsXhsXhsNotePageDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_xhs_note_page,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiModelsXhsXhsNotePageDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelsXhsXhsNotePageDefaultConstMeta =>
+      const TaskConstMeta(debugName: "xhs_note_page_default", argNames: []);
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<String>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_String(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    FutureOr<List<YFrameData>> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<List<YFrameData>>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_list_y_frame_data(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(ImportProgress) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_import_progress(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_AudioError => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_AudioError => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_AudioRecognizer => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_AudioRecognizer => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ImportError => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ImportError => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_RecipeImporter => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_RecipeImporter => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_VideoFrameExtractor => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_VideoFrameExtractor => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  AudioError
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  AudioRecognizer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioRecognizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ImportError
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RecipeImporter
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecipeImporterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  VideoFrameExtractor
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VideoFrameExtractorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  AudioRecognizer
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioRecognizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RecipeImporter
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecipeImporterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  VideoFrameExtractor
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VideoFrameExtractorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  FutureOr<String> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<List<YFrameData>> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(ImportProgress)
+  dco_decode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return decodeDartOpaque(raw, generalizedFrbRustBinding);
+  }
+
+  @protected
+  AudioError
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  AudioRecognizer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioRecognizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ImportError
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RecipeImporter
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RecipeImporterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  VideoFrameExtractor
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VideoFrameExtractorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AsrConfig dco_decode_asr_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return AsrConfig(
+      decodingMethod: dco_decode_decoding_method(arr[0]),
+      numActivePaths: dco_decode_u_32(arr[1]),
+      numThreads: dco_decode_u_32(arr[2]),
+      endpoint: dco_decode_endpoint_config(arr[3]),
+      modelFiles: dco_decode_asr_model_files(arr[4]),
+    );
+  }
+
+  @protected
+  AsrModelFiles dco_decode_asr_model_files(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AsrModelFiles(
+      encoderParam: dco_decode_String(arr[0]),
+      encoderBin: dco_decode_String(arr[1]),
+      decoderParam: dco_decode_String(arr[2]),
+      decoderBin: dco_decode_String(arr[3]),
+      joinerParam: dco_decode_String(arr[4]),
+      joinerBin: dco_decode_String(arr[5]),
+      tokens: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  AudioEvent dco_decode_audio_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AudioEvent(
+      kind: dco_decode_audio_event_kind(arr[0]),
+      startMs: dco_decode_u_64(arr[1]),
+      endMs: dco_decode_u_64(arr[2]),
+      confidence: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  AudioEventKind dco_decode_audio_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AudioEventKind.values[raw as int];
+  }
+
+  @protected
+  AudioRecognizerConfig dco_decode_audio_recognizer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return AudioRecognizerConfig(
+      hotwords: dco_decode_opt_box_autoadd_hotword_config(arr[0]),
+      asr: dco_decode_asr_config(arr[1]),
+      modelPacks: dco_decode_list_model_pack_config(arr[2]),
+      pool: dco_decode_recognizer_pool_config(arr[3]),
+      vad: dco_decode_vad_config(arr[4]),
+      vadFallback: dco_decode_vad_fallback(arr[5]),
+      diarization: dco_decode_opt_box_autoadd_diarization_config(arr[6]),
+      postprocess: dco_decode_postprocess_config(arr[7]),
+      resampleQuality: dco_decode_resample_quality(arr[8]),
+      preprocess: dco_decode_opt_box_autoadd_preprocess_config(arr[9]),
+      events: dco_decode_opt_box_autoadd_event_detection_config(arr[10]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AudioRecognizerConfig dco_decode_box_autoadd_audio_recognizer_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audio_recognizer_config(raw);
+  }
+
+  @protected
+  DiarizationConfig dco_decode_box_autoadd_diarization_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_diarization_config(raw);
+  }
+
+  @protected
+  EventDetectionConfig dco_decode_box_autoadd_event_detection_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_event_detection_config(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  HotwordConfig dco_decode_box_autoadd_hotword_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_hotword_config(raw);
+  }
+
+  @protected
+  LoudnessConfig dco_decode_box_autoadd_loudness_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_loudness_config(raw);
+  }
+
+  @protected
+  ModelValidationReport dco_decode_box_autoadd_model_validation_report(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_model_validation_report(raw);
+  }
+
+  @protected
+  NoiseReductionConfig dco_decode_box_autoadd_noise_reduction_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_noise_reduction_config(raw);
+  }
+
+  @protected
+  ParsedRecipe dco_decode_box_autoadd_parsed_recipe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_parsed_recipe(raw);
+  }
+
+  @protected
+  PreprocessConfig dco_decode_box_autoadd_preprocess_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_preprocess_config(raw);
+  }
+
+  @protected
+  SourceVideo dco_decode_box_autoadd_source_video(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_source_video(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  XhsApiError dco_decode_box_autoadd_xhs_api_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_xhs_api_error(raw);
+  }
+
+  @protected
+  XhsCacheConfig dco_decode_box_autoadd_xhs_cache_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_xhs_cache_config(raw);
+  }
+
+  @protected
+  XhsClientConfig dco_decode_box_autoadd_xhs_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_xhs_client_config(raw);
+  }
+
+  @protected
+  XhsVideo dco_decode_box_autoadd_xhs_video(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_xhs_video(raw);
+  }
+
+  @protected
+  DecodingMethod dco_decode_decoding_method(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DecodingMethod.values[raw as int];
+  }
+
+  @protected
+  DiarizationConfig dco_decode_diarization_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DiarizationConfig(
+      maxSpeakers: dco_decode_u_32(arr[0]),
+      threshold: dco_decode_f_32(arr[1]),
+      minSegmentMs: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  EndpointConfig dco_decode_endpoint_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EndpointConfig(
+      enable: dco_decode_bool(arr[0]),
+      rule1MinTrailingSilence: dco_decode_f_32(arr[1]),
+      rule2MinTrailingSilence: dco_decode_f_32(arr[2]),
+      rule3MinUtteranceLength: dco_decode_f_32(arr[3]),
+    );
+  }
+
+  @protected
+  EventDetectionConfig dco_decode_event_detection_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EventDetectionConfig(
+      minEventMs: dco_decode_u_32(arr[0]),
+      minConfidence: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  ExtractionStats dco_decode_extraction_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ExtractionStats(
+      processedFrames: dco_decode_u_64(arr[0]),
+      extractedFrames: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FrameExtractedInfo dco_decode_frame_extracted_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FrameExtractedInfo(
+      timestampMs: dco_decode_u_64(arr[0]),
+      frameNumber: dco_decode_u_64(arr[1]),
+      confidence: dco_decode_f_32(arr[2]),
+      jpegData: dco_decode_list_prim_u_8_strict(arr[3]),
+      width: dco_decode_u_32(arr[4]),
+      height: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  Hotword dco_decode_hotword(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Hotword(
+      phrase: dco_decode_String(arr[0]),
+      score: dco_decode_opt_box_autoadd_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  HotwordConfig dco_decode_hotword_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HotwordConfig(
+      hotwords: dco_decode_list_hotword(arr[0]),
+      useCookingLexicon: dco_decode_bool(arr[1]),
+      defaultScore: dco_decode_f_32(arr[2]),
+      workDir: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImportProgress dco_decode_import_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImportProgress(
+      stage: dco_decode_import_stage(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ImportStage dco_decode_import_stage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportStage.values[raw as int];
+  }
+
+  @protected
+  ImportedRecipe dco_decode_imported_recipe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ImportedRecipe(
+      sourceUrl: dco_decode_String(arr[0]),
+      article: dco_decode_source_article(arr[1]),
+      transcript: dco_decode_opt_String(arr[2]),
+      keyframes: dco_decode_list_frame_extracted_info(arr[3]),
+      recipe: dco_decode_parsed_recipe(arr[4]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AudioEvent> dco_decode_list_audio_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_audio_event).toList();
+  }
+
+  @protected
+  List<FrameExtractedInfo> dco_decode_list_frame_extracted_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_frame_extracted_info).toList();
+  }
+
+  @protected
+  List<Hotword> dco_decode_list_hotword(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_hotword).toList();
+  }
+
+  @protected
+  List<ModelFileIssue> dco_decode_list_model_file_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_model_file_issue).toList();
+  }
+
+  @protected
+  List<ModelPackConfig> dco_decode_list_model_pack_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_model_pack_config).toList();
+  }
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<double>;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_i_16_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RecipeIngredient> dco_decode_list_recipe_ingredient(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_recipe_ingredient).toList();
+  }
+
+  @protected
+  List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transcript_segment).toList();
+  }
+
+  @protected
+  List<TranscriptToken> dco_decode_list_transcript_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transcript_token).toList();
+  }
+
+  @protected
+  List<XhsNoteCard> dco_decode_list_xhs_note_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_xhs_note_card).toList();
+  }
+
+  @protected
+  List<YFrameData> dco_decode_list_y_frame_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_y_frame_data).toList();
+  }
+
+  @protected
+  LoudnessConfig dco_decode_loudness_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LoudnessConfig(
+      measure: dco_decode_loudness_measure(arr[0]),
+      targetDb: dco_decode_f_32(arr[1]),
+      maxGainDb: dco_decode_f_32(arr[2]),
+      windowMs: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  LoudnessMeasure dco_decode_loudness_measure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LoudnessMeasure.values[raw as int];
+  }
+
+  @protected
+  ModelFileIssue dco_decode_model_file_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ModelFileIssue(
+      path: dco_decode_String(arr[0]),
+      kind: dco_decode_model_issue_kind(arr[1]),
+    );
+  }
+
+  @protected
+  ModelIssueKind dco_decode_model_issue_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ModelIssueKind_Missing();
+      case 1:
+        return ModelIssueKind_InvalidPath();
+      case 2:
+        return ModelIssueKind_SizeMismatch(
+          expected: dco_decode_u_64(raw[1]),
+          actual: dco_decode_u_64(raw[2]),
+        );
+      case 3:
+        return ModelIssueKind_ChecksumMismatch(
+          expected: dco_decode_String(raw[1]),
+          actual: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ModelPackConfig dco_decode_model_pack_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ModelPackConfig(
+      dir: dco_decode_String(arr[0]),
+      languages: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  ModelValidationReport dco_decode_model_validation_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ModelValidationReport(
+      checked: dco_decode_u_32(arr[0]),
+      checksumsVerified: dco_decode_bool(arr[1]),
+      issues: dco_decode_list_model_file_issue(arr[2]),
+    );
+  }
+
+  @protected
+  NoiseReductionConfig dco_decode_noise_reduction_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NoiseReductionConfig(
+      overSubtraction: dco_decode_f_32(arr[0]),
+      floorDb: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  NoteType dco_decode_note_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NoteType.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AudioRecognizerConfig? dco_decode_opt_box_autoadd_audio_recognizer_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_audio_recognizer_config(raw);
+  }
+
+  @protected
+  DiarizationConfig? dco_decode_opt_box_autoadd_diarization_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_diarization_config(raw);
+  }
+
+  @protected
+  EventDetectionConfig? dco_decode_opt_box_autoadd_event_detection_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_event_detection_config(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  HotwordConfig? dco_decode_opt_box_autoadd_hotword_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_hotword_config(raw);
+  }
+
+  @protected
+  LoudnessConfig? dco_decode_opt_box_autoadd_loudness_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_loudness_config(raw);
+  }
+
+  @protected
+  NoiseReductionConfig? dco_decode_opt_box_autoadd_noise_reduction_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_noise_reduction_config(raw);
+  }
+
+  @protected
+  ParsedRecipe? dco_decode_opt_box_autoadd_parsed_recipe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_parsed_recipe(raw);
+  }
+
+  @protected
+  PreprocessConfig? dco_decode_opt_box_autoadd_preprocess_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_preprocess_config(raw);
+  }
+
+  @protected
+  SourceVideo? dco_decode_opt_box_autoadd_source_video(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_source_video(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  XhsCacheConfig? dco_decode_opt_box_autoadd_xhs_cache_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_xhs_cache_config(raw);
+  }
+
+  @protected
+  XhsClientConfig? dco_decode_opt_box_autoadd_xhs_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_xhs_client_config(raw);
+  }
+
+  @protected
+  XhsVideo? dco_decode_opt_box_autoadd_xhs_video(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_xhs_video(raw);
+  }
+
+  @protected
+  ParsedRecipe dco_decode_parsed_recipe(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ParsedRecipe(
+      title: dco_decode_String(arr[0]),
+      ingredients: dco_decode_list_recipe_ingredient(arr[1]),
+      steps: dco_decode_list_String(arr[2]),
+      tips: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  Platform dco_decode_platform(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Platform.values[raw as int];
+  }
+
+  @protected
+  PostprocessConfig dco_decode_postprocess_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PostprocessConfig(
+      punctuation: dco_decode_punctuation_mode(arr[0]),
+      mixedEnglish: dco_decode_bool(arr[1]),
+      inverseTextNormalization: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  PreprocessConfig dco_decode_preprocess_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PreprocessConfig(
+      removeDc: dco_decode_bool(arr[0]),
+      highPassHz: dco_decode_u_32(arr[1]),
+      noiseReduction: dco_decode_opt_box_autoadd_noise_reduction_config(arr[2]),
+      loudness: dco_decode_opt_box_autoadd_loudness_config(arr[3]),
+    );
+  }
+
+  @protected
+  PunctuationMode dco_decode_punctuation_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PunctuationMode_Off();
+      case 1:
+        return PunctuationMode_Rules(pauseMs: dco_decode_u_32(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RecipeIngredient dco_decode_recipe_ingredient(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RecipeIngredient(
+      name: dco_decode_String(arr[0]),
+      amount: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  RecognizerPoolConfig dco_decode_recognizer_pool_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RecognizerPoolConfig(
+      maxInstances: dco_decode_u_32(arr[0]),
+      maxMemoryMb: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ResampleQuality dco_decode_resample_quality(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ResampleQuality.values[raw as int];
+  }
+
+  @protected
+  SegmentationPath dco_decode_segmentation_path(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SegmentationPath.values[raw as int];
+  }
+
+  @protected
+  SourceArticle dco_decode_source_article(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return SourceArticle(
+      platform: dco_decode_platform(arr[0]),
+      title: dco_decode_String(arr[1]),
+      desc: dco_decode_String(arr[2]),
+      author: dco_decode_source_author(arr[3]),
+      images: dco_decode_list_String(arr[4]),
+      video: dco_decode_opt_box_autoadd_source_video(arr[5]),
+      noteType: dco_decode_note_type(arr[6]),
+      recipe: dco_decode_opt_box_autoadd_parsed_recipe(arr[7]),
+    );
+  }
+
+  @protected
+  SourceAuthor dco_decode_source_author(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SourceAuthor(
+      name: dco_decode_String(arr[0]),
+      id: dco_decode_String(arr[1]),
+      avatar: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  SourceVideo dco_decode_source_video(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SourceVideo(
+      duration: dco_decode_i_64(arr[0]),
+      cover: dco_decode_String(arr[1]),
+      playUrl: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  Transcript dco_decode_transcript(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Transcript(
+      segments: dco_decode_list_transcript_segment(arr[0]),
+      segmentation: dco_decode_segmentation_path(arr[1]),
+      fallbackReason: dco_decode_opt_String(arr[2]),
+      modelPack: dco_decode_String(arr[3]),
+      language: dco_decode_opt_String(arr[4]),
+      languageDetected: dco_decode_bool(arr[5]),
+      events: dco_decode_list_audio_event(arr[6]),
+    );
+  }
+
+  @protected
+  TranscriptSegment dco_decode_transcript_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TranscriptSegment(
+      startMs: dco_decode_u_64(arr[0]),
+      endMs: dco_decode_u_64(arr[1]),
+      text: dco_decode_String(arr[2]),
+      tokens: dco_decode_list_transcript_token(arr[3]),
+      speaker: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  TranscriptToken dco_decode_transcript_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TranscriptToken(
+      token: dco_decode_String(arr[0]),
+      startMs: dco_decode_u_64(arr[1]),
+      confidence: dco_decode_opt_box_autoadd_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  VadConfig dco_decode_vad_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return VadConfig(
+      threshold: dco_decode_f_32(arr[0]),
+      minSilenceDurationMs: dco_decode_u_32(arr[1]),
+      minSpeechDurationMs: dco_decode_u_32(arr[2]),
+      numThreads: dco_decode_u_32(arr[3]),
+      bufferSizeSecs: dco_decode_f_32(arr[4]),
+      padMs: dco_decode_u_32(arr[5]),
+      mergeGapMs: dco_decode_u_32(arr[6]),
+      maxSegmentMs: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  VadFallback dco_decode_vad_fallback(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return VadFallback_Error();
+      case 1:
+        return VadFallback_FixedWindow(
+          windowMs: dco_decode_u_32(raw[1]),
+          overlapMs: dco_decode_u_32(raw[2]),
+        );
+      case 2:
+        return VadFallback_WholeFile();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  XhsApiError dco_decode_xhs_api_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return XhsApiError_UrlNotFound();
+      case 1:
+        return XhsApiError_InvalidLink(url: dco_decode_String(raw[1]));
+      case 2:
+        return XhsApiError_HttpStatus(
+          status: dco_decode_u_16(raw[1]),
+          url: dco_decode_opt_String(raw[2]),
+        );
+      case 3:
+        return XhsApiError_Network(
+          message: dco_decode_String(raw[1]),
+          timeout: dco_decode_bool(raw[2]),
+        );
+      case 4:
+        return XhsApiError_ClientConfig(message: dco_decode_String(raw[1]));
+      case 5:
+        return XhsApiError_Parse(message: dco_decode_String(raw[1]));
+      case 6:
+        return XhsApiError_Blocked(reason: dco_decode_xhs_block_reason(raw[1]));
+      case 7:
+        return XhsApiError_Io(message: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  XhsArticle dco_decode_xhs_article(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return XhsArticle(
+      title: dco_decode_String(arr[0]),
+      desc: dco_decode_String(arr[1]),
+      author: dco_decode_xhs_author(arr[2]),
+      images: dco_decode_list_String(arr[3]),
+      video: dco_decode_opt_box_autoadd_xhs_video(arr[4]),
+      noteType: dco_decode_note_type(arr[5]),
+    );
+  }
+
+  @protected
+  XhsAuthor dco_decode_xhs_author(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return XhsAuthor(
+      nickname: dco_decode_String(arr[0]),
+      userId: dco_decode_String(arr[1]),
+      avatar: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  XhsAuthorProfile dco_decode_xhs_author_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return XhsAuthorProfile(
+      author: dco_decode_xhs_author(arr[0]),
+      redId: dco_decode_String(arr[1]),
+      bio: dco_decode_String(arr[2]),
+      ipLocation: dco_decode_opt_String(arr[3]),
+      follows: dco_decode_u_64(arr[4]),
+      fans: dco_decode_u_64(arr[5]),
+      likedAndCollected: dco_decode_u_64(arr[6]),
+      notes: dco_decode_xhs_note_page(arr[7]),
+    );
+  }
+
+  @protected
+  XhsBlockReason dco_decode_xhs_block_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhsBlockReason.values[raw as int];
+  }
+
+  @protected
+  XhsCacheConfig dco_decode_xhs_cache_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return XhsCacheConfig(
+      cacheDir: dco_decode_String(arr[0]),
+      ttlSecs: dco_decode_u_64(arr[1]),
+      maxSizeBytes: dco_decode_u_64(arr[2]),
+      bypass: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  XhsClientConfig dco_decode_xhs_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return XhsClientConfig(
+      connectTimeoutMs: dco_decode_u_64(arr[0]),
+      readTimeoutMs: dco_decode_u_64(arr[1]),
+      proxy: dco_decode_opt_String(arr[2]),
+      cookieStore: dco_decode_bool(arr[3]),
+      cookies: dco_decode_list_String(arr[4]),
+      maxRedirects: dco_decode_u_32(arr[5]),
+      cache: dco_decode_opt_box_autoadd_xhs_cache_config(arr[6]),
+    );
+  }
+
+  @protected
+  XhsLink dco_decode_xhs_link(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return XhsLink(
+      kind: dco_decode_xhs_link_kind(arr[0]),
+      id: dco_decode_String(arr[1]),
+      xsecToken: dco_decode_opt_String(arr[2]),
+      xsecSource: dco_decode_opt_String(arr[3]),
+      url: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  XhsLinkKind dco_decode_xhs_link_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return XhsLinkKind.values[raw as int];
+  }
+
+  @protected
+  XhsNoteCard dco_decode_xhs_note_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return XhsNoteCard(
+      noteId: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      cover: dco_decode_String(arr[2]),
+      noteType: dco_decode_note_type(arr[3]),
+      likedCount: dco_decode_u_64(arr[4]),
+      xsecToken: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  XhsNotePage dco_decode_xhs_note_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return XhsNotePage(
+      notes: dco_decode_list_xhs_note_card(arr[0]),
+      cursor: dco_decode_String(arr[1]),
+      hasMore: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  XhsVideo dco_decode_xhs_video(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return XhsVideo(
+      duration: dco_decode_i_64(arr[0]),
+      cover: dco_decode_String(arr[1]),
+      playUrl: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  YFrameData dco_decode_y_frame_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return YFrameData(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      yPlane: dco_decode_list_prim_u_8_strict(arr[2]),
+      timestampMs: dco_decode_u_64(arr[3]),
+      frameNumber: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  AudioError
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AudioErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  AudioRecognizer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AudioRecognizerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ImportError
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ImportErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RecipeImporter
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RecipeImporterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  VideoFrameExtractor
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return VideoFrameExtractorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  AudioRecognizer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AudioRecognizerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RecipeImporter
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RecipeImporterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  VideoFrameExtractor
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return VideoFrameExtractorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  FutureOr<String> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_String_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  FutureOr<List<YFrameData>> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  FutureOr<void> Function(ImportProgress)
+  sse_decode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_usize(deserializer);
+    return decodeDartOpaque(inner, generalizedFrbRustBinding);
+  }
+
+  @protected
+  AudioError
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AudioErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  AudioRecognizer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return AudioRecognizerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ImportError
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ImportErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RecipeImporter
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RecipeImporterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  VideoFrameExtractor
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return VideoFrameExtractorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AsrConfig sse_decode_asr_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_decodingMethod = sse_decode_decoding_method(deserializer);
+    var var_numActivePaths = sse_decode_u_32(deserializer);
+    var var_numThreads = sse_decode_u_32(deserializer);
+    var var_endpoint = sse_decode_endpoint_config(deserializer);
+    var var_modelFiles = sse_decode_asr_model_files(deserializer);
+    return AsrConfig(
+      decodingMethod: var_decodingMethod,
+      numActivePaths: var_numActivePaths,
+      numThreads: var_numThreads,
+      endpoint: var_endpoint,
+      modelFiles: var_modelFiles,
+    );
+  }
+
+  @protected
+  AsrModelFiles sse_decode_asr_model_files(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_encoderParam = sse_decode_String(deserializer);
+    var var_encoderBin = sse_decode_String(deserializer);
+    var var_decoderParam = sse_decode_String(deserializer);
+    var var_decoderBin = sse_decode_String(deserializer);
+    var var_joinerParam = sse_decode_String(deserializer);
+    var var_joinerBin = sse_decode_String(deserializer);
+    var var_tokens = sse_decode_String(deserializer);
+    return AsrModelFiles(
+      encoderParam: var_encoderParam,
+      encoderBin: var_encoderBin,
+      decoderParam: var_decoderParam,
+      decoderBin: var_decoderBin,
+      joinerParam: var_joinerParam,
+      joinerBin: var_joinerBin,
+      tokens: var_tokens,
+    );
+  }
+
+  @protected
+  AudioEvent sse_decode_audio_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_audio_event_kind(deserializer);
+    var var_startMs = sse_decode_u_64(deserializer);
+    var var_endMs = sse_decode_u_64(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    return AudioEvent(
+      kind: var_kind,
+      startMs: var_startMs,
+      endMs: var_endMs,
+      confidence: var_confidence,
+    );
+  }
+
+  @protected
+  AudioEventKind sse_decode_audio_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AudioEventKind.values[inner];
+  }
+
+  @protected
+  AudioRecognizerConfig sse_decode_audio_recognizer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hotwords = sse_decode_opt_box_autoadd_hotword_config(deserializer);
+    var var_asr = sse_decode_asr_config(deserializer);
+    var var_modelPacks = sse_decode_list_model_pack_config(deserializer);
+    var var_pool = sse_decode_recognizer_pool_config(deserializer);
+    var var_vad = sse_decode_vad_config(deserializer);
+    var var_vadFallback = sse_decode_vad_fallback(deserializer);
+    var var_diarization = sse_decode_opt_box_autoadd_diarization_config(
+      deserializer,
+    );
+    var var_postprocess = sse_decode_postprocess_config(deserializer);
+    var var_resampleQuality = sse_decode_resample_quality(deserializer);
+    var var_preprocess = sse_decode_opt_box_autoadd_preprocess_config(
+      deserializer,
+    );
+    var var_events = sse_decode_opt_box_autoadd_event_detection_config(
+      deserializer,
+    );
+    return AudioRecognizerConfig(
+      hotwords: var_hotwords,
+      asr: var_asr,
+      modelPacks: var_modelPacks,
+      pool: var_pool,
+      vad: var_vad,
+      vadFallback: var_vadFallback,
+      diarization: var_diarization,
+      postprocess: var_postprocess,
+      resampleQuality: var_resampleQuality,
+      preprocess: var_preprocess,
+      events: var_events,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AudioRecognizerConfig sse_decode_box_autoadd_audio_recognizer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audio_recognizer_config(deserializer));
+  }
+
+  @protected
+  DiarizationConfig sse_decode_box_autoadd_diarization_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_diarization_config(deserializer));
+  }
+
+  @protected
+  EventDetectionConfig sse_decode_box_autoadd_event_detection_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_event_detection_config(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  HotwordConfig sse_decode_box_autoadd_hotword_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_hotword_config(deserializer));
+  }
+
+  @protected
+  LoudnessConfig sse_decode_box_autoadd_loudness_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_loudness_config(deserializer));
+  }
+
+  @protected
+  ModelValidationReport sse_decode_box_autoadd_model_validation_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_model_validation_report(deserializer));
+  }
+
+  @protected
+  NoiseReductionConfig sse_decode_box_autoadd_noise_reduction_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_noise_reduction_config(deserializer));
+  }
+
+  @protected
+  ParsedRecipe sse_decode_box_autoadd_parsed_recipe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_parsed_recipe(deserializer));
+  }
+
+  @protected
+  PreprocessConfig sse_decode_box_autoadd_preprocess_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_preprocess_config(deserializer));
+  }
+
+  @protected
+  SourceVideo sse_decode_box_autoadd_source_video(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_source_video(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  XhsApiError sse_decode_box_autoadd_xhs_api_error(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_xhs_api_error(deserializer));
+  }
+
+  @protected
+  XhsCacheConfig sse_decode_box_autoadd_xhs_cache_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_xhs_cache_config(deserializer));
+  }
+
+  @protected
+  XhsClientConfig sse_decode_box_autoadd_xhs_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_xhs_client_config(deserializer));
+  }
+
+  @protected
+  XhsVideo sse_decode_box_autoadd_xhs_video(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_xhs_video(deserializer));
+  }
+
+  @protected
+  DecodingMethod sse_decode_decoding_method(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DecodingMethod.values[inner];
+  }
+
+  @protected
+  DiarizationConfig sse_decode_diarization_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxSpeakers = sse_decode_u_32(deserializer);
+    var var_threshold = sse_decode_f_32(deserializer);
+    var var_minSegmentMs = sse_decode_u_32(deserializer);
+    return DiarizationConfig(
+      maxSpeakers: var_maxSpeakers,
+      threshold: var_threshold,
+      minSegmentMs: var_minSegmentMs,
+    );
+  }
+
+  @protected
+  EndpointConfig sse_decode_endpoint_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enable = sse_decode_bool(deserializer);
+    var var_rule1MinTrailingSilence = sse_decode_f_32(deserializer);
+    var var_rule2MinTrailingSilence = sse_decode_f_32(deserializer);
+    var var_rule3MinUtteranceLength = sse_decode_f_32(deserializer);
+    return EndpointConfig(
+      enable: var_enable,
+      rule1MinTrailingSilence: var_rule1MinTrailingSilence,
+      rule2MinTrailingSilence: var_rule2MinTrailingSilence,
+      rule3MinUtteranceLength: var_rule3MinUtteranceLength,
+    );
+  }
+
+  @protected
+  EventDetectionConfig sse_decode_event_detection_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minEventMs = sse_decode_u_32(deserializer);
+    var var_minConfidence = sse_decode_f_32(deserializer);
+    return EventDetectionConfig(
+      minEventMs: var_minEventMs,
+      minConfidence: var_minConfidence,
+    );
+  }
+
+  @protected
+  ExtractionStats sse_decode_extraction_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_processedFrames = sse_decode_u_64(deserializer);
+    var var_extractedFrames = sse_decode_u_64(deserializer);
+    return ExtractionStats(
+      processedFrames: var_processedFrames,
+      extractedFrames: var_extractedFrames,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  FrameExtractedInfo sse_decode_frame_extracted_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timestampMs = sse_decode_u_64(deserializer);
+    var var_frameNumber = sse_decode_u_64(deserializer);
+    var var_confidence = sse_decode_f_32(deserializer);
+    var var_jpegData = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return FrameExtractedInfo(
+      timestampMs: var_timestampMs,
+      frameNumber: var_frameNumber,
+      confidence: var_confidence,
+      jpegData: var_jpegData,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
+  @protected
+  Hotword sse_decode_hotword(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_phrase = sse_decode_String(deserializer);
+    var var_score = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return Hotword(phrase: var_phrase, score: var_score);
+  }
+
+  @protected
+  HotwordConfig sse_decode_hotword_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hotwords = sse_decode_list_hotword(deserializer);
+    var var_useCookingLexicon = sse_decode_bool(deserializer);
+    var var_defaultScore = sse_decode_f_32(deserializer);
+    var var_workDir = sse_decode_opt_String(deserializer);
+    return HotwordConfig(
+      hotwords: var_hotwords,
+      useCookingLexicon: var_useCookingLexicon,
+      defaultScore: var_defaultScore,
+      workDir: var_workDir,
+    );
+  }
+
+  @protected
+  int sse_decode_i_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt16();
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImportProgress sse_decode_import_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stage = sse_decode_import_stage(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return ImportProgress(stage: var_stage, message: var_message);
+  }
+
+  @protected
+  ImportStage sse_decode_import_stage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImportStage.values[inner];
+  }
+
+  @protected
+  ImportedRecipe sse_decode_imported_recipe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sourceUrl = sse_decode_String(deserializer);
+    var var_article = sse_decode_source_article(deserializer);
+    var var_transcript = sse_decode_opt_String(deserializer);
+    var var_keyframes = sse_decode_list_frame_extracted_info(deserializer);
+    var var_recipe = sse_decode_parsed_recipe(deserializer);
+    return ImportedRecipe(
+      sourceUrl: var_sourceUrl,
+      article: var_article,
+      transcript: var_transcript,
+      keyframes: var_keyframes,
+      recipe: var_recipe,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AudioEvent> sse_decode_list_audio_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AudioEvent>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audio_event(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FrameExtractedInfo> sse_decode_list_frame_extracted_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FrameExtractedInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_frame_extracted_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Hotword> sse_decode_list_hotword(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Hotword>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_hotword(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModelFileIssue> sse_decode_list_model_file_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModelFileIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_model_file_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModelPackConfig> sse_decode_list_model_pack_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModelPackConfig>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_model_pack_config(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_i_16_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt16List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RecipeIngredient> sse_decode_list_recipe_ingredient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RecipeIngredient>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_recipe_ingredient(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TranscriptSegment> sse_decode_list_transcript_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TranscriptSegment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transcript_segment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TranscriptToken> sse_decode_list_transcript_token(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TranscriptToken>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transcript_token(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<XhsNoteCard> sse_decode_list_xhs_note_card(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <XhsNoteCard>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_xhs_note_card(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<YFrameData> sse_decode_list_y_frame_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <YFrameData>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_y_frame_data(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LoudnessConfig sse_decode_loudness_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_measure = sse_decode_loudness_measure(deserializer);
+    var var_targetDb = sse_decode_f_32(deserializer);
+    var var_maxGainDb = sse_decode_f_32(deserializer);
+    var var_windowMs = sse_decode_u_32(deserializer);
+    return LoudnessConfig(
+      measure: var_measure,
+      targetDb: var_targetDb,
+      maxGainDb: var_maxGainDb,
+      windowMs: var_windowMs,
+    );
+  }
+
+  @protected
+  LoudnessMeasure sse_decode_loudness_measure(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LoudnessMeasure.values[inner];
+  }
+
+  @protected
+  ModelFileIssue sse_decode_model_file_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_kind = sse_decode_model_issue_kind(deserializer);
+    return ModelFileIssue(path: var_path, kind: var_kind);
+  }
+
+  @protected
+  ModelIssueKind sse_decode_model_issue_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ModelIssueKind_Missing();
+      case 1:
+        return ModelIssueKind_InvalidPath();
+      case 2:
+        var var_expected = sse_decode_u_64(deserializer);
+        var var_actual = sse_decode_u_64(deserializer);
+        return ModelIssueKind_SizeMismatch(
+          expected: var_expected,
+          actual: var_actual,
+        );
+      case 3:
+        var var_expected = sse_decode_String(deserializer);
+        var var_actual = sse_decode_String(deserializer);
+        return ModelIssueKind_ChecksumMismatch(
+          expected: var_expected,
+          actual: var_actual,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ModelPackConfig sse_decode_model_pack_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dir = sse_decode_String(deserializer);
+    var var_languages = sse_decode_list_String(deserializer);
+    return ModelPackConfig(dir: var_dir, languages: var_languages);
+  }
+
+  @protected
+  ModelValidationReport sse_decode_model_validation_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_checked = sse_decode_u_32(deserializer);
+    var var_checksumsVerified = sse_decode_bool(deserializer);
+    var var_issues = sse_decode_list_model_file_issue(deserializer);
+    return ModelValidationReport(
+      checked: var_checked,
+      checksumsVerified: var_checksumsVerified,
+      issues: var_issues,
+    );
+  }
+
+  @protected
+  NoiseReductionConfig sse_decode_noise_reduction_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_overSubtraction = sse_decode_f_32(deserializer);
+    var var_floorDb = sse_decode_f_32(deserializer);
+    return NoiseReductionConfig(
+      overSubtraction: var_overSubtraction,
+      floorDb: var_floorDb,
+    );
+  }
+
+  @protected
+  NoteType sse_decode_note_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NoteType.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AudioRecognizerConfig? sse_decode_opt_box_autoadd_audio_recognizer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audio_recognizer_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DiarizationConfig? sse_decode_opt_box_autoadd_diarization_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_diarization_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EventDetectionConfig? sse_decode_opt_box_autoadd_event_detection_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_event_detection_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  HotwordConfig? sse_decode_opt_box_autoadd_hotword_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_hotword_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LoudnessConfig? sse_decode_opt_box_autoadd_loudness_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_loudness_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NoiseReductionConfig? sse_decode_opt_box_autoadd_noise_reduction_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_noise_reduction_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ParsedRecipe? sse_decode_opt_box_autoadd_parsed_recipe(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_parsed_recipe(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PreprocessConfig? sse_decode_opt_box_autoadd_preprocess_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_preprocess_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SourceVideo? sse_decode_opt_box_autoadd_source_video(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_source_video(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  XhsCacheConfig? sse_decode_opt_box_autoadd_xhs_cache_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_xhs_cache_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  XhsClientConfig? sse_decode_opt_box_autoadd_xhs_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_xhs_client_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  XhsVideo? sse_decode_opt_box_autoadd_xhs_video(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_xhs_video(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ParsedRecipe sse_decode_parsed_recipe(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_ingredients = sse_decode_list_recipe_ingredient(deserializer);
+    var var_steps = sse_decode_list_String(deserializer);
+    var var_tips = sse_decode_list_String(deserializer);
+    return ParsedRecipe(
+      title: var_title,
+      ingredients: var_ingredients,
+      steps: var_steps,
+      tips: var_tips,
+    );
+  }
+
+  @protected
+  Platform sse_decode_platform(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Platform.values[inner];
+  }
+
+  @protected
+  PostprocessConfig sse_decode_postprocess_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_punctuation = sse_decode_punctuation_mode(deserializer);
+    var var_mixedEnglish = sse_decode_bool(deserializer);
+    var var_inverseTextNormalization = sse_decode_bool(deserializer);
+    return PostprocessConfig(
+      punctuation: var_punctuation,
+      mixedEnglish: var_mixedEnglish,
+      inverseTextNormalization: var_inverseTextNormalization,
+    );
+  }
+
+  @protected
+  PreprocessConfig sse_decode_preprocess_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_removeDc = sse_decode_bool(deserializer);
+    var var_highPassHz = sse_decode_u_32(deserializer);
+    var var_noiseReduction = sse_decode_opt_box_autoadd_noise_reduction_config(
+      deserializer,
+    );
+    var var_loudness = sse_decode_opt_box_autoadd_loudness_config(deserializer);
+    return PreprocessConfig(
+      removeDc: var_removeDc,
+      highPassHz: var_highPassHz,
+      noiseReduction: var_noiseReduction,
+      loudness: var_loudness,
+    );
+  }
+
+  @protected
+  PunctuationMode sse_decode_punctuation_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return PunctuationMode_Off();
+      case 1:
+        var var_pauseMs = sse_decode_u_32(deserializer);
+        return PunctuationMode_Rules(pauseMs: var_pauseMs);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  RecipeIngredient sse_decode_recipe_ingredient(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_amount = sse_decode_opt_String(deserializer);
+    return RecipeIngredient(name: var_name, amount: var_amount);
+  }
+
+  @protected
+  RecognizerPoolConfig sse_decode_recognizer_pool_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxInstances = sse_decode_u_32(deserializer);
+    var var_maxMemoryMb = sse_decode_u_32(deserializer);
+    return RecognizerPoolConfig(
+      maxInstances: var_maxInstances,
+      maxMemoryMb: var_maxMemoryMb,
+    );
+  }
+
+  @protected
+  ResampleQuality sse_decode_resample_quality(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ResampleQuality.values[inner];
+  }
+
+  @protected
+  SegmentationPath sse_decode_segmentation_path(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SegmentationPath.values[inner];
+  }
+
+  @protected
+  SourceArticle sse_decode_source_article(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_platform = sse_decode_platform(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_desc = sse_decode_String(deserializer);
+    var var_author = sse_decode_source_author(deserializer);
+    var var_images = sse_decode_list_String(deserializer);
+    var var_video = sse_decode_opt_box_autoadd_source_video(deserializer);
+    var var_noteType = sse_decode_note_type(deserializer);
+    var var_recipe = sse_decode_opt_box_autoadd_parsed_recipe(deserializer);
+    return SourceArticle(
+      platform: var_platform,
+      title: var_title,
+      desc: var_desc,
+      author: var_author,
+      images: var_images,
+      video: var_video,
+      noteType: var_noteType,
+      recipe: var_recipe,
+    );
+  }
+
+  @protected
+  SourceAuthor sse_decode_source_author(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_id = sse_decode_String(deserializer);
+    var var_avatar = sse_decode_String(deserializer);
+    return SourceAuthor(name: var_name, id: var_id, avatar: var_avatar);
+  }
+
+  @protected
+  SourceVideo sse_decode_source_video(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_i_64(deserializer);
+    var var_cover = sse_decode_String(deserializer);
+    var var_playUrl = sse_decode_String(deserializer);
+    return SourceVideo(
+      duration: var_duration,
+      cover: var_cover,
+      playUrl: var_playUrl,
+    );
+  }
+
+  @protected
+  Transcript sse_decode_transcript(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_segments = sse_decode_list_transcript_segment(deserializer);
+    var var_segmentation = sse_decode_segmentation_path(deserializer);
+    var var_fallbackReason = sse_decode_opt_String(deserializer);
+    var var_modelPack = sse_decode_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_languageDetected = sse_decode_bool(deserializer);
+    var var_events = sse_decode_list_audio_event(deserializer);
+    return Transcript(
+      segments: var_segments,
+      segmentation: var_segmentation,
+      fallbackReason: var_fallbackReason,
+      modelPack: var_modelPack,
+      language: var_language,
+      languageDetected: var_languageDetected,
+      events: var_events,
+    );
+  }
+
+  @protected
+  TranscriptSegment sse_decode_transcript_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_u_64(deserializer);
+    var var_endMs = sse_decode_u_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_tokens = sse_decode_list_transcript_token(deserializer);
+    var var_speaker = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return TranscriptSegment(
+      startMs: var_startMs,
+      endMs: var_endMs,
+      text: var_text,
+      tokens: var_tokens,
+      speaker: var_speaker,
+    );
+  }
+
+  @protected
+  TranscriptToken sse_decode_transcript_token(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_token = sse_decode_String(deserializer);
+    var var_startMs = sse_decode_u_64(deserializer);
+    var var_confidence = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return TranscriptToken(
+      token: var_token,
+      startMs: var_startMs,
+      confidence: var_confidence,
+    );
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  VadConfig sse_decode_vad_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_threshold = sse_decode_f_32(deserializer);
+    var var_minSilenceDurationMs = sse_decode_u_32(deserializer);
+    var var_minSpeechDurationMs = sse_decode_u_32(deserializer);
+    var var_numThreads = sse_decode_u_32(deserializer);
+    var var_bufferSizeSecs = sse_decode_f_32(deserializer);
+    var var_padMs = sse_decode_u_32(deserializer);
+    var var_mergeGapMs = sse_decode_u_32(deserializer);
+    var var_maxSegmentMs = sse_decode_u_32(deserializer);
+    return VadConfig(
+      threshold: var_threshold,
+      minSilenceDurationMs: var_minSilenceDurationMs,
+      minSpeechDurationMs: var_minSpeechDurationMs,
+      numThreads: var_numThreads,
+      bufferSizeSecs: var_bufferSizeSecs,
+      padMs: var_padMs,
+      mergeGapMs: var_mergeGapMs,
+      maxSegmentMs: var_maxSegmentMs,
+    );
+  }
+
+  @protected
+  VadFallback sse_decode_vad_fallback(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return VadFallback_Error();
+      case 1:
+        var var_windowMs = sse_decode_u_32(deserializer);
+        var var_overlapMs = sse_decode_u_32(deserializer);
+        return VadFallback_FixedWindow(
+          windowMs: var_windowMs,
+          overlapMs: var_overlapMs,
+        );
+      case 2:
+        return VadFallback_WholeFile();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  XhsApiError sse_decode_xhs_api_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return XhsApiError_UrlNotFound();
+      case 1:
+        var var_url = sse_decode_String(deserializer);
+        return XhsApiError_InvalidLink(url: var_url);
+      case 2:
+        var var_status = sse_decode_u_16(deserializer);
+        var var_url = sse_decode_opt_String(deserializer);
+        return XhsApiError_HttpStatus(status: var_status, url: var_url);
+      case 3:
+        var var_message = sse_decode_String(deserializer);
+        var var_timeout = sse_decode_bool(deserializer);
+        return XhsApiError_Network(message: var_message, timeout: var_timeout);
+      case 4:
+        var var_message = sse_decode_String(deserializer);
+        return XhsApiError_ClientConfig(message: var_message);
+      case 5:
+        var var_message = sse_decode_String(deserializer);
+        return XhsApiError_Parse(message: var_message);
+      case 6:
+        var var_reason = sse_decode_xhs_block_reason(deserializer);
+        return XhsApiError_Blocked(reason: var_reason);
+      case 7:
+        var var_message = sse_decode_String(deserializer);
+        return XhsApiError_Io(message: var_message);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  XhsArticle sse_decode_xhs_article(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_desc = sse_decode_String(deserializer);
+    var var_author = sse_decode_xhs_author(deserializer);
+    var var_images = sse_decode_list_String(deserializer);
+    var var_video = sse_decode_opt_box_autoadd_xhs_video(deserializer);
+    var var_noteType = sse_decode_note_type(deserializer);
+    return XhsArticle(
+      title: var_title,
+      desc: var_desc,
+      author: var_author,
+      images: var_images,
+      video: var_video,
+      noteType: var_noteType,
+    );
+  }
+
+  @protected
+  XhsAuthor sse_decode_xhs_author(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_nickname = sse_decode_String(deserializer);
+    var var_userId = sse_decode_String(deserializer);
+    var var_avatar = sse_decode_String(deserializer);
+    return XhsAuthor(
+      nickname: var_nickname,
+      userId: var_userId,
+      avatar: var_avatar,
+    );
+  }
+
+  @protected
+  XhsAuthorProfile sse_decode_xhs_author_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_author = sse_decode_xhs_author(deserializer);
+    var var_redId = sse_decode_String(deserializer);
+    var var_bio = sse_decode_String(deserializer);
+    var var_ipLocation = sse_decode_opt_String(deserializer);
+    var var_follows = sse_decode_u_64(deserializer);
+    var var_fans = sse_decode_u_64(deserializer);
+    var var_likedAndCollected = sse_decode_u_64(deserializer);
+    var var_notes = sse_decode_xhs_note_page(deserializer);
+    return XhsAuthorProfile(
+      author: var_author,
+      redId: var_redId,
+      bio: var_bio,
+      ipLocation: var_ipLocation,
+      follows: var_follows,
+      fans: var_fans,
+      likedAndCollected: var_likedAndCollected,
+      notes: var_notes,
+    );
+  }
+
+  @protected
+  XhsBlockReason sse_decode_xhs_block_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return XhsBlockReason.values[inner];
+  }
+
+  @protected
+  XhsCacheConfig sse_decode_xhs_cache_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cacheDir = sse_decode_String(deserializer);
+    var var_ttlSecs = sse_decode_u_64(deserializer);
+    var var_maxSizeBytes = sse_decode_u_64(deserializer);
+    var var_bypass = sse_decode_bool(deserializer);
+    return XhsCacheConfig(
+      cacheDir: var_cacheDir,
+      ttlSecs: var_ttlSecs,
+      maxSizeBytes: var_maxSizeBytes,
+      bypass: var_bypass,
+    );
+  }
+
+  @protected
+  XhsClientConfig sse_decode_xhs_client_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connectTimeoutMs = sse_decode_u_64(deserializer);
+    var var_readTimeoutMs = sse_decode_u_64(deserializer);
+    var var_proxy = sse_decode_opt_String(deserializer);
+    var var_cookieStore = sse_decode_bool(deserializer);
+    var var_cookies = sse_decode_list_String(deserializer);
+    var var_maxRedirects = sse_decode_u_32(deserializer);
+    var var_cache = sse_decode_opt_box_autoadd_xhs_cache_config(deserializer);
+    return XhsClientConfig(
+      connectTimeoutMs: var_connectTimeoutMs,
+      readTimeoutMs: var_readTimeoutMs,
+      proxy: var_proxy,
+      cookieStore: var_cookieStore,
+      cookies: var_cookies,
+      maxRedirects: var_maxRedirects,
+      cache: var_cache,
+    );
+  }
+
+  @protected
+  XhsLink sse_decode_xhs_link(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_xhs_link_kind(deserializer);
+    var var_id = sse_decode_String(deserializer);
+    var var_xsecToken = sse_decode_opt_String(deserializer);
+    var var_xsecSource = sse_decode_opt_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    return XhsLink(
+      kind: var_kind,
+      id: var_id,
+      xsecToken: var_xsecToken,
+      xsecSource: var_xsecSource,
+      url: var_url,
+    );
+  }
+
+  @protected
+  XhsLinkKind sse_decode_xhs_link_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return XhsLinkKind.values[inner];
+  }
+
+  @protected
+  XhsNoteCard sse_decode_xhs_note_card(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_noteId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_cover = sse_decode_String(deserializer);
+    var var_noteType = sse_decode_note_type(deserializer);
+    var var_likedCount = sse_decode_u_64(deserializer);
+    var var_xsecToken = sse_decode_opt_String(deserializer);
+    return XhsNoteCard(
+      noteId: var_noteId,
+      title: var_title,
+      cover: var_cover,
+      noteType: var_noteType,
+      likedCount: var_likedCount,
+      xsecToken: var_xsecToken,
+    );
+  }
+
+  @protected
+  XhsNotePage sse_decode_xhs_note_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_notes = sse_decode_list_xhs_note_card(deserializer);
+    var var_cursor = sse_decode_String(deserializer);
+    var var_hasMore = sse_decode_bool(deserializer);
+    return XhsNotePage(
+      notes: var_notes,
+      cursor: var_cursor,
+      hasMore: var_hasMore,
+    );
+  }
+
+  @protected
+  XhsVideo sse_decode_xhs_video(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_duration = sse_decode_i_64(deserializer);
+    var var_cover = sse_decode_String(deserializer);
+    var var_playUrl = sse_decode_String(deserializer);
+    return XhsVideo(
+      duration: var_duration,
+      cover: var_cover,
+      playUrl: var_playUrl,
+    );
+  }
+
+  @protected
+  YFrameData sse_decode_y_frame_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_yPlane = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_timestampMs = sse_decode_u_64(deserializer);
+    var var_frameNumber = sse_decode_u_64(deserializer);
+    return YFrameData(
+      width: var_width,
+      height: var_height,
+      yPlane: var_yPlane,
+      timestampMs: var_timestampMs,
+      frameNumber: var_frameNumber,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    AudioError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AudioErrorImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    AudioRecognizer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AudioRecognizerImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    ImportError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ImportErrorImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    RecipeImporter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RecipeImporterImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    VideoFrameExtractor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as VideoFrameExtractorImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    AudioRecognizer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AudioRecognizerImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    RecipeImporter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RecipeImporterImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    VideoFrameExtractor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as VideoFrameExtractorImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_String_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    FutureOr<List<YFrameData>> Function(String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+        self,
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(ImportProgress) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(self),
+      serializer,
+    );
+  }
 
-  @override
-  XhsArticle crateApiXhsParseXhsFromUrl({required String url}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(url, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_xhs_article,
-          decodeErrorData: sse_decode_xhs_api_error,
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      PlatformPointerUtil.ptrToBigInt(
+        encodeDartOpaque(
+          self,
+          portManager.dartHandlerPort,
+          generalizedFrbRustBinding,
         ),
-        constMeta: kCrateApiXhsParseXhsFromUrlConstMeta,
-        argValues: [url],
-        apiImpl: this,
       ),
+      serializer,
     );
   }
 
-  TaskConstMeta get kCrateApiXhsParseXhsFromUrlConstMeta =>
-      const TaskConstMeta(debugName: "parse_xhs_from_url", argNames: ["url"]);
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    AudioError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AudioErrorImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AudioError => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError;
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    AudioRecognizer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as AudioRecognizerImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AudioError => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError;
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    ImportError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ImportErrorImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AudioRecognizer => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer;
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    RecipeImporter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as RecipeImporterImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AudioRecognizer => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer;
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    VideoFrameExtractor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as VideoFrameExtractorImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_VideoFrameExtractor => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor;
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_VideoFrameExtractor => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor;
+  @protected
+  void sse_encode_asr_config(AsrConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_decoding_method(self.decodingMethod, serializer);
+    sse_encode_u_32(self.numActivePaths, serializer);
+    sse_encode_u_32(self.numThreads, serializer);
+    sse_encode_endpoint_config(self.endpoint, serializer);
+    sse_encode_asr_model_files(self.modelFiles, serializer);
+  }
 
   @protected
-  AudioError
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    dynamic raw,
+  void sse_encode_asr_model_files(
+    AsrModelFiles self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.encoderParam, serializer);
+    sse_encode_String(self.encoderBin, serializer);
+    sse_encode_String(self.decoderParam, serializer);
+    sse_encode_String(self.decoderBin, serializer);
+    sse_encode_String(self.joinerParam, serializer);
+    sse_encode_String(self.joinerBin, serializer);
+    sse_encode_String(self.tokens, serializer);
   }
 
   @protected
-  AudioRecognizer
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    dynamic raw,
+  void sse_encode_audio_event(AudioEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_event_kind(self.kind, serializer);
+    sse_encode_u_64(self.startMs, serializer);
+    sse_encode_u_64(self.endMs, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_event_kind(
+    AudioEventKind self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioRecognizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  VideoFrameExtractor
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    dynamic raw,
+  void sse_encode_audio_recognizer_config(
+    AudioRecognizerConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return VideoFrameExtractorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_hotword_config(self.hotwords, serializer);
+    sse_encode_asr_config(self.asr, serializer);
+    sse_encode_list_model_pack_config(self.modelPacks, serializer);
+    sse_encode_recognizer_pool_config(self.pool, serializer);
+    sse_encode_vad_config(self.vad, serializer);
+    sse_encode_vad_fallback(self.vadFallback, serializer);
+    sse_encode_opt_box_autoadd_diarization_config(self.diarization, serializer);
+    sse_encode_postprocess_config(self.postprocess, serializer);
+    sse_encode_resample_quality(self.resampleQuality, serializer);
+    sse_encode_opt_box_autoadd_preprocess_config(self.preprocess, serializer);
+    sse_encode_opt_box_autoadd_event_detection_config(self.events, serializer);
   }
 
   @protected
-  AudioRecognizer
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    dynamic raw,
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audio_recognizer_config(
+    AudioRecognizerConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioRecognizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audio_recognizer_config(self, serializer);
   }
 
   @protected
-  VideoFrameExtractor
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    dynamic raw,
+  void sse_encode_box_autoadd_diarization_config(
+    DiarizationConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return VideoFrameExtractorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_diarization_config(self, serializer);
   }
 
   @protected
-  AudioError
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    dynamic raw,
+  void sse_encode_box_autoadd_event_detection_config(
+    EventDetectionConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_detection_config(self, serializer);
   }
 
   @protected
-  AudioRecognizer
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    dynamic raw,
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_hotword_config(
+    HotwordConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AudioRecognizerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hotword_config(self, serializer);
   }
 
   @protected
-  VideoFrameExtractor
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    dynamic raw,
+  void sse_encode_box_autoadd_loudness_config(
+    LoudnessConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return VideoFrameExtractorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_loudness_config(self, serializer);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_box_autoadd_model_validation_report(
+    ModelValidationReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_model_validation_report(self, serializer);
   }
 
   @protected
-  XhsVideo dco_decode_box_autoadd_xhs_video(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_xhs_video(raw);
+  void sse_encode_box_autoadd_noise_reduction_config(
+    NoiseReductionConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_noise_reduction_config(self, serializer);
   }
 
   @protected
-  ExtractionStats dco_decode_extraction_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ExtractionStats(
-      processedFrames: dco_decode_u_64(arr[0]),
-      extractedFrames: dco_decode_u_64(arr[1]),
-    );
+  void sse_encode_box_autoadd_parsed_recipe(
+    ParsedRecipe self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_parsed_recipe(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_preprocess_config(
+    PreprocessConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_preprocess_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_source_video(
+    SourceVideo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_source_video(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_xhs_api_error(
+    XhsApiError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_xhs_api_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_xhs_cache_config(
+    XhsCacheConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_xhs_cache_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_xhs_client_config(
+    XhsClientConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_xhs_client_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_xhs_video(
+    XhsVideo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_xhs_video(self, serializer);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_decoding_method(
+    DecodingMethod self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  FrameExtractedInfo dco_decode_frame_extracted_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return FrameExtractedInfo(
-      timestampMs: dco_decode_u_64(arr[0]),
-      frameNumber: dco_decode_u_64(arr[1]),
-      confidence: dco_decode_f_32(arr[2]),
-      jpegData: dco_decode_list_prim_u_8_strict(arr[3]),
-      width: dco_decode_u_32(arr[4]),
-      height: dco_decode_u_32(arr[5]),
-    );
+  void sse_encode_diarization_config(
+    DiarizationConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxSpeakers, serializer);
+    sse_encode_f_32(self.threshold, serializer);
+    sse_encode_u_32(self.minSegmentMs, serializer);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_endpoint_config(
+    EndpointConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enable, serializer);
+    sse_encode_f_32(self.rule1MinTrailingSilence, serializer);
+    sse_encode_f_32(self.rule2MinTrailingSilence, serializer);
+    sse_encode_f_32(self.rule3MinUtteranceLength, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_event_detection_config(
+    EventDetectionConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.minEventMs, serializer);
+    sse_encode_f_32(self.minConfidence, serializer);
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_extraction_stats(
+    ExtractionStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.processedFrames, serializer);
+    sse_encode_u_64(self.extractedFrames, serializer);
   }
 
   @protected
-  List<FrameExtractedInfo> dco_decode_list_frame_extracted_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_frame_extracted_info).toList();
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_frame_extracted_info(
+    FrameExtractedInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timestampMs, serializer);
+    sse_encode_u_64(self.frameNumber, serializer);
+    sse_encode_f_32(self.confidence, serializer);
+    sse_encode_list_prim_u_8_strict(self.jpegData, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
   }
 
   @protected
-  List<YFrameData> dco_decode_list_y_frame_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_y_frame_data).toList();
+  void sse_encode_hotword(Hotword self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.phrase, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.score, serializer);
   }
 
   @protected
-  NoteType dco_decode_note_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NoteType.values[raw as int];
+  void sse_encode_hotword_config(HotwordConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_hotword(self.hotwords, serializer);
+    sse_encode_bool(self.useCookingLexicon, serializer);
+    sse_encode_f_32(self.defaultScore, serializer);
+    sse_encode_opt_String(self.workDir, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_i_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt16(self);
   }
 
   @protected
-  XhsVideo? dco_decode_opt_box_autoadd_xhs_video(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_xhs_video(raw);
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_import_progress(
+    ImportProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_stage(self.stage, serializer);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_import_stage(ImportStage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_imported_recipe(
+    ImportedRecipe self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sourceUrl, serializer);
+    sse_encode_source_article(self.article, serializer);
+    sse_encode_opt_String(self.transcript, serializer);
+    sse_encode_list_frame_extracted_info(self.keyframes, serializer);
+    sse_encode_parsed_recipe(self.recipe, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  XhsApiError dco_decode_xhs_api_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return XhsApiError(
-      errorType: dco_decode_String(arr[0]),
-      message: dco_decode_String(arr[1]),
-    );
+  void sse_encode_list_audio_event(
+    List<AudioEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audio_event(item, serializer);
+    }
   }
 
   @protected
-  XhsArticle dco_decode_xhs_article(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return XhsArticle(
-      title: dco_decode_String(arr[0]),
-      desc: dco_decode_String(arr[1]),
-      author: dco_decode_xhs_author(arr[2]),
-      images: dco_decode_list_String(arr[3]),
-      video: dco_decode_opt_box_autoadd_xhs_video(arr[4]),
-      noteType: dco_decode_note_type(arr[5]),
-    );
+  void sse_encode_list_frame_extracted_info(
+    List<FrameExtractedInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_frame_extracted_info(item, serializer);
+    }
   }
 
   @protected
-  XhsAuthor dco_decode_xhs_author(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return XhsAuthor(
-      nickname: dco_decode_String(arr[0]),
-      userId: dco_decode_String(arr[1]),
-      avatar: dco_decode_String(arr[2]),
-    );
+  void sse_encode_list_hotword(List<Hotword> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_hotword(item, serializer);
+    }
   }
 
   @protected
-  XhsVideo dco_decode_xhs_video(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return XhsVideo(
-      duration: dco_decode_i_64(arr[0]),
-      cover: dco_decode_String(arr[1]),
-      playUrl: dco_decode_String(arr[2]),
-    );
+  void sse_encode_list_model_file_issue(
+    List<ModelFileIssue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_model_file_issue(item, serializer);
+    }
   }
 
   @protected
-  YFrameData dco_decode_y_frame_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return YFrameData(
-      width: dco_decode_u_32(arr[0]),
-      height: dco_decode_u_32(arr[1]),
-      yPlane: dco_decode_list_prim_u_8_strict(arr[2]),
-      timestampMs: dco_decode_u_64(arr[3]),
-      frameNumber: dco_decode_u_64(arr[4]),
-    );
+  void sse_encode_list_model_pack_config(
+    List<ModelPackConfig> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_model_pack_config(item, serializer);
+    }
   }
 
   @protected
-  AudioError
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioErrorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(
+      self is Float32List ? self : Float32List.fromList(self),
     );
   }
 
   @protected
-  AudioRecognizer
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_i_16_loose(
+    List<int> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioRecognizerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt16List(
+      self is Int16List ? self : Int16List.fromList(self),
     );
   }
 
   @protected
-  VideoFrameExtractor
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return VideoFrameExtractorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  AudioRecognizer
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    SseDeserializer deserializer,
+  void sse_encode_list_recipe_ingredient(
+    List<RecipeIngredient> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioRecognizerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_recipe_ingredient(item, serializer);
+    }
   }
 
   @protected
-  VideoFrameExtractor
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    SseDeserializer deserializer,
+  void sse_encode_list_transcript_segment(
+    List<TranscriptSegment> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return VideoFrameExtractorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_transcript_segment(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_transcript_token(
+    List<TranscriptToken> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_transcript_token(item, serializer);
+    }
   }
 
   @protected
-  AudioError
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    SseDeserializer deserializer,
+  void sse_encode_list_xhs_note_card(
+    List<XhsNoteCard> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioErrorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_xhs_note_card(item, serializer);
+    }
   }
 
   @protected
-  AudioRecognizer
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    SseDeserializer deserializer,
+  void sse_encode_list_y_frame_data(
+    List<YFrameData> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return AudioRecognizerImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_y_frame_data(item, serializer);
+    }
   }
 
   @protected
-  VideoFrameExtractor
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    SseDeserializer deserializer,
+  void sse_encode_loudness_config(
+    LoudnessConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return VideoFrameExtractorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_loudness_measure(self.measure, serializer);
+    sse_encode_f_32(self.targetDb, serializer);
+    sse_encode_f_32(self.maxGainDb, serializer);
+    sse_encode_u_32(self.windowMs, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_loudness_measure(
+    LoudnessMeasure self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  XhsVideo sse_decode_box_autoadd_xhs_video(SseDeserializer deserializer) {
+  void sse_encode_model_file_issue(
+    ModelFileIssue self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_xhs_video(deserializer));
+    sse_encode_String(self.path, serializer);
+    sse_encode_model_issue_kind(self.kind, serializer);
   }
 
   @protected
-  ExtractionStats sse_decode_extraction_stats(SseDeserializer deserializer) {
+  void sse_encode_model_issue_kind(
+    ModelIssueKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_processedFrames = sse_decode_u_64(deserializer);
-    var var_extractedFrames = sse_decode_u_64(deserializer);
-    return ExtractionStats(
-      processedFrames: var_processedFrames,
-      extractedFrames: var_extractedFrames,
-    );
+    switch (self) {
+      case ModelIssueKind_Missing():
+        sse_encode_i_32(0, serializer);
+      case ModelIssueKind_InvalidPath():
+        sse_encode_i_32(1, serializer);
+      case ModelIssueKind_SizeMismatch(
+        expected: final expected,
+        actual: final actual,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_64(expected, serializer);
+        sse_encode_u_64(actual, serializer);
+      case ModelIssueKind_ChecksumMismatch(
+        expected: final expected,
+        actual: final actual,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(expected, serializer);
+        sse_encode_String(actual, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_model_pack_config(
+    ModelPackConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_String(self.dir, serializer);
+    sse_encode_list_String(self.languages, serializer);
   }
 
   @protected
-  FrameExtractedInfo sse_decode_frame_extracted_info(
-    SseDeserializer deserializer,
+  void sse_encode_model_validation_report(
+    ModelValidationReport self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timestampMs = sse_decode_u_64(deserializer);
-    var var_frameNumber = sse_decode_u_64(deserializer);
-    var var_confidence = sse_decode_f_32(deserializer);
-    var var_jpegData = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    return FrameExtractedInfo(
-      timestampMs: var_timestampMs,
-      frameNumber: var_frameNumber,
-      confidence: var_confidence,
-      jpegData: var_jpegData,
-      width: var_width,
-      height: var_height,
-    );
+    sse_encode_u_32(self.checked, serializer);
+    sse_encode_bool(self.checksumsVerified, serializer);
+    sse_encode_list_model_file_issue(self.issues, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_noise_reduction_config(
+    NoiseReductionConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_f_32(self.overSubtraction, serializer);
+    sse_encode_f_32(self.floorDb, serializer);
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_note_type(NoteType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<FrameExtractedInfo> sse_decode_list_frame_extracted_info(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_audio_recognizer_config(
+    AudioRecognizerConfig? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FrameExtractedInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_frame_extracted_info(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audio_recognizer_config(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_diarization_config(
+    DiarizationConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_diarization_config(self, serializer);
+    }
   }
 
   @protected
-  List<YFrameData> sse_decode_list_y_frame_data(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_event_detection_config(
+    EventDetectionConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <YFrameData>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_y_frame_data(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_event_detection_config(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  NoteType sse_decode_note_type(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return NoteType.values[inner];
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_hotword_config(
+    HotwordConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_hotword_config(self, serializer);
     }
   }
 
   @protected
-  XhsVideo? sse_decode_opt_box_autoadd_xhs_video(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_loudness_config(
+    LoudnessConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_xhs_video(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_loudness_config(self, serializer);
     }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_noise_reduction_config(
+    NoiseReductionConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_noise_reduction_config(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_parsed_recipe(
+    ParsedRecipe? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_parsed_recipe(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_preprocess_config(
+    PreprocessConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_preprocess_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_source_video(
+    SourceVideo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_source_video(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  XhsApiError sse_decode_xhs_api_error(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_xhs_cache_config(
+    XhsCacheConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_errorType = sse_decode_String(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    return XhsApiError(errorType: var_errorType, message: var_message);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_xhs_cache_config(self, serializer);
+    }
   }
 
   @protected
-  XhsArticle sse_decode_xhs_article(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_xhs_client_config(
+    XhsClientConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_String(deserializer);
-    var var_desc = sse_decode_String(deserializer);
-    var var_author = sse_decode_xhs_author(deserializer);
-    var var_images = sse_decode_list_String(deserializer);
-    var var_video = sse_decode_opt_box_autoadd_xhs_video(deserializer);
-    var var_noteType = sse_decode_note_type(deserializer);
-    return XhsArticle(
-      title: var_title,
-      desc: var_desc,
-      author: var_author,
-      images: var_images,
-      video: var_video,
-      noteType: var_noteType,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_xhs_client_config(self, serializer);
+    }
   }
 
   @protected
-  XhsAuthor sse_decode_xhs_author(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_xhs_video(
+    XhsVideo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_nickname = sse_decode_String(deserializer);
-    var var_userId = sse_decode_String(deserializer);
-    var var_avatar = sse_decode_String(deserializer);
-    return XhsAuthor(
-      nickname: var_nickname,
-      userId: var_userId,
-      avatar: var_avatar,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_xhs_video(self, serializer);
+    }
   }
 
   @protected
-  XhsVideo sse_decode_xhs_video(SseDeserializer deserializer) {
+  void sse_encode_parsed_recipe(ParsedRecipe self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_duration = sse_decode_i_64(deserializer);
-    var var_cover = sse_decode_String(deserializer);
-    var var_playUrl = sse_decode_String(deserializer);
-    return XhsVideo(
-      duration: var_duration,
-      cover: var_cover,
-      playUrl: var_playUrl,
-    );
+    sse_encode_String(self.title, serializer);
+    sse_encode_list_recipe_ingredient(self.ingredients, serializer);
+    sse_encode_list_String(self.steps, serializer);
+    sse_encode_list_String(self.tips, serializer);
   }
 
   @protected
-  YFrameData sse_decode_y_frame_data(SseDeserializer deserializer) {
+  void sse_encode_platform(Platform self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_yPlane = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_timestampMs = sse_decode_u_64(deserializer);
-    var var_frameNumber = sse_decode_u_64(deserializer);
-    return YFrameData(
-      width: var_width,
-      height: var_height,
-      yPlane: var_yPlane,
-      timestampMs: var_timestampMs,
-      frameNumber: var_frameNumber,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_postprocess_config(
+    PostprocessConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_punctuation_mode(self.punctuation, serializer);
+    sse_encode_bool(self.mixedEnglish, serializer);
+    sse_encode_bool(self.inverseTextNormalization, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    AudioError self,
+  void sse_encode_preprocess_config(
+    PreprocessConfig self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioErrorImpl).frbInternalSseEncode(move: true),
+    sse_encode_bool(self.removeDc, serializer);
+    sse_encode_u_32(self.highPassHz, serializer);
+    sse_encode_opt_box_autoadd_noise_reduction_config(
+      self.noiseReduction,
       serializer,
     );
+    sse_encode_opt_box_autoadd_loudness_config(self.loudness, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    AudioRecognizer self,
+  void sse_encode_punctuation_mode(
+    PunctuationMode self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioRecognizerImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    switch (self) {
+      case PunctuationMode_Off():
+        sse_encode_i_32(0, serializer);
+      case PunctuationMode_Rules(pauseMs: final pauseMs):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(pauseMs, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    VideoFrameExtractor self,
+  void sse_encode_recipe_ingredient(
+    RecipeIngredient self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as VideoFrameExtractorImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.amount, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    AudioRecognizer self,
+  void sse_encode_recognizer_pool_config(
+    RecognizerPoolConfig self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioRecognizerImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_u_32(self.maxInstances, serializer);
+    sse_encode_u_32(self.maxMemoryMb, serializer);
   }
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    VideoFrameExtractor self,
+  void sse_encode_resample_quality(
+    ResampleQuality self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as VideoFrameExtractorImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    AudioError self,
+  void sse_encode_segmentation_path(
+    SegmentationPath self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioErrorImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    AudioRecognizer self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_source_article(SourceArticle self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AudioRecognizerImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_platform(self.platform, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.desc, serializer);
+    sse_encode_source_author(self.author, serializer);
+    sse_encode_list_String(self.images, serializer);
+    sse_encode_opt_box_autoadd_source_video(self.video, serializer);
+    sse_encode_note_type(self.noteType, serializer);
+    sse_encode_opt_box_autoadd_parsed_recipe(self.recipe, serializer);
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    VideoFrameExtractor self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_source_author(SourceAuthor self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as VideoFrameExtractorImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.avatar, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_source_video(SourceVideo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_64(self.duration, serializer);
+    sse_encode_String(self.cover, serializer);
+    sse_encode_String(self.playUrl, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_xhs_video(
-    XhsVideo self,
+  void sse_encode_transcript(Transcript self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_transcript_segment(self.segments, serializer);
+    sse_encode_segmentation_path(self.segmentation, serializer);
+    sse_encode_opt_String(self.fallbackReason, serializer);
+    sse_encode_String(self.modelPack, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_bool(self.languageDetected, serializer);
+    sse_encode_list_audio_event(self.events, serializer);
+  }
+
+  @protected
+  void sse_encode_transcript_segment(
+    TranscriptSegment self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_xhs_video(self, serializer);
+    sse_encode_u_64(self.startMs, serializer);
+    sse_encode_u_64(self.endMs, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_transcript_token(self.tokens, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.speaker, serializer);
   }
 
   @protected
-  void sse_encode_extraction_stats(
-    ExtractionStats self,
+  void sse_encode_transcript_token(
+    TranscriptToken self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.processedFrames, serializer);
-    sse_encode_u_64(self.extractedFrames, serializer);
+    sse_encode_String(self.token, serializer);
+    sse_encode_u_64(self.startMs, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.confidence, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    serializer.buffer.putUint16(self);
   }
 
   @protected
-  void sse_encode_frame_extracted_info(
-    FrameExtractedInfo self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.timestampMs, serializer);
-    sse_encode_u_64(self.frameNumber, serializer);
-    sse_encode_f_32(self.confidence, serializer);
-    sse_encode_list_prim_u_8_strict(self.jpegData, serializer);
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    serializer.buffer.putUint8(self);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
   }
 
   @protected
-  void sse_encode_list_frame_extracted_info(
-    List<FrameExtractedInfo> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_frame_extracted_info(item, serializer);
-    }
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_vad_config(VadConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_f_32(self.threshold, serializer);
+    sse_encode_u_32(self.minSilenceDurationMs, serializer);
+    sse_encode_u_32(self.minSpeechDurationMs, serializer);
+    sse_encode_u_32(self.numThreads, serializer);
+    sse_encode_f_32(self.bufferSizeSecs, serializer);
+    sse_encode_u_32(self.padMs, serializer);
+    sse_encode_u_32(self.mergeGapMs, serializer);
+    sse_encode_u_32(self.maxSegmentMs, serializer);
   }
 
   @protected
-  void sse_encode_list_y_frame_data(
-    List<YFrameData> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_vad_fallback(VadFallback self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_y_frame_data(item, serializer);
+    switch (self) {
+      case VadFallback_Error():
+        sse_encode_i_32(0, serializer);
+      case VadFallback_FixedWindow(
+        windowMs: final windowMs,
+        overlapMs: final overlapMs,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(windowMs, serializer);
+        sse_encode_u_32(overlapMs, serializer);
+      case VadFallback_WholeFile():
+        sse_encode_i_32(2, serializer);
     }
   }
 
   @protected
-  void sse_encode_note_type(NoteType self, SseSerializer serializer) {
+  void sse_encode_xhs_api_error(XhsApiError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    switch (self) {
+      case XhsApiError_UrlNotFound():
+        sse_encode_i_32(0, serializer);
+      case XhsApiError_InvalidLink(url: final url):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(url, serializer);
+      case XhsApiError_HttpStatus(status: final status, url: final url):
+        sse_encode_i_32(2, serializer);
+        sse_encode_u_16(status, serializer);
+        sse_encode_opt_String(url, serializer);
+      case XhsApiError_Network(message: final message, timeout: final timeout):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(message, serializer);
+        sse_encode_bool(timeout, serializer);
+      case XhsApiError_ClientConfig(message: final message):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(message, serializer);
+      case XhsApiError_Parse(message: final message):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(message, serializer);
+      case XhsApiError_Blocked(reason: final reason):
+        sse_encode_i_32(6, serializer);
+        sse_encode_xhs_block_reason(reason, serializer);
+      case XhsApiError_Io(message: final message):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(message, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_xhs_article(XhsArticle self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.desc, serializer);
+    sse_encode_xhs_author(self.author, serializer);
+    sse_encode_list_String(self.images, serializer);
+    sse_encode_opt_box_autoadd_xhs_video(self.video, serializer);
+    sse_encode_note_type(self.noteType, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_xhs_video(
-    XhsVideo? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_xhs_author(XhsAuthor self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_xhs_video(self, serializer);
-    }
+    sse_encode_String(self.nickname, serializer);
+    sse_encode_String(self.userId, serializer);
+    sse_encode_String(self.avatar, serializer);
   }
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
+  void sse_encode_xhs_author_profile(
+    XhsAuthorProfile self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
+    sse_encode_xhs_author(self.author, serializer);
+    sse_encode_String(self.redId, serializer);
+    sse_encode_String(self.bio, serializer);
+    sse_encode_opt_String(self.ipLocation, serializer);
+    sse_encode_u_64(self.follows, serializer);
+    sse_encode_u_64(self.fans, serializer);
+    sse_encode_u_64(self.likedAndCollected, serializer);
+    sse_encode_xhs_note_page(self.notes, serializer);
   }
 
   @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_xhs_block_reason(
+    XhsBlockReason self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_xhs_cache_config(
+    XhsCacheConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    sse_encode_String(self.cacheDir, serializer);
+    sse_encode_u_64(self.ttlSecs, serializer);
+    sse_encode_u_64(self.maxSizeBytes, serializer);
+    sse_encode_bool(self.bypass, serializer);
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_xhs_client_config(
+    XhsClientConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.connectTimeoutMs, serializer);
+    sse_encode_u_64(self.readTimeoutMs, serializer);
+    sse_encode_opt_String(self.proxy, serializer);
+    sse_encode_bool(self.cookieStore, serializer);
+    sse_encode_list_String(self.cookies, serializer);
+    sse_encode_u_32(self.maxRedirects, serializer);
+    sse_encode_opt_box_autoadd_xhs_cache_config(self.cache, serializer);
   }
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_xhs_link(XhsLink self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    sse_encode_xhs_link_kind(self.kind, serializer);
+    sse_encode_String(self.id, serializer);
+    sse_encode_opt_String(self.xsecToken, serializer);
+    sse_encode_opt_String(self.xsecSource, serializer);
+    sse_encode_String(self.url, serializer);
   }
 
   @protected
-  void sse_encode_xhs_api_error(XhsApiError self, SseSerializer serializer) {
+  void sse_encode_xhs_link_kind(XhsLinkKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.errorType, serializer);
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_xhs_article(XhsArticle self, SseSerializer serializer) {
+  void sse_encode_xhs_note_card(XhsNoteCard self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.noteId, serializer);
     sse_encode_String(self.title, serializer);
-    sse_encode_String(self.desc, serializer);
-    sse_encode_xhs_author(self.author, serializer);
-    sse_encode_list_String(self.images, serializer);
-    sse_encode_opt_box_autoadd_xhs_video(self.video, serializer);
+    sse_encode_String(self.cover, serializer);
     sse_encode_note_type(self.noteType, serializer);
+    sse_encode_u_64(self.likedCount, serializer);
+    sse_encode_opt_String(self.xsecToken, serializer);
   }
 
   @protected
-  void sse_encode_xhs_author(XhsAuthor self, SseSerializer serializer) {
+  void sse_encode_xhs_note_page(XhsNotePage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.nickname, serializer);
-    sse_encode_String(self.userId, serializer);
-    sse_encode_String(self.avatar, serializer);
+    sse_encode_list_xhs_note_card(self.notes, serializer);
+    sse_encode_String(self.cursor, serializer);
+    sse_encode_bool(self.hasMore, serializer);
   }
 
   @protected
@@ -1394,12 +6053,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.timestampMs, serializer);
     sse_encode_u_64(self.frameNumber, serializer);
   }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
 }
 
 @sealed
@@ -1446,12 +6099,119 @@ class AudioRecognizerImpl extends RustOpaque implements AudioRecognizer {
       RustLib.instance.api.crateApiAudioAudioRecognizerModelsDir(that: this);
 
   /// 转录音频文件（WAV 格式）
+  ///
+  /// 每个语音片段一行：`HH:MM:SS:mm - HH:MM:SS:mm  --  文本`，启用说话人分离时文本前带 `[S1] ` 标签
   Future<String> transcribeAudio({required String path, String? language}) =>
       RustLib.instance.api.crateApiAudioAudioRecognizerTranscribeAudio(
         that: this,
         path: path,
         language: language,
       );
+
+  /// 转录音频文件，返回带逐字时间戳与置信度的结构化结果
+  ///
+  /// 文件按块解码、重采样和做 VAD，内存中只保留尚未转录完的音频窗口，峰值内存与时长无关
+  Future<Transcript> transcribeAudioDetailed({
+    required String path,
+    String? language,
+  }) => RustLib
+      .instance
+      .api
+      .crateApiAudioAudioRecognizerTranscribeAudioDetailed(
+        that: this,
+        path: path,
+        language: language,
+      );
+
+  /// 转录内存中的 PCM 数据，平台解码器无需先写临时 WAV
+  ///
+  /// samples 为交错排列的多声道样本（范围 [-1.0, 1.0]），多声道混为单声道，非 16kHz 时重采样
+  Future<Transcript> transcribeSamples({
+    required List<double> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  }) => RustLib.instance.api.crateApiAudioAudioRecognizerTranscribeSamples(
+    that: this,
+    samples: samples,
+    sampleRate: sampleRate,
+    channels: channels,
+    language: language,
+  );
+
+  /// 同 [`Self::transcribe_samples`]，输入为 16 位整型 PCM
+  Future<Transcript> transcribeSamplesI16({
+    required List<int> samples,
+    required int sampleRate,
+    required int channels,
+    String? language,
+  }) => RustLib.instance.api.crateApiAudioAudioRecognizerTranscribeSamplesI16(
+    that: this,
+    samples: samples,
+    sampleRate: sampleRate,
+    channels: channels,
+    language: language,
+  );
+}
+
+@sealed
+class ImportErrorImpl extends RustOpaque implements ImportError {
+  // Not to be used by end users
+  ImportErrorImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ImportErrorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ImportError,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ImportError,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ImportErrorPtr,
+  );
+}
+
+@sealed
+class RecipeImporterImpl extends RustOpaque implements RecipeImporter {
+  // Not to be used by end users
+  RecipeImporterImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  RecipeImporterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_RecipeImporter,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_RecipeImporter,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_RecipeImporterPtr,
+  );
+
+  /// 从分享文本导入菜谱
+  ///
+  /// - `decode_audio`: 视频路径 → 解码后的 WAV 路径
+  /// - `extract_frames`: 视频路径 → Y 平面帧列表（建议在 Dart 侧先按时间抽样）
+  /// - `on_progress`: 阶段进度回调
+  Future<ImportedRecipe> importFromText({
+    required String shareText,
+    String? language,
+    required FutureOr<String> Function(String) decodeAudio,
+    required FutureOr<List<YFrameData>> Function(String) extractFrames,
+    required FutureOr<void> Function(ImportProgress) onProgress,
+  }) => RustLib.instance.api.crateApiImporterRecipeImporterImportFromText(
+    that: this,
+    shareText: shareText,
+    language: language,
+    decodeAudio: decodeAudio,
+    extractFrames: extractFrames,
+    onProgress: onProgress,
+  );
 }
 
 @sealed
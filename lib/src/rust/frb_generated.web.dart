@@ -7,6 +7,12 @@
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/audio.dart';
+import 'api/importer.dart';
+import 'api/models/asr.dart';
+import 'api/models/manifest.dart';
+import 'api/models/recipe.dart';
+import 'api/models/source.dart';
+import 'api/models/transcript.dart';
 import 'api/models/xhs.dart';
 import 'api/video.dart';
 import 'api/xhs.dart';
@@ -33,10 +39,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_AudioRecognizerPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ImportErrorPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_RecipeImporterPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_VideoFrameExtractorPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor;
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
   @protected
   AudioError
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
@@ -49,6 +66,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ImportError
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    dynamic raw,
+  );
+
+  @protected
+  RecipeImporter
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    dynamic raw,
+  );
+
   @protected
   VideoFrameExtractor
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
@@ -61,12 +90,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RecipeImporter
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    dynamic raw,
+  );
+
   @protected
   VideoFrameExtractor
   dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
     dynamic raw,
   );
 
+  @protected
+  FutureOr<String> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<List<YFrameData>> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    dynamic raw,
+  );
+
+  @protected
+  FutureOr<void> Function(ImportProgress)
+  dco_decode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    dynamic raw,
+  );
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw);
+
   @protected
   AudioError
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
@@ -79,6 +133,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ImportError
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    dynamic raw,
+  );
+
+  @protected
+  RecipeImporter
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    dynamic raw,
+  );
+
   @protected
   VideoFrameExtractor
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
@@ -88,9 +154,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AsrConfig dco_decode_asr_config(dynamic raw);
+
+  @protected
+  AsrModelFiles dco_decode_asr_model_files(dynamic raw);
+
+  @protected
+  AudioEvent dco_decode_audio_event(dynamic raw);
+
+  @protected
+  AudioEventKind dco_decode_audio_event_kind(dynamic raw);
+
+  @protected
+  AudioRecognizerConfig dco_decode_audio_recognizer_config(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AudioRecognizerConfig dco_decode_box_autoadd_audio_recognizer_config(
+    dynamic raw,
+  );
+
+  @protected
+  DiarizationConfig dco_decode_box_autoadd_diarization_config(dynamic raw);
+
+  @protected
+  EventDetectionConfig dco_decode_box_autoadd_event_detection_config(
+    dynamic raw,
+  );
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  HotwordConfig dco_decode_box_autoadd_hotword_config(dynamic raw);
+
+  @protected
+  LoudnessConfig dco_decode_box_autoadd_loudness_config(dynamic raw);
+
+  @protected
+  ModelValidationReport dco_decode_box_autoadd_model_validation_report(
+    dynamic raw,
+  );
+
+  @protected
+  NoiseReductionConfig dco_decode_box_autoadd_noise_reduction_config(
+    dynamic raw,
+  );
+
+  @protected
+  ParsedRecipe dco_decode_box_autoadd_parsed_recipe(dynamic raw);
+
+  @protected
+  PreprocessConfig dco_decode_box_autoadd_preprocess_config(dynamic raw);
+
+  @protected
+  SourceVideo dco_decode_box_autoadd_source_video(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  XhsApiError dco_decode_box_autoadd_xhs_api_error(dynamic raw);
+
+  @protected
+  XhsCacheConfig dco_decode_box_autoadd_xhs_cache_config(dynamic raw);
+
+  @protected
+  XhsClientConfig dco_decode_box_autoadd_xhs_client_config(dynamic raw);
+
   @protected
   XhsVideo dco_decode_box_autoadd_xhs_video(dynamic raw);
 
+  @protected
+  DecodingMethod dco_decode_decoding_method(dynamic raw);
+
+  @protected
+  DiarizationConfig dco_decode_diarization_config(dynamic raw);
+
+  @protected
+  EndpointConfig dco_decode_endpoint_config(dynamic raw);
+
+  @protected
+  EventDetectionConfig dco_decode_event_detection_config(dynamic raw);
+
   @protected
   ExtractionStats dco_decode_extraction_stats(dynamic raw);
 
@@ -100,33 +249,195 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FrameExtractedInfo dco_decode_frame_extracted_info(dynamic raw);
 
+  @protected
+  Hotword dco_decode_hotword(dynamic raw);
+
+  @protected
+  HotwordConfig dco_decode_hotword_config(dynamic raw);
+
+  @protected
+  int dco_decode_i_16(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  ImportProgress dco_decode_import_progress(dynamic raw);
+
+  @protected
+  ImportStage dco_decode_import_stage(dynamic raw);
+
+  @protected
+  ImportedRecipe dco_decode_imported_recipe(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AudioEvent> dco_decode_list_audio_event(dynamic raw);
+
   @protected
   List<FrameExtractedInfo> dco_decode_list_frame_extracted_info(dynamic raw);
 
+  @protected
+  List<Hotword> dco_decode_list_hotword(dynamic raw);
+
+  @protected
+  List<ModelFileIssue> dco_decode_list_model_file_issue(dynamic raw);
+
+  @protected
+  List<ModelPackConfig> dco_decode_list_model_pack_config(dynamic raw);
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+  @protected
+  List<int> dco_decode_list_prim_i_16_loose(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RecipeIngredient> dco_decode_list_recipe_ingredient(dynamic raw);
+
+  @protected
+  List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw);
+
+  @protected
+  List<TranscriptToken> dco_decode_list_transcript_token(dynamic raw);
+
+  @protected
+  List<XhsNoteCard> dco_decode_list_xhs_note_card(dynamic raw);
+
   @protected
   List<YFrameData> dco_decode_list_y_frame_data(dynamic raw);
 
+  @protected
+  LoudnessConfig dco_decode_loudness_config(dynamic raw);
+
+  @protected
+  LoudnessMeasure dco_decode_loudness_measure(dynamic raw);
+
+  @protected
+  ModelFileIssue dco_decode_model_file_issue(dynamic raw);
+
+  @protected
+  ModelIssueKind dco_decode_model_issue_kind(dynamic raw);
+
+  @protected
+  ModelPackConfig dco_decode_model_pack_config(dynamic raw);
+
+  @protected
+  ModelValidationReport dco_decode_model_validation_report(dynamic raw);
+
+  @protected
+  NoiseReductionConfig dco_decode_noise_reduction_config(dynamic raw);
+
   @protected
   NoteType dco_decode_note_type(dynamic raw);
 
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  AudioRecognizerConfig? dco_decode_opt_box_autoadd_audio_recognizer_config(
+    dynamic raw,
+  );
+
+  @protected
+  DiarizationConfig? dco_decode_opt_box_autoadd_diarization_config(dynamic raw);
+
+  @protected
+  EventDetectionConfig? dco_decode_opt_box_autoadd_event_detection_config(
+    dynamic raw,
+  );
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  HotwordConfig? dco_decode_opt_box_autoadd_hotword_config(dynamic raw);
+
+  @protected
+  LoudnessConfig? dco_decode_opt_box_autoadd_loudness_config(dynamic raw);
+
+  @protected
+  NoiseReductionConfig? dco_decode_opt_box_autoadd_noise_reduction_config(
+    dynamic raw,
+  );
+
+  @protected
+  ParsedRecipe? dco_decode_opt_box_autoadd_parsed_recipe(dynamic raw);
+
+  @protected
+  PreprocessConfig? dco_decode_opt_box_autoadd_preprocess_config(dynamic raw);
+
+  @protected
+  SourceVideo? dco_decode_opt_box_autoadd_source_video(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  XhsCacheConfig? dco_decode_opt_box_autoadd_xhs_cache_config(dynamic raw);
+
+  @protected
+  XhsClientConfig? dco_decode_opt_box_autoadd_xhs_client_config(dynamic raw);
+
   @protected
   XhsVideo? dco_decode_opt_box_autoadd_xhs_video(dynamic raw);
 
+  @protected
+  ParsedRecipe dco_decode_parsed_recipe(dynamic raw);
+
+  @protected
+  Platform dco_decode_platform(dynamic raw);
+
+  @protected
+  PostprocessConfig dco_decode_postprocess_config(dynamic raw);
+
+  @protected
+  PreprocessConfig dco_decode_preprocess_config(dynamic raw);
+
+  @protected
+  PunctuationMode dco_decode_punctuation_mode(dynamic raw);
+
+  @protected
+  RecipeIngredient dco_decode_recipe_ingredient(dynamic raw);
+
+  @protected
+  RecognizerPoolConfig dco_decode_recognizer_pool_config(dynamic raw);
+
+  @protected
+  ResampleQuality dco_decode_resample_quality(dynamic raw);
+
+  @protected
+  SegmentationPath dco_decode_segmentation_path(dynamic raw);
+
+  @protected
+  SourceArticle dco_decode_source_article(dynamic raw);
+
+  @protected
+  SourceAuthor dco_decode_source_author(dynamic raw);
+
+  @protected
+  SourceVideo dco_decode_source_video(dynamic raw);
+
+  @protected
+  Transcript dco_decode_transcript(dynamic raw);
+
+  @protected
+  TranscriptSegment dco_decode_transcript_segment(dynamic raw);
+
+  @protected
+  TranscriptToken dco_decode_transcript_token(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -134,274 +445,1146 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BigInt dco_decode_u_64(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  VadConfig dco_decode_vad_config(dynamic raw);
+
+  @protected
+  VadFallback dco_decode_vad_fallback(dynamic raw);
+
+  @protected
+  XhsApiError dco_decode_xhs_api_error(dynamic raw);
+
+  @protected
+  XhsArticle dco_decode_xhs_article(dynamic raw);
+
+  @protected
+  XhsAuthor dco_decode_xhs_author(dynamic raw);
+
+  @protected
+  XhsAuthorProfile dco_decode_xhs_author_profile(dynamic raw);
+
+  @protected
+  XhsBlockReason dco_decode_xhs_block_reason(dynamic raw);
+
+  @protected
+  XhsCacheConfig dco_decode_xhs_cache_config(dynamic raw);
+
+  @protected
+  XhsClientConfig dco_decode_xhs_client_config(dynamic raw);
+
+  @protected
+  XhsLink dco_decode_xhs_link(dynamic raw);
+
+  @protected
+  XhsLinkKind dco_decode_xhs_link_kind(dynamic raw);
+
+  @protected
+  XhsNoteCard dco_decode_xhs_note_card(dynamic raw);
+
+  @protected
+  XhsNotePage dco_decode_xhs_note_page(dynamic raw);
+
+  @protected
+  XhsVideo dco_decode_xhs_video(dynamic raw);
+
+  @protected
+  YFrameData dco_decode_y_frame_data(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  AudioError
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioRecognizer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImportError
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecipeImporter
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  VideoFrameExtractor
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioRecognizer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecipeImporter
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  VideoFrameExtractor
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<String> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_String_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<List<YFrameData>> Function(String)
+  sse_decode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FutureOr<void> Function(ImportProgress)
+  sse_decode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+  @protected
+  AudioError
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AudioRecognizer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImportError
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RecipeImporter
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  VideoFrameExtractor
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AsrConfig sse_decode_asr_config(SseDeserializer deserializer);
+
+  @protected
+  AsrModelFiles sse_decode_asr_model_files(SseDeserializer deserializer);
+
+  @protected
+  AudioEvent sse_decode_audio_event(SseDeserializer deserializer);
+
+  @protected
+  AudioEventKind sse_decode_audio_event_kind(SseDeserializer deserializer);
+
+  @protected
+  AudioRecognizerConfig sse_decode_audio_recognizer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AudioRecognizerConfig sse_decode_box_autoadd_audio_recognizer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DiarizationConfig sse_decode_box_autoadd_diarization_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EventDetectionConfig sse_decode_box_autoadd_event_detection_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  HotwordConfig sse_decode_box_autoadd_hotword_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoudnessConfig sse_decode_box_autoadd_loudness_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ModelValidationReport sse_decode_box_autoadd_model_validation_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoiseReductionConfig sse_decode_box_autoadd_noise_reduction_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ParsedRecipe sse_decode_box_autoadd_parsed_recipe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PreprocessConfig sse_decode_box_autoadd_preprocess_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SourceVideo sse_decode_box_autoadd_source_video(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  XhsApiError sse_decode_box_autoadd_xhs_api_error(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhsCacheConfig sse_decode_box_autoadd_xhs_cache_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhsClientConfig sse_decode_box_autoadd_xhs_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhsVideo sse_decode_box_autoadd_xhs_video(SseDeserializer deserializer);
+
+  @protected
+  DecodingMethod sse_decode_decoding_method(SseDeserializer deserializer);
+
+  @protected
+  DiarizationConfig sse_decode_diarization_config(SseDeserializer deserializer);
+
+  @protected
+  EndpointConfig sse_decode_endpoint_config(SseDeserializer deserializer);
+
+  @protected
+  EventDetectionConfig sse_decode_event_detection_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExtractionStats sse_decode_extraction_stats(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  FrameExtractedInfo sse_decode_frame_extracted_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Hotword sse_decode_hotword(SseDeserializer deserializer);
+
+  @protected
+  HotwordConfig sse_decode_hotword_config(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportProgress sse_decode_import_progress(SseDeserializer deserializer);
+
+  @protected
+  ImportStage sse_decode_import_stage(SseDeserializer deserializer);
+
+  @protected
+  ImportedRecipe sse_decode_imported_recipe(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AudioEvent> sse_decode_list_audio_event(SseDeserializer deserializer);
+
+  @protected
+  List<FrameExtractedInfo> sse_decode_list_frame_extracted_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Hotword> sse_decode_list_hotword(SseDeserializer deserializer);
+
+  @protected
+  List<ModelFileIssue> sse_decode_list_model_file_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ModelPackConfig> sse_decode_list_model_pack_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_i_16_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RecipeIngredient> sse_decode_list_recipe_ingredient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TranscriptSegment> sse_decode_list_transcript_segment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TranscriptToken> sse_decode_list_transcript_token(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<XhsNoteCard> sse_decode_list_xhs_note_card(SseDeserializer deserializer);
+
+  @protected
+  List<YFrameData> sse_decode_list_y_frame_data(SseDeserializer deserializer);
+
+  @protected
+  LoudnessConfig sse_decode_loudness_config(SseDeserializer deserializer);
+
+  @protected
+  LoudnessMeasure sse_decode_loudness_measure(SseDeserializer deserializer);
+
+  @protected
+  ModelFileIssue sse_decode_model_file_issue(SseDeserializer deserializer);
+
+  @protected
+  ModelIssueKind sse_decode_model_issue_kind(SseDeserializer deserializer);
+
+  @protected
+  ModelPackConfig sse_decode_model_pack_config(SseDeserializer deserializer);
+
+  @protected
+  ModelValidationReport sse_decode_model_validation_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoiseReductionConfig sse_decode_noise_reduction_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteType sse_decode_note_type(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AudioRecognizerConfig? sse_decode_opt_box_autoadd_audio_recognizer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DiarizationConfig? sse_decode_opt_box_autoadd_diarization_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EventDetectionConfig? sse_decode_opt_box_autoadd_event_detection_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  HotwordConfig? sse_decode_opt_box_autoadd_hotword_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoudnessConfig? sse_decode_opt_box_autoadd_loudness_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoiseReductionConfig? sse_decode_opt_box_autoadd_noise_reduction_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ParsedRecipe? sse_decode_opt_box_autoadd_parsed_recipe(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PreprocessConfig? sse_decode_opt_box_autoadd_preprocess_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SourceVideo? sse_decode_opt_box_autoadd_source_video(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  XhsCacheConfig? sse_decode_opt_box_autoadd_xhs_cache_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhsClientConfig? sse_decode_opt_box_autoadd_xhs_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  XhsVideo? sse_decode_opt_box_autoadd_xhs_video(SseDeserializer deserializer);
+
+  @protected
+  ParsedRecipe sse_decode_parsed_recipe(SseDeserializer deserializer);
+
+  @protected
+  Platform sse_decode_platform(SseDeserializer deserializer);
+
+  @protected
+  PostprocessConfig sse_decode_postprocess_config(SseDeserializer deserializer);
+
+  @protected
+  PreprocessConfig sse_decode_preprocess_config(SseDeserializer deserializer);
+
+  @protected
+  PunctuationMode sse_decode_punctuation_mode(SseDeserializer deserializer);
+
+  @protected
+  RecipeIngredient sse_decode_recipe_ingredient(SseDeserializer deserializer);
+
+  @protected
+  RecognizerPoolConfig sse_decode_recognizer_pool_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ResampleQuality sse_decode_resample_quality(SseDeserializer deserializer);
+
+  @protected
+  SegmentationPath sse_decode_segmentation_path(SseDeserializer deserializer);
+
+  @protected
+  SourceArticle sse_decode_source_article(SseDeserializer deserializer);
+
+  @protected
+  SourceAuthor sse_decode_source_author(SseDeserializer deserializer);
+
+  @protected
+  SourceVideo sse_decode_source_video(SseDeserializer deserializer);
+
+  @protected
+  Transcript sse_decode_transcript(SseDeserializer deserializer);
+
+  @protected
+  TranscriptSegment sse_decode_transcript_segment(SseDeserializer deserializer);
+
+  @protected
+  TranscriptToken sse_decode_transcript_token(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  VadConfig sse_decode_vad_config(SseDeserializer deserializer);
+
+  @protected
+  VadFallback sse_decode_vad_fallback(SseDeserializer deserializer);
+
+  @protected
+  XhsApiError sse_decode_xhs_api_error(SseDeserializer deserializer);
+
+  @protected
+  XhsArticle sse_decode_xhs_article(SseDeserializer deserializer);
+
+  @protected
+  XhsAuthor sse_decode_xhs_author(SseDeserializer deserializer);
+
+  @protected
+  XhsAuthorProfile sse_decode_xhs_author_profile(SseDeserializer deserializer);
+
+  @protected
+  XhsBlockReason sse_decode_xhs_block_reason(SseDeserializer deserializer);
+
+  @protected
+  XhsCacheConfig sse_decode_xhs_cache_config(SseDeserializer deserializer);
+
+  @protected
+  XhsClientConfig sse_decode_xhs_client_config(SseDeserializer deserializer);
+
+  @protected
+  XhsLink sse_decode_xhs_link(SseDeserializer deserializer);
+
+  @protected
+  XhsLinkKind sse_decode_xhs_link_kind(SseDeserializer deserializer);
+
+  @protected
+  XhsNoteCard sse_decode_xhs_note_card(SseDeserializer deserializer);
+
+  @protected
+  XhsNotePage sse_decode_xhs_note_page(SseDeserializer deserializer);
+
+  @protected
+  XhsVideo sse_decode_xhs_video(SseDeserializer deserializer);
+
+  @protected
+  YFrameData sse_decode_y_frame_data(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    AudioError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    AudioRecognizer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    ImportError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    RecipeImporter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    VideoFrameExtractor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    AudioRecognizer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    RecipeImporter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    VideoFrameExtractor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    FutureOr<List<YFrameData>> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(ImportProgress) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
+    AudioError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
+    AudioRecognizer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    ImportError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    RecipeImporter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
+    VideoFrameExtractor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_asr_config(AsrConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_asr_model_files(AsrModelFiles self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_event(AudioEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_event_kind(
+    AudioEventKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audio_recognizer_config(
+    AudioRecognizerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_audio_recognizer_config(
+    AudioRecognizerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_diarization_config(
+    DiarizationConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_event_detection_config(
+    EventDetectionConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_hotword_config(
+    HotwordConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_loudness_config(
+    LoudnessConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_model_validation_report(
+    ModelValidationReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_noise_reduction_config(
+    NoiseReductionConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_parsed_recipe(
+    ParsedRecipe self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_preprocess_config(
+    PreprocessConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_source_video(
+    SourceVideo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_xhs_api_error(
+    XhsApiError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_xhs_cache_config(
+    XhsCacheConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_xhs_client_config(
+    XhsClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_xhs_video(
+    XhsVideo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_decoding_method(
+    DecodingMethod self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_diarization_config(
+    DiarizationConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_endpoint_config(
+    EndpointConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_event_detection_config(
+    EventDetectionConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_extraction_stats(
+    ExtractionStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_frame_extracted_info(
+    FrameExtractedInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_hotword(Hotword self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hotword_config(HotwordConfig self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_i_16(int self, SseSerializer serializer);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
-  XhsApiError dco_decode_xhs_api_error(dynamic raw);
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
   @protected
-  XhsArticle dco_decode_xhs_article(dynamic raw);
+  void sse_encode_import_progress(
+    ImportProgress self,
+    SseSerializer serializer,
+  );
 
   @protected
-  XhsAuthor dco_decode_xhs_author(dynamic raw);
+  void sse_encode_import_stage(ImportStage self, SseSerializer serializer);
 
   @protected
-  XhsVideo dco_decode_xhs_video(dynamic raw);
+  void sse_encode_imported_recipe(
+    ImportedRecipe self,
+    SseSerializer serializer,
+  );
 
   @protected
-  YFrameData dco_decode_y_frame_data(dynamic raw);
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
   @protected
-  AudioError
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    SseDeserializer deserializer,
+  void sse_encode_list_audio_event(
+    List<AudioEvent> self,
+    SseSerializer serializer,
   );
 
   @protected
-  AudioRecognizer
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    SseDeserializer deserializer,
+  void sse_encode_list_frame_extracted_info(
+    List<FrameExtractedInfo> self,
+    SseSerializer serializer,
   );
 
   @protected
-  VideoFrameExtractor
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    SseDeserializer deserializer,
-  );
+  void sse_encode_list_hotword(List<Hotword> self, SseSerializer serializer);
 
   @protected
-  AudioRecognizer
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    SseDeserializer deserializer,
+  void sse_encode_list_model_file_issue(
+    List<ModelFileIssue> self,
+    SseSerializer serializer,
   );
 
   @protected
-  VideoFrameExtractor
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    SseDeserializer deserializer,
+  void sse_encode_list_model_pack_config(
+    List<ModelPackConfig> self,
+    SseSerializer serializer,
   );
 
   @protected
-  AudioError
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
   );
 
   @protected
-  AudioRecognizer
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_i_16_loose(
+    List<int> self,
+    SseSerializer serializer,
   );
 
   @protected
-  VideoFrameExtractor
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_recipe_ingredient(
+    List<RecipeIngredient> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  XhsVideo sse_decode_box_autoadd_xhs_video(SseDeserializer deserializer);
+  void sse_encode_list_transcript_segment(
+    List<TranscriptSegment> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ExtractionStats sse_decode_extraction_stats(SseDeserializer deserializer);
+  void sse_encode_list_transcript_token(
+    List<TranscriptToken> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  void sse_encode_list_xhs_note_card(
+    List<XhsNoteCard> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FrameExtractedInfo sse_decode_frame_extracted_info(
-    SseDeserializer deserializer,
+  void sse_encode_list_y_frame_data(
+    List<YFrameData> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_loudness_config(
+    LoudnessConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_loudness_measure(
+    LoudnessMeasure self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_model_file_issue(
+    ModelFileIssue self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<FrameExtractedInfo> sse_decode_list_frame_extracted_info(
-    SseDeserializer deserializer,
+  void sse_encode_model_issue_kind(
+    ModelIssueKind self,
+    SseSerializer serializer,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_model_pack_config(
+    ModelPackConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<YFrameData> sse_decode_list_y_frame_data(SseDeserializer deserializer);
+  void sse_encode_model_validation_report(
+    ModelValidationReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  NoteType sse_decode_note_type(SseDeserializer deserializer);
+  void sse_encode_noise_reduction_config(
+    NoiseReductionConfig self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_note_type(NoteType self, SseSerializer serializer);
 
   @protected
-  XhsVideo? sse_decode_opt_box_autoadd_xhs_video(SseDeserializer deserializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_audio_recognizer_config(
+    AudioRecognizerConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_diarization_config(
+    DiarizationConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_event_detection_config(
+    EventDetectionConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_hotword_config(
+    HotwordConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  XhsApiError sse_decode_xhs_api_error(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_loudness_config(
+    LoudnessConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  XhsArticle sse_decode_xhs_article(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_noise_reduction_config(
+    NoiseReductionConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  XhsAuthor sse_decode_xhs_author(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_parsed_recipe(
+    ParsedRecipe? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  XhsVideo sse_decode_xhs_video(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_preprocess_config(
+    PreprocessConfig? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  YFrameData sse_decode_y_frame_data(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_source_video(
+    SourceVideo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    AudioError self,
+  void sse_encode_opt_box_autoadd_xhs_cache_config(
+    XhsCacheConfig? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    AudioRecognizer self,
+  void sse_encode_opt_box_autoadd_xhs_client_config(
+    XhsClientConfig? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    VideoFrameExtractor self,
+  void sse_encode_opt_box_autoadd_xhs_video(
+    XhsVideo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    AudioRecognizer self,
-    SseSerializer serializer,
-  );
+  void sse_encode_parsed_recipe(ParsedRecipe self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    VideoFrameExtractor self,
-    SseSerializer serializer,
-  );
+  void sse_encode_platform(Platform self, SseSerializer serializer);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioError(
-    AudioError self,
+  void sse_encode_postprocess_config(
+    PostprocessConfig self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAudioRecognizer(
-    AudioRecognizer self,
+  void sse_encode_preprocess_config(
+    PreprocessConfig self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
-    VideoFrameExtractor self,
+  void sse_encode_punctuation_mode(
+    PunctuationMode self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_xhs_video(
-    XhsVideo self,
+  void sse_encode_recipe_ingredient(
+    RecipeIngredient self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_extraction_stats(
-    ExtractionStats self,
+  void sse_encode_recognizer_pool_config(
+    RecognizerPoolConfig self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_resample_quality(
+    ResampleQuality self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_frame_extracted_info(
-    FrameExtractedInfo self,
+  void sse_encode_segmentation_path(
+    SegmentationPath self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_source_article(SourceArticle self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_source_author(SourceAuthor self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_source_video(SourceVideo self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_frame_extracted_info(
-    List<FrameExtractedInfo> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_transcript(Transcript self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_transcript_segment(
+    TranscriptSegment self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_y_frame_data(
-    List<YFrameData> self,
+  void sse_encode_transcript_token(
+    TranscriptToken self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_note_type(NoteType self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_xhs_video(
-    XhsVideo? self,
-    SseSerializer serializer,
-  );
+  void sse_encode_u_16(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
@@ -418,6 +1601,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_vad_config(VadConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_vad_fallback(VadFallback self, SseSerializer serializer);
+
   @protected
   void sse_encode_xhs_api_error(XhsApiError self, SseSerializer serializer);
 
@@ -428,13 +1617,46 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_xhs_author(XhsAuthor self, SseSerializer serializer);
 
   @protected
-  void sse_encode_xhs_video(XhsVideo self, SseSerializer serializer);
+  void sse_encode_xhs_author_profile(
+    XhsAuthorProfile self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_y_frame_data(YFrameData self, SseSerializer serializer);
+  void sse_encode_xhs_block_reason(
+    XhsBlockReason self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_xhs_cache_config(
+    XhsCacheConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_xhs_client_config(
+    XhsClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_xhs_link(XhsLink self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_xhs_link_kind(XhsLinkKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_xhs_note_card(XhsNoteCard self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_xhs_note_page(XhsNotePage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_xhs_video(XhsVideo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_y_frame_data(YFrameData self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -474,6 +1696,38 @@ class RustLibWire implements BaseWire {
         ptr,
       );
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
     int ptr,
@@ -517,6 +1771,26 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
     int ptr,
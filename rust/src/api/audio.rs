@@ -1,10 +1,24 @@
 //! 音频识别器 - ASR + VAD
 
-use crate::core::audio::{AudioError, NcnnHandle, SpeechSegment, VadHandle};
-use crate::core::audio::{load_wav_mono_f32, resample_to_16k_mono};
+use crate::api::models::asr::{
+    AudioRecognizerConfig, EventDetectionConfig, PreprocessConfig, RecognizerPoolConfig,
+    ResampleQuality, VadFallback,
+};
+use crate::api::models::manifest::ModelValidationReport;
+use crate::api::models::transcript::{AudioEvent, SegmentationPath, Transcript, TranscriptSegment};
+use crate::core::audio::language::{self, PROBE_SECS, PROBE_SPAN_SECS};
+use crate::core::audio::manifest::{self, MANIFEST_FILE};
+use crate::core::audio::preprocess;
+use crate::core::audio::vad::SegmentAssembler;
+use crate::core::audio::{downmix_to_mono, i16_to_f32, resample_to_16k_mono};
+use crate::core::audio::{
+    fixed_windows, AudioError, Diarizer, EventDetector, HotwordsFile, ModelPack, PcmWindow,
+    Postprocessor, PreprocessStream, RecognizerPool, SpeechSegment, TokenVocab, VadHandle,
+    Wav16kStream, STREAM_CHUNK,
+};
 use flutter_rust_bridge::frb;
-use log::{debug, error, info};
-use std::path::Path;
+use log::{debug, error, info, warn};
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 /// 音频识别器 - 封装 ASR + VAD
@@ -16,38 +30,119 @@ use std::sync::Mutex;
 #[frb(opaque)]
 pub struct AudioRecognizer {
     models_dir: String,
-    ncnn: NcnnHandle,
+    /// 第一个为默认模型包
+    packs: Vec<ModelPack>,
     vad: Mutex<VadHandle>,
+    vad_fallback: VadFallback,
+    diarizer: Option<Diarizer>,
+    postprocessor: Postprocessor,
+    resample_quality: ResampleQuality,
+    preprocess: Option<PreprocessConfig>,
+    events: Option<EventDetectionConfig>,
 }
 
 impl AudioRecognizer {
     /// 创建音频识别器并加载模型
     ///
     /// models_dir 下需要包含：
-    /// - sherpa-ncnn/ (ASR 模型)
+    /// - sherpa-ncnn/ (默认 ASR 模型包，可通过 config.model_packs 配置多个)
     /// - silero-vad/ (VAD 模型)
+    /// - models.json (可选，模型清单；存在时先按大小校验，提前发现缺失或被截断的文件)
+    ///
+    /// config 为 None 时使用默认配置（不启用热词）；非法参数在加载模型前报错
     #[frb(dart_async)]
-    pub async fn create(models_dir: String) -> Result<Self, AudioError> {
+    pub async fn create(
+        models_dir: String,
+        config: Option<AudioRecognizerConfig>,
+    ) -> Result<Self, AudioError> {
         info!("🎙️ AudioRecognizer: initializing with models_dir: {}", models_dir);
         crate::init_logging();
+        let config = config.unwrap_or_default();
+        validate_fallback(&config.vad_fallback)?;
+        language::validate_packs(&config.model_packs)?;
+        let diarizer = config.diarization.as_ref().map(Diarizer::new).transpose()?;
+        if let Some(preprocess) = &config.preprocess {
+            preprocess::validate_config(preprocess)?;
+        }
+        config.events.as_ref().map(EventDetector::new).transpose()?;
+        check_manifest(Path::new(&models_dir))?;
 
-        // 初始化 Sherpa-NCNN (ASR)
-        let sherpa_path = Path::new(&models_dir).join("sherpa-ncnn");
-        let ncnn = if sherpa_path.exists() {
-            info!("🎙️ Loading Sherpa-NCNN from {:?}", sherpa_path);
-            NcnnHandle::new(&sherpa_path.to_string_lossy())?
-        } else {
-            return Err(AudioError::ModelLoadFailed(format!(
-                "sherpa-ncnn model not found at {:?}",
-                sherpa_path
-            )));
+        // 初始化 Sherpa-NCNN (ASR)，默认包缺失时报错，其余包缺失时跳过
+        let available: Vec<_> = config
+            .model_packs
+            .iter()
+            .enumerate()
+            .filter(|(index, pack)| {
+                let exists = Path::new(&models_dir).join(&pack.dir).exists();
+                if !exists && *index > 0 {
+                    warn!("⚠️ Model pack {} not found, skipping", pack.dir);
+                }
+                exists || *index == 0
+            })
+            .map(|(_, pack)| pack)
+            .collect();
+        let pool_config = RecognizerPoolConfig {
+            max_memory_mb: match config.pool.max_memory_mb {
+                0 => 0,
+                mb => (mb / available.len() as u32).max(1),
+            },
+            ..config.pool.clone()
         };
 
+        let mut packs = Vec::with_capacity(available.len());
+        for pack in available {
+            let sherpa_path = Path::new(&models_dir).join(&pack.dir);
+            if !sherpa_path.exists() {
+                return Err(AudioError::ModelLoadFailed(format!(
+                    "{} model not found at {:?}",
+                    pack.dir, sherpa_path
+                )));
+            }
+            info!(
+                "🎙️ Loading Sherpa-NCNN from {:?} ({})",
+                sherpa_path,
+                pack.languages.join(", ")
+            );
+            // 热词按各包自己的词表编码，每个识别器一份，写在可写目录而不是模型目录
+            let hotwords = match &config.hotwords {
+                Some(hotwords) => {
+                    let vocab =
+                        TokenVocab::load(&sherpa_path.join(&config.asr.model_files.tokens))?;
+                    let dir = hotwords
+                        .work_dir
+                        .as_ref()
+                        .map(PathBuf::from)
+                        .unwrap_or_else(std::env::temp_dir);
+                    HotwordsFile::create(&dir, hotwords, &vocab)?
+                }
+                None => None,
+            };
+            let pool = RecognizerPool::new(
+                &sherpa_path.to_string_lossy(),
+                &config.asr,
+                hotwords
+                    .as_ref()
+                    .map(|file| file.path().to_string_lossy().to_string())
+                    .as_deref(),
+                &pool_config,
+            )?;
+            packs.push(ModelPack {
+                dir: pack.dir.clone(),
+                languages: pack
+                    .languages
+                    .iter()
+                    .filter_map(|l| language::normalize_language(l))
+                    .collect(),
+                pool,
+                hotwords,
+            });
+        }
+
         // 初始化 Silero-VAD
         let vad_path = Path::new(&models_dir).join("silero-vad");
         let vad = if vad_path.exists() {
             info!("🔇 Loading Silero-VAD from {:?}", vad_path);
-            VadHandle::new(&vad_path.to_string_lossy())?
+            VadHandle::new(&vad_path.to_string_lossy(), &config.vad)?
         } else {
             return Err(AudioError::ModelLoadFailed(format!(
                 "silero-vad model not found at {:?}",
@@ -58,27 +153,87 @@ impl AudioRecognizer {
         info!("✅ AudioRecognizer initialized successfully");
         Ok(Self {
             models_dir,
-            ncnn,
+            packs,
             vad: Mutex::new(vad),
+            vad_fallback: config.vad_fallback,
+            diarizer,
+            postprocessor: Postprocessor::new(&config.postprocess, config.hotwords.as_ref()),
+            resample_quality: config.resample_quality,
+            preprocess: config.preprocess,
+            events: config.events,
         })
     }
 
     /// 转录音频文件（WAV 格式）
+    ///
+    /// 每个语音片段一行：`HH:MM:SS:mm - HH:MM:SS:mm  --  文本`，启用说话人分离时文本前带 `[S1] ` 标签
     #[frb(dart_async)]
     pub async fn transcribe_audio(&self, path: String, language: Option<String>) -> Result<String, AudioError> {
-        info!("🎵 Loading WAV file: {}", path);
+        let transcript = self.transcribe_audio_detailed(path, language).await?;
+        Ok(format_transcript(&transcript))
+    }
 
-        match load_wav_mono_f32(&path) {
-            Ok(pcm) => {
-                info!("📊 WAV loaded: {} samples", pcm.len());
-                debug!("Language: {:?}", language);
-                self.transcribe_pcm(pcm, 16_000, language).await
-            }
-            Err(e) => {
-                error!("❌ Failed to load WAV: {}", e);
-                Err(e)
-            }
+    /// 转录音频文件，返回带逐字时间戳与置信度的结构化结果
+    ///
+    /// 文件按块解码、重采样和做 VAD，内存中只保留尚未转录完的音频窗口，峰值内存与时长无关
+    #[frb(dart_async)]
+    pub async fn transcribe_audio_detailed(
+        &self,
+        path: String,
+        language: Option<String>,
+    ) -> Result<Transcript, AudioError> {
+        info!("🎵 Streaming WAV file: {}", path);
+        debug!("Language: {:?}", language);
+
+        self.transcribe_stream(
+            || Wav16kStream::open(&path, self.resample_quality),
+            language,
+        )
+        .map_err(|e| {
+            error!("❌ Failed to transcribe {}: {}", path, e);
+            e
+        })
+    }
+
+    /// 转录内存中的 PCM 数据，平台解码器无需先写临时 WAV
+    ///
+    /// samples 为交错排列的多声道样本（范围 [-1.0, 1.0]），多声道混为单声道，非 16kHz 时重采样
+    #[frb(dart_async)]
+    pub async fn transcribe_samples(
+        &self,
+        samples: Vec<f32>,
+        sample_rate: u32,
+        channels: u16,
+        language: Option<String>,
+    ) -> Result<Transcript, AudioError> {
+        if sample_rate == 0 {
+            return Err(AudioError::InvalidInput(
+                "sample_rate must be positive".to_string(),
+            ));
         }
+        info!(
+            "🎵 Transcribing {} in-memory samples ({} Hz, {} channels)",
+            samples.len(),
+            sample_rate,
+            channels
+        );
+        let mono = downmix_to_mono(&samples, channels)?;
+        drop(samples);
+        self.transcribe_pcm(mono, sample_rate, language).await
+    }
+
+    /// 同 [`Self::transcribe_samples`]，输入为 16 位整型 PCM
+    #[frb(dart_async)]
+    pub async fn transcribe_samples_i16(
+        &self,
+        samples: Vec<i16>,
+        sample_rate: u32,
+        channels: u16,
+        language: Option<String>,
+    ) -> Result<Transcript, AudioError> {
+        let samples = i16_to_f32(&samples);
+        self.transcribe_samples(samples, sample_rate, channels, language)
+            .await
     }
 
     /// 转录 PCM 数据 (内部使用)
@@ -87,7 +242,7 @@ impl AudioRecognizer {
         pcm: Vec<f32>,
         sample_rate: u32,
         language: Option<String>,
-    ) -> Result<String, AudioError> {
+    ) -> Result<Transcript, AudioError> {
         info!(
             "🔄 Starting VAD-based transcription: {} samples at {} Hz",
             pcm.len(),
@@ -99,7 +254,7 @@ impl AudioRecognizer {
             pcm
         } else {
             info!("🔧 Resampling from {} Hz to 16 kHz...", sample_rate);
-            match resample_to_16k_mono(&pcm, sample_rate) {
+            match resample_to_16k_mono(&pcm, sample_rate, self.resample_quality) {
                 Ok(resampled) => {
                     info!("✓ Resampled: {} -> {} samples", pcm.len(), resampled.len());
                     resampled
@@ -111,33 +266,344 @@ impl AudioRecognizer {
             }
         };
 
+        self.transcribe_stream(
+            || Ok(pcm_16k.chunks(STREAM_CHUNK).map(|chunk| Ok(chunk.to_vec()))),
+            language,
+        )
+    }
+
+    /// 流式转录：`open` 每次调用都从头产出 16kHz 单声道分块
+    ///
+    /// 第一遍边送入 VAD 边转录已确定的片段；没有检测到语音时按回退策略重新读取一遍。
+    /// 配置了预处理时，VAD 与 ASR 看到的都是预处理后的音频
+    fn transcribe_stream<I>(
+        &self,
+        open: impl Fn() -> Result<I, AudioError>,
+        hint: Option<String>,
+    ) -> Result<Transcript, AudioError>
+    where
+        I: Iterator<Item = Result<Vec<f32>, AudioError>>,
+    {
         info!("🔍 Running Silero VAD to detect speech segments...");
-        let speech_segments = {
-            let mut vad = self.vad.lock().map_err(|e| {
-                AudioError::SherpaNcnn(format!("VAD lock poisoned: {}", e))
-            })?;
-            match vad.detect_speech_segments(&pcm_16k, 16_000) {
-                Ok(segments) => segments,
-                Err(e) => {
-                    error!("❌ VAD detection failed: {}", e);
-                    error!("⚠️  Falling back to fixed-time chunking");
-                    let duration = pcm_16k.len() as f32 / 16_000.0;
-                    vec![SpeechSegment {
-                        start: 0.0,
-                        end: duration,
-                    }]
+        let mut session = Session::new(self, hint.clone());
+        let mut window = PcmWindow::default();
+        let mut detector = self.events.as_ref().map(EventDetector::new).transpose()?;
+        {
+            // 整个第一遍持有 VAD，VAD 状态跨块保留
+            let mut vad = self
+                .vad
+                .lock()
+                .map_err(|e| AudioError::SherpaNcnn(format!("VAD lock poisoned: {}", e)))?;
+            let mut assembler = SegmentAssembler::new(vad.config());
+            vad.begin_stream();
+
+            // 事件检测看原始音频，VAD 与 ASR 看预处理后的音频
+            let raw = open()?.inspect(|chunk| {
+                if let (Some(detector), Ok(chunk)) = (detector.as_mut(), chunk) {
+                    detector.accept(chunk);
                 }
+            });
+            for chunk in PreprocessStream::new(raw, self.preprocess.as_ref())? {
+                let chunk = chunk?;
+                window.append(&chunk);
+                for raw in vad.accept(&chunk) {
+                    session.push(&window, assembler.push(raw));
+                }
+                let keep_from = [
+                    session.keep_from(),
+                    assembler.pending_start(),
+                    Some(window.end_secs() - vad.lookback_secs()),
+                ]
+                .into_iter()
+                .flatten()
+                .fold(f32::MAX, f32::min);
+                window.trim_before(keep_from);
+            }
+            for raw in vad.finish_stream() {
+                session.push(&window, assembler.push(raw));
+            }
+            session.push(&window, assembler.finish(window.end_secs()));
+        }
+        session.flush(&window, true);
+
+        let events = detector
+            .map(|detector| detector.finish(session.speech()))
+            .unwrap_or_default();
+        if session.has_segments() {
+            return Ok(session.finish(SegmentationPath::Vad, None, events));
+        }
+
+        let error = AudioError::NoSpeech;
+        let duration = window.end_secs();
+        let (segments, segmentation) = match &self.vad_fallback {
+            VadFallback::Error => return Err(error),
+            VadFallback::FixedWindow {
+                window_ms,
+                overlap_ms,
+            } => {
+                warn!("⚠️  {}, falling back to fixed-window chunking", error);
+                let windows = fixed_windows(
+                    duration,
+                    *window_ms as f32 / 1000.0,
+                    *overlap_ms as f32 / 1000.0,
+                );
+                (windows, SegmentationPath::FixedWindow)
+            }
+            VadFallback::WholeFile => {
+                warn!("⚠️  {}, falling back to whole-file transcription", error);
+                let whole = vec![SpeechSegment::new(0.0, duration)];
+                (whole, SegmentationPath::WholeFile)
             }
         };
 
+        // 第二遍：片段已知，音频到齐一个转录一个
+        let mut session = Session::new(self, hint);
+        let mut window = PcmWindow::default();
+        let mut planned = segments.into_iter().peekable();
+        for chunk in PreprocessStream::new(open()?, self.preprocess.as_ref())? {
+            window.append(&chunk?);
+            let mut ready = Vec::new();
+            while let Some(segment) = planned.next_if(|s| s.end <= window.end_secs()) {
+                ready.push(segment);
+            }
+            session.push(&window, ready);
+            let keep_from = [session.keep_from(), planned.peek().map(|s| s.start)]
+                .into_iter()
+                .flatten()
+                .fold(window.end_secs(), f32::min);
+            window.trim_before(keep_from);
+        }
+        session.push(&window, planned.collect());
+        session.flush(&window, true);
+        Ok(session.finish(segmentation, Some(error.to_string()), events))
+    }
+
+    /// 选择模型包：有语言提示时按提示路由；没有提示且有多个模型包时，
+    /// 先用默认包识别开头的片段，得分足够高就直接采用，否则再让其余包试听，取得分最高的。
+    /// 试听结果直接复用
+    fn select_pack(
+        &self,
+        window: &PcmWindow,
+        segments: &[SpeechSegment],
+        hint: Option<&str>,
+    ) -> PackSelection {
+        if let Some(lang) = hint.and_then(language::normalize_language) {
+            let pack_languages: Vec<_> =
+                self.packs.iter().map(|p| p.languages.as_slice()).collect();
+            let index = language::route_language(&pack_languages, &lang).unwrap_or_else(|| {
+                warn!(
+                    "⚠️ No model pack for language {}, using {}",
+                    lang, self.packs[0].dir
+                );
+                0
+            });
+            return PackSelection {
+                index,
+                language: Some(lang),
+                detected: false,
+                probe: Vec::new(),
+            };
+        }
+
+        let probe_count = language::probe_count(segments, PROBE_SECS);
+        if self.packs.len() == 1 || probe_count == 0 {
+            return PackSelection {
+                index: 0,
+                language: None,
+                detected: false,
+                probe: Vec::new(),
+            };
+        }
+
+        info!(
+            "🌐 Identifying language on {} segment(s) with {} model packs",
+            probe_count,
+            self.packs.len()
+        );
+        let mut probes = Vec::with_capacity(self.packs.len());
+        let mut scores = Vec::with_capacity(self.packs.len());
+        for pack in &self.packs {
+            let results = pack
+                .pool
+                .transcribe_segments(window, &segments[..probe_count], None);
+            let ok: Vec<_> = results
+                .iter()
+                .filter_map(|r| r.as_ref().ok())
+                .cloned()
+                .collect();
+            let score = language::probe_score(&ok);
+            probes.push(results);
+            scores.push(score);
+            // 多数音频就是默认包的语言，默认包有把握时省掉其余包的试听
+            if scores.len() == 1 && score.is_some_and(|s| s >= language::ACCEPT_SCORE) {
+                break;
+            }
+        }
+        let index = language::pick_best(&scores);
         info!(
-            "🎙️  Running ASR on {} speech segments...",
-            speech_segments.len()
+            "🌐 Language scores {:?}, picked {}",
+            scores, self.packs[index].dir
+        );
+
+        PackSelection {
+            index,
+            language: self.packs[index].languages.first().cloned(),
+            detected: true,
+            probe: probes.swap_remove(index),
+        }
+    }
+
+    /// 获取模型目录
+    #[frb(sync, getter)]
+    pub fn models_dir(&self) -> String {
+        self.models_dir.clone()
+    }
+}
+
+/// 模型包选择结果
+struct PackSelection {
+    index: usize,
+    language: Option<String>,
+    detected: bool,
+    /// 语言识别时已得到的开头片段结果
+    probe: Vec<Result<TranscriptSegment, AudioError>>,
+}
+
+/// 一次流式转录的状态
+///
+/// 片段确定后先排队；需要语言识别时攒够试听时长再选模型包，之后来一批转录一批。
+/// 说话人声纹在片段音频还在窗口中时提取，最后统一聚类
+struct Session<'a> {
+    recognizer: &'a AudioRecognizer,
+    hint: Option<String>,
+    selection: Option<PackSelection>,
+    /// 已确定、尚未转录的片段
+    queued: Vec<SpeechSegment>,
+    segments: Vec<SpeechSegment>,
+    results: Vec<Result<TranscriptSegment, AudioError>>,
+    embeddings: Vec<Vec<f32>>,
+}
+
+impl<'a> Session<'a> {
+    fn new(recognizer: &'a AudioRecognizer, hint: Option<String>) -> Self {
+        Self {
+            recognizer,
+            hint,
+            selection: None,
+            queued: Vec::new(),
+            segments: Vec::new(),
+            results: Vec::new(),
+            embeddings: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, window: &PcmWindow, segments: Vec<SpeechSegment>) {
+        if segments.is_empty() {
+            return;
+        }
+        self.queued.extend(segments);
+        self.flush(window, false);
+    }
+
+    /// 转录排队的片段；`force` 为 false 且还在等待语言识别的试听片段时先不处理
+    fn flush(&mut self, window: &PcmWindow, force: bool) {
+        if self.queued.is_empty() && !force {
+            return;
+        }
+
+        let mut done = Vec::new();
+        if self.selection.is_none() {
+            if !force && self.awaiting_probe(window) {
+                return;
+            }
+            let mut selection =
+                self.recognizer
+                    .select_pack(window, &self.queued, self.hint.as_deref());
+            let pack = &self.recognizer.packs[selection.index];
+            info!(
+                "🧵 Transcribing with model pack {} ({} recognizer instance(s)), language: {:?}",
+                pack.dir,
+                pack.pool.size(),
+                selection.language
+            );
+            done = std::mem::take(&mut selection.probe);
+            self.selection = Some(selection);
+        }
+        if self.queued.is_empty() {
+            return;
+        }
+
+        let selection = self.selection.as_ref().expect("model pack selected");
+        let queued = std::mem::take(&mut self.queued);
+        info!("🎙️  Running ASR on {} speech segments...", queued.len());
+        done.extend(
+            self.recognizer.packs[selection.index]
+                .pool
+                .transcribe_segments(window, &queued[done.len()..], selection.language.as_deref()),
         );
+        if let Some(diarizer) = &self.recognizer.diarizer {
+            self.embeddings.extend(
+                queued
+                    .iter()
+                    .map(|segment| diarizer.embed(&window.extract(segment))),
+            );
+        }
+        self.segments.extend(queued);
+        self.results.extend(done);
+    }
 
-        let mut lines: Vec<String> = Vec::new();
+    /// 没有语言提示且有多个模型包时，排队的语音不足试听时长就继续等待
+    fn awaiting_probe(&self, window: &PcmWindow) -> bool {
+        let hinted = self
+            .hint
+            .as_deref()
+            .and_then(language::normalize_language)
+            .is_some();
+        if hinted || self.recognizer.packs.len() == 1 {
+            return false;
+        }
+        let speech: f32 = self.queued.iter().map(|s| s.end - s.start).sum();
+        let span = window.end_secs() - self.queued[0].start;
+        speech < PROBE_SECS && span < PROBE_SPAN_SECS
+    }
 
-        for (index, segment) in speech_segments.iter().enumerate() {
+    /// 窗口中需要保留的最早时间
+    fn keep_from(&self) -> Option<f32> {
+        self.queued.first().map(|segment| segment.start)
+    }
+
+    fn has_segments(&self) -> bool {
+        !self.segments.is_empty()
+    }
+
+    /// 已转录的片段，VAD 路径下即检测到的语音区间
+    fn speech(&self) -> &[SpeechSegment] {
+        &self.segments
+    }
+
+    fn finish(
+        mut self,
+        segmentation: SegmentationPath,
+        fallback_reason: Option<String>,
+        events: Vec<AudioEvent>,
+    ) -> Transcript {
+        if let Some(diarizer) = &self.recognizer.diarizer {
+            info!("🗣️ Running speaker diarization...");
+            diarizer.assign(&mut self.segments, &self.embeddings);
+        }
+
+        let selection = self.selection.expect("model pack selected");
+        let mut transcript = Transcript {
+            segmentation,
+            fallback_reason,
+            model_pack: self.recognizer.packs[selection.index].dir.clone(),
+            language: selection.language,
+            language_detected: selection.detected,
+            events,
+            ..Default::default()
+        };
+
+        for (index, (segment, result)) in self.segments.iter().zip(self.results).enumerate() {
             info!(
                 "📦 Segment {}: {:.2}s - {:.2}s (duration: {:.2}s)",
                 index + 1,
@@ -146,24 +612,15 @@ impl AudioRecognizer {
                 segment.end - segment.start
             );
 
-            let segment_samples = VadHandle::extract_segment(&pcm_16k, 16_000, segment);
+            match result {
+                Ok(mut result) => {
+                    result.speaker = segment.speaker;
+                    self.recognizer.postprocessor.process(&mut result);
+                    info!("✅ Segment {} complete ({} chars)", index + 1, result.text.len());
+                    debug!("   Text: {}", result.text);
 
-            debug!(
-                "   Extracted {} samples for segment {}",
-                segment_samples.len(),
-                index + 1
-            );
-
-            match self.ncnn.transcribe(&segment_samples, 16_000, language.as_deref()) {
-                Ok(result) => {
-                    info!("✅ Segment {} complete ({} chars)", index + 1, result.len());
-                    debug!("   Text: {}", result);
-
-                    if !result.trim().is_empty() {
-                        let start_time = format_timestamp(segment.start);
-                        let end_time = format_timestamp(segment.end);
-                        let line = format!("{} - {}  --  {}", start_time, end_time, result.trim());
-                        lines.push(line);
+                    if !result.text.is_empty() {
+                        transcript.segments.push(result);
                     }
                 }
                 Err(e) => {
@@ -173,19 +630,12 @@ impl AudioRecognizer {
             }
         }
 
-        let result = lines.join("\n");
         info!(
             "🎯 All segments processed, total lines: {}",
-            lines.len()
+            transcript.segments.len()
         );
-        debug!("Result:\n{}", result);
-        Ok(result)
-    }
-
-    /// 获取模型目录
-    #[frb(sync, getter)]
-    pub fn models_dir(&self) -> String {
-        self.models_dir.clone()
+        debug!("Result:\n{}", format_transcript(&transcript));
+        transcript
     }
 }
 
@@ -195,6 +645,75 @@ impl Drop for AudioRecognizer {
     }
 }
 
+/// 按 models_dir/models.json 校验模型文件
+///
+/// verify_checksums 为 true 时计算每个文件的 SHA-256（模型较大，耗时数秒）；
+/// 为 false 时只比较大小。models.json 不存在或格式错误时返回错误
+#[frb(dart_async)]
+pub async fn validate_models(
+    models_dir: String,
+    verify_checksums: bool,
+) -> Result<ModelValidationReport, AudioError> {
+    manifest::validate_models(Path::new(&models_dir), verify_checksums)
+}
+
+/// 创建识别器前的快速校验，没有清单时跳过
+fn check_manifest(models_dir: &Path) -> Result<(), AudioError> {
+    if !models_dir.join(MANIFEST_FILE).exists() {
+        debug!(
+            "No {} in {:?}, skipping model validation",
+            MANIFEST_FILE, models_dir
+        );
+        return Ok(());
+    }
+    let report = manifest::validate_models(models_dir, false)?;
+    if report.is_valid() {
+        return Ok(());
+    }
+    let issues = manifest::describe_issues(&report);
+    error!("❌ Model validation failed: {}", issues);
+    Err(AudioError::ModelLoadFailed(format!(
+        "model files missing or corrupt: {}",
+        issues
+    )))
+}
+
+fn validate_fallback(fallback: &VadFallback) -> Result<(), AudioError> {
+    match fallback {
+        VadFallback::FixedWindow {
+            window_ms,
+            overlap_ms,
+        } if *window_ms == 0 || overlap_ms >= window_ms => Err(AudioError::InvalidConfig(format!(
+            "fixed window must be longer than its overlap, got window {}ms, overlap {}ms",
+            window_ms, overlap_ms
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// 每个片段一行：`HH:MM:SS:mm - HH:MM:SS:mm  --  文本`，
+/// 启用说话人分离时文本前加 `[S1] ` 形式的说话人标签
+fn format_transcript(transcript: &Transcript) -> String {
+    transcript
+        .segments
+        .iter()
+        .map(|segment| {
+            let speaker = segment
+                .speaker
+                .map(|id| format!("[S{}] ", id + 1))
+                .unwrap_or_default();
+            format!(
+                "{} - {}  --  {}{}",
+                format_timestamp(segment.start_ms as f32 / 1000.0),
+                format_timestamp(segment.end_ms as f32 / 1000.0),
+                speaker,
+                segment.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Format seconds to HH:MM:SS:mm
 fn format_timestamp(seconds: f32) -> String {
     let hours = (seconds / 3600.0) as u32;
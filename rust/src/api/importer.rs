@@ -0,0 +1,216 @@
+//! 菜谱导入 - 分享文本 → 结构化菜谱的完整流程
+
+use crate::api::audio::AudioRecognizer;
+use crate::api::models::asr::{AudioRecognizerConfig, HotwordConfig};
+use crate::api::models::recipe::{ImportProgress, ImportStage, ImportedRecipe};
+use crate::api::models::xhs::XhsClientConfig;
+use crate::api::xhs::XhsApiError;
+use crate::core::audio::AudioError;
+use crate::core::provider::{ProviderError, ProviderRegistry};
+use crate::core::recipe::parse_recipe;
+use crate::core::video::{FrameExtractorManager, YFrameData};
+use crate::core::xhs::AsyncXhsParser;
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::{info, warn};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ImportError {
+    #[error("{0}")]
+    Xhs(#[from] XhsApiError),
+    #[error("不支持的分享链接")]
+    UnsupportedLink,
+    #[error("内容获取失败: {0}")]
+    Provider(String),
+    #[error("视频下载失败: {0}")]
+    Download(String),
+    #[error("音频解码失败: {0}")]
+    Decode(String),
+    #[error("{0}")]
+    Audio(#[from] AudioError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl From<ProviderError> for ImportError {
+    fn from(e: ProviderError) -> Self {
+        match e {
+            ProviderError::Xhs(e) => ImportError::Xhs(e.into()),
+            ProviderError::UnsupportedUrl(_) => ImportError::UnsupportedLink,
+            e => ImportError::Provider(e.to_string()),
+        }
+    }
+}
+
+/// 菜谱导入器 - 串联内容平台解析、视频下载、原生解码、ASR 与关键帧提取
+///
+/// 分享链接按 [`ProviderRegistry`] 的注册顺序匹配平台（小红书、下厨房）。
+/// 音频解码与视频抽帧依赖平台能力，由 Dart 侧以回调形式提供。
+///
+/// ```dart
+/// final importer = await RecipeImporter.create(modelsDir: modelsPath, workDir: tmpPath);
+/// final recipe = await importer.importFromText(
+///   shareText: text,
+///   decodeAudio: (videoPath) => MediaNativeDecoder.decodeAudioToWav(videoPath),
+///   extractFrames: (videoPath) => collectYFrames(videoPath),
+///   onProgress: (p) => print('${p.stage}: ${p.message}'),
+/// );
+/// ```
+#[frb(opaque)]
+pub struct RecipeImporter {
+    work_dir: PathBuf,
+    registry: Arc<ProviderRegistry>,
+    /// 仅用于下载视频，页面抓取走 registry
+    downloader: AsyncXhsParser,
+    recognizer: AudioRecognizer,
+}
+
+impl RecipeImporter {
+    /// 创建导入器并加载音频模型
+    ///
+    /// work_dir 用于存放下载的视频与热词文件；client_config 为 None 时使用默认网络配置
+    #[frb(dart_async)]
+    pub async fn create(
+        models_dir: String,
+        work_dir: String,
+        client_config: Option<XhsClientConfig>,
+    ) -> Result<Self, ImportError> {
+        info!("📥 RecipeImporter: initializing, work_dir: {}", work_dir);
+        std::fs::create_dir_all(&work_dir)?;
+
+        let client_config = client_config.unwrap_or_default();
+        let downloader = AsyncXhsParser::with_config(&client_config).map_err(XhsApiError::from)?;
+        // 各平台使用阻塞客户端，不能在异步运行时中创建
+        let registry =
+            tokio::task::spawn_blocking(move || ProviderRegistry::with_config(&client_config))
+                .await
+                .map_err(|e| ImportError::Provider(e.to_string()))??;
+
+        // 导入的都是菜谱视频，默认启用内置烹饪词表；热词文件写在 work_dir，模型目录可能只读
+        let recognizer_config = AudioRecognizerConfig {
+            hotwords: Some(HotwordConfig {
+                work_dir: Some(work_dir.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let recognizer = AudioRecognizer::create(models_dir, Some(recognizer_config)).await?;
+
+        Ok(Self {
+            work_dir: PathBuf::from(work_dir),
+            registry: Arc::new(registry),
+            downloader,
+            recognizer,
+        })
+    }
+
+    /// 从分享文本导入菜谱
+    ///
+    /// - `decode_audio`: 视频路径 → 解码后的 WAV 路径
+    /// - `extract_frames`: 视频路径 → Y 平面帧列表（建议在 Dart 侧先按时间抽样）
+    /// - `on_progress`: 阶段进度回调
+    #[frb(dart_async)]
+    pub async fn import_from_text(
+        &self,
+        share_text: String,
+        language: Option<String>,
+        decode_audio: impl Fn(String) -> DartFnFuture<String>,
+        extract_frames: impl Fn(String) -> DartFnFuture<Vec<YFrameData>>,
+        on_progress: impl Fn(ImportProgress) -> DartFnFuture<()>,
+    ) -> Result<ImportedRecipe, ImportError> {
+        report(&on_progress, ImportStage::FetchingNote, "正在解析笔记...").await;
+        let registry = self.registry.clone();
+        let (source_url, article) =
+            tokio::task::spawn_blocking(move || registry.fetch_from_text(&share_text))
+                .await
+                .map_err(|e| ImportError::Provider(e.to_string()))??;
+        info!(
+            "📄 Article fetched: {} ({:?}, {:?})",
+            article.title, article.platform, article.note_type
+        );
+
+        let mut transcript = None;
+        let mut keyframes = Vec::new();
+
+        if let Some(video) = &article.video {
+            report(
+                &on_progress,
+                ImportStage::DownloadingVideo,
+                "正在下载视频...",
+            )
+            .await;
+            let video_path = self.work_dir.join(video_file_name(&source_url));
+            let bytes = self
+                .downloader
+                .download_video(&video.play_url, &video_path)
+                .await
+                .map_err(|e| ImportError::Download(e.to_string()))?;
+            info!("⬇️ Video downloaded: {} bytes -> {:?}", bytes, video_path);
+            let video_path = video_path.to_string_lossy().to_string();
+
+            report(&on_progress, ImportStage::DecodingAudio, "正在提取音频...").await;
+            let wav_path = decode_audio(video_path.clone()).await;
+            if wav_path.is_empty() || !Path::new(&wav_path).exists() {
+                return Err(ImportError::Decode(format!(
+                    "decoded wav not found: {:?}",
+                    wav_path
+                )));
+            }
+
+            report(&on_progress, ImportStage::Transcribing, "正在转录语音...").await;
+            transcript = Some(self.recognizer.transcribe_audio(wav_path, language).await?);
+
+            report(
+                &on_progress,
+                ImportStage::ExtractingFrames,
+                "正在提取关键帧...",
+            )
+            .await;
+            let frames = extract_frames(video_path).await;
+            // 每次导入单独计数，并发导入互不影响
+            let frame_extractor = FrameExtractorManager::new();
+            keyframes = frame_extractor.process_batch(frames);
+            info!("🖼️ Keyframes extracted: {}", keyframes.len());
+        } else {
+            warn!("⚠️ Article has no video, skipping audio and frame extraction");
+        }
+
+        report(&on_progress, ImportStage::ParsingRecipe, "正在解析菜谱...").await;
+        let recipe = match &article.recipe {
+            Some(recipe) => recipe.clone(),
+            None => parse_recipe(&article.title, &article.desc, transcript.as_deref()),
+        };
+
+        report(&on_progress, ImportStage::Complete, "导入完成").await;
+        Ok(ImportedRecipe {
+            source_url,
+            article,
+            transcript,
+            keyframes,
+            recipe,
+        })
+    }
+}
+
+/// 按链接生成稳定的文件名，重复导入同一链接时覆盖旧文件
+fn video_file_name(source_url: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    source_url.hash(&mut hasher);
+    format!("{:016x}.mp4", hasher.finish())
+}
+
+async fn report(
+    on_progress: &impl Fn(ImportProgress) -> DartFnFuture<()>,
+    stage: ImportStage,
+    message: &str,
+) {
+    info!("📥 Import stage: {:?}", stage);
+    on_progress(ImportProgress {
+        stage,
+        message: message.to_string(),
+    })
+    .await;
+}
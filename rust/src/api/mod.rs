@@ -1,4 +1,5 @@
 pub mod audio;
+pub mod importer;
 pub mod models;
 pub mod video;
 pub mod xhs;
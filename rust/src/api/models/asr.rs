@@ -0,0 +1,430 @@
+use serde::{Deserialize, Serialize};
+
+/// 热词，用于提升菜名、食材等专有词的识别率
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hotword {
+    pub phrase: String,
+    /// 加权分数，None 时使用 [`HotwordConfig::default_score`]
+    pub score: Option<f32>,
+}
+
+/// 热词配置，启用后解码方式切换为 modified beam search
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HotwordConfig {
+    /// 自定义热词，与内置词表重复时以自定义分数为准
+    pub hotwords: Vec<Hotword>,
+    /// 是否合并内置的烹饪词表（调料、食材、烹饪手法）
+    pub use_cooking_lexicon: bool,
+    pub default_score: f32,
+    /// 热词文件的存放目录，需可写；None 时使用系统临时目录
+    ///
+    /// 每个识别器生成自己的热词文件，识别器释放时删除
+    pub work_dir: Option<String>,
+}
+
+impl Default for HotwordConfig {
+    fn default() -> Self {
+        Self {
+            hotwords: Vec::new(),
+            use_cooking_lexicon: true,
+            default_score: 1.5,
+            work_dir: None,
+        }
+    }
+}
+
+/// 解码方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecodingMethod {
+    /// 贪心搜索，最快、最省电
+    GreedySearch,
+    /// 改进的束搜索，更准确，热词加权依赖该方式
+    ModifiedBeamSearch,
+}
+
+impl DecodingMethod {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            DecodingMethod::GreedySearch => "greedy_search",
+            DecodingMethod::ModifiedBeamSearch => "modified_beam_search",
+        }
+    }
+}
+
+/// 端点检测规则（秒）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointConfig {
+    pub enable: bool,
+    /// 尚未识别出内容时，尾部静音超过该值即判定为端点
+    pub rule1_min_trailing_silence: f32,
+    /// 已识别出内容后，尾部静音超过该值即判定为端点
+    pub rule2_min_trailing_silence: f32,
+    /// 单句长度超过该值即强制断句
+    pub rule3_min_utterance_length: f32,
+}
+
+impl Default for EndpointConfig {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            rule1_min_trailing_silence: 2.4,
+            rule2_min_trailing_silence: 1.2,
+            rule3_min_utterance_length: 300.0,
+        }
+    }
+}
+
+/// 模型目录下的文件名
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AsrModelFiles {
+    pub encoder_param: String,
+    pub encoder_bin: String,
+    pub decoder_param: String,
+    pub decoder_bin: String,
+    pub joiner_param: String,
+    pub joiner_bin: String,
+    pub tokens: String,
+}
+
+impl Default for AsrModelFiles {
+    fn default() -> Self {
+        Self {
+            encoder_param: "encoder_jit_trace-pnnx.ncnn.param".to_string(),
+            encoder_bin: "encoder_jit_trace-pnnx.ncnn.bin".to_string(),
+            decoder_param: "decoder_jit_trace-pnnx.ncnn.param".to_string(),
+            decoder_bin: "decoder_jit_trace-pnnx.ncnn.bin".to_string(),
+            joiner_param: "joiner_jit_trace-pnnx.ncnn.param".to_string(),
+            joiner_bin: "joiner_jit_trace-pnnx.ncnn.bin".to_string(),
+            tokens: "tokens.txt".to_string(),
+        }
+    }
+}
+
+impl AsrModelFiles {
+    pub(crate) fn all(&self) -> [&str; 7] {
+        [
+            &self.encoder_param,
+            &self.encoder_bin,
+            &self.decoder_param,
+            &self.decoder_bin,
+            &self.joiner_param,
+            &self.joiner_bin,
+            &self.tokens,
+        ]
+    }
+}
+
+/// ASR 解码配置，低端机可选贪心搜索、减少线程以节省电量
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AsrConfig {
+    pub decoding_method: DecodingMethod,
+    /// 束搜索保留的路径数（beam size），仅 ModifiedBeamSearch 生效
+    pub num_active_paths: u32,
+    /// 推理线程数，0 表示自动（CPU 核数，最多 4）
+    pub num_threads: u32,
+    pub endpoint: EndpointConfig,
+    pub model_files: AsrModelFiles,
+}
+
+impl Default for AsrConfig {
+    fn default() -> Self {
+        Self {
+            decoding_method: DecodingMethod::GreedySearch,
+            num_active_paths: 4,
+            num_threads: 0,
+            endpoint: EndpointConfig::default(),
+            model_files: AsrModelFiles::default(),
+        }
+    }
+}
+
+/// ASR 模型包，对应 models_dir 下的一个子目录
+///
+/// 各模型包共用 [`AsrConfig`]（包括模型文件名）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelPackConfig {
+    /// 子目录名，如 `sherpa-ncnn`、`sherpa-ncnn-yue`
+    pub dir: String,
+    /// 支持的语言代码，如 `zh`、`en`、`yue`
+    pub languages: Vec<String>,
+}
+
+impl ModelPackConfig {
+    pub(crate) fn new(dir: &str, languages: &[&str]) -> Self {
+        Self {
+            dir: dir.to_string(),
+            languages: languages.iter().map(|l| l.to_string()).collect(),
+        }
+    }
+}
+
+/// 识别器池配置，多个识别器实例并行转录 VAD 片段
+///
+/// 每个实例都会完整加载一份模型，实例数同时受 CPU 核数与内存上限约束，至少加载 1 个
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecognizerPoolConfig {
+    /// 实例数上限，0 表示自动（CPU 核数的一半，最多 4）
+    pub max_instances: u32,
+    /// 所有实例的内存上限（MB），按模型文件大小估算，0 表示不限制
+    pub max_memory_mb: u32,
+}
+
+impl Default for RecognizerPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_instances: 0,
+            max_memory_mb: 512,
+        }
+    }
+}
+
+/// Silero VAD 参数与片段后处理
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VadConfig {
+    /// 语音概率阈值，0.0 ~ 1.0，越大越严格
+    pub threshold: f32,
+    /// 静音超过该时长才切分片段（毫秒）
+    pub min_silence_duration_ms: u32,
+    /// 短于该时长的语音被丢弃（毫秒）
+    pub min_speech_duration_ms: u32,
+    pub num_threads: u32,
+    /// VAD 内部缓冲区长度（秒）
+    pub buffer_size_secs: f32,
+    /// 片段前后各外扩的时长（毫秒），避免切掉首尾的字
+    pub pad_ms: u32,
+    /// 间隔短于该时长的相邻片段合并（毫秒），0 表示不合并
+    pub merge_gap_ms: u32,
+    /// 长于该时长的片段被均分（毫秒），0 表示不切分；过长的片段会明显降低识别质量
+    pub max_segment_ms: u32,
+}
+
+impl Default for VadConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.5,
+            min_silence_duration_ms: 500,
+            min_speech_duration_ms: 250,
+            num_threads: 2,
+            buffer_size_secs: 60.0,
+            pad_ms: 100,
+            merge_gap_ms: 200,
+            max_segment_ms: 20_000,
+        }
+    }
+}
+
+/// VAD 失败或未检测到语音时的处理方式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VadFallback {
+    /// 直接报错
+    Error,
+    /// 按固定窗口切分整段音频，相邻窗口重叠以免切断字词（重叠处可能重复少量文字）
+    FixedWindow { window_ms: u32, overlap_ms: u32 },
+    /// 整段音频作为一个片段识别，长音频会很慢且质量明显下降
+    WholeFile,
+}
+
+impl Default for VadFallback {
+    fn default() -> Self {
+        VadFallback::FixedWindow {
+            window_ms: 15_000,
+            overlap_ms: 1_000,
+        }
+    }
+}
+
+/// 重采样到 16kHz 的质量档位，档位越高滤波器越长、混叠越少、越耗电
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResampleQuality {
+    /// 阻带衰减约 60dB，适合低端设备
+    Fast,
+    /// 阻带衰减约 80dB
+    #[default]
+    Balanced,
+    /// 阻带衰减约 100dB，过渡带最窄
+    High,
+}
+
+/// 响度测量方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LoudnessMeasure {
+    /// 直接计算均方根电平（dBFS）
+    Rms,
+    /// ITU-R BS.1770 K 加权响度（LUFS），更接近人耳感受
+    Lufs,
+}
+
+/// 响度归一化配置，按最近一段时间的响度平滑调整增益，静音不会被放大
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoudnessConfig {
+    pub measure: LoudnessMeasure,
+    /// 目标响度（dBFS 或 LUFS）
+    pub target_db: f32,
+    /// 最大增益（dB），避免把远处的微弱声音放大成噪声
+    pub max_gain_db: f32,
+    /// 响度测量窗口（毫秒）
+    pub window_ms: u32,
+}
+
+impl Default for LoudnessConfig {
+    fn default() -> Self {
+        Self {
+            measure: LoudnessMeasure::Lufs,
+            target_db: -23.0,
+            max_gain_db: 24.0,
+            window_ms: 3_000,
+        }
+    }
+}
+
+/// 谱减降噪配置，噪声谱持续跟踪，适合抽油烟机等平稳噪声
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NoiseReductionConfig {
+    /// 过减因子，越大降噪越狠，语音失真也越明显
+    pub over_subtraction: f32,
+    /// 每个频点的最小增益（dB），保留少量底噪以减轻“音乐噪声”
+    pub floor_db: f32,
+}
+
+impl Default for NoiseReductionConfig {
+    fn default() -> Self {
+        Self {
+            over_subtraction: 2.0,
+            floor_db: -20.0,
+        }
+    }
+}
+
+/// 音频预处理，按 去直流 → 高通 → 降噪 → 响度归一化 的顺序在 VAD 与 ASR 之前执行，每一步可单独关闭
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreprocessConfig {
+    pub remove_dc: bool,
+    /// 高通截止频率（Hz），滤掉抽油烟机、冰箱等低频嗡声，0 表示关闭
+    pub high_pass_hz: u32,
+    pub noise_reduction: Option<NoiseReductionConfig>,
+    pub loudness: Option<LoudnessConfig>,
+}
+
+impl Default for PreprocessConfig {
+    fn default() -> Self {
+        Self {
+            remove_dc: true,
+            high_pass_hz: 80,
+            noise_reduction: Some(NoiseReductionConfig::default()),
+            loudness: Some(LoudnessConfig::default()),
+        }
+    }
+}
+
+/// 声音事件检测配置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventDetectionConfig {
+    /// 短于该时长的事件被丢弃（毫秒），检测以 512ms 为一块
+    pub min_event_ms: u32,
+    /// 置信度低于该值的块不标注事件
+    pub min_confidence: f32,
+}
+
+impl Default for EventDetectionConfig {
+    fn default() -> Self {
+        Self {
+            min_event_ms: 500,
+            min_confidence: 0.5,
+        }
+    }
+}
+
+/// 说话人分离配置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiarizationConfig {
+    /// 最多区分的说话人数
+    pub max_speakers: u32,
+    /// 聚类合并阈值（余弦距离，0.0 ~ 2.0），越小越容易分出新的说话人
+    pub threshold: f32,
+    /// 短于该时长的片段声纹不可靠，不参与聚类，沿用时间上最近片段的说话人（毫秒）
+    pub min_segment_ms: u32,
+}
+
+impl Default for DiarizationConfig {
+    fn default() -> Self {
+        Self {
+            max_speakers: 4,
+            threshold: 0.15,
+            min_segment_ms: 1_000,
+        }
+    }
+}
+
+/// 标点恢复方式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PunctuationMode {
+    Off,
+    /// 按 token 间的停顿加逗号，句末加句号或问号
+    Rules {
+        /// 相邻 token 起始时间间隔超过该值时加逗号（毫秒）
+        pause_ms: u32,
+    },
+}
+
+/// 识别结果后处理，按 标点 → 中英混排 → 数字规整 的顺序对每个片段执行，每一步单独开启
+///
+/// 默认全部关闭，输出与识别器原文一致。只修改片段的 `text`，`tokens` 保留识别器的原始输出
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostprocessConfig {
+    pub punctuation: PunctuationMode,
+    /// 模型输出的全大写英文转小写（热词中的缩写保持大写），规范中英文之间的空格
+    pub mixed_english: bool,
+    /// 中文数字与单位、时间转写为阿拉伯数字（三百克 → 300克，八点十五分 → 8:15）
+    pub inverse_text_normalization: bool,
+}
+
+impl Default for PostprocessConfig {
+    fn default() -> Self {
+        Self {
+            punctuation: PunctuationMode::Off,
+            mixed_english: false,
+            inverse_text_normalization: false,
+        }
+    }
+}
+
+/// 音频识别器配置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioRecognizerConfig {
+    /// 热词配置，不为 None 时按各模型包的词表生成热词文件并启用热词加权
+    pub hotwords: Option<HotwordConfig>,
+    pub asr: AsrConfig,
+    /// 模型包列表，第一个为默认包且必须存在；其余包目录不存在时跳过
+    pub model_packs: Vec<ModelPackConfig>,
+    /// 识别器池配置，内存上限由所有模型包均分
+    pub pool: RecognizerPoolConfig,
+    pub vad: VadConfig,
+    pub vad_fallback: VadFallback,
+    /// 说话人分离，不为 None 时为每个片段标注说话人
+    pub diarization: Option<DiarizationConfig>,
+    pub postprocess: PostprocessConfig,
+    /// 非 16kHz 输入的重采样质量
+    pub resample_quality: ResampleQuality,
+    /// 音频预处理，不为 None 时在 VAD 与 ASR 之前对 16kHz 音频执行
+    pub preprocess: Option<PreprocessConfig>,
+    /// 声音事件检测，不为 None 时在非语音区间标注油爆、水开、提示音等事件
+    pub events: Option<EventDetectionConfig>,
+}
+
+impl Default for AudioRecognizerConfig {
+    fn default() -> Self {
+        Self {
+            hotwords: None,
+            asr: AsrConfig::default(),
+            model_packs: vec![ModelPackConfig::new("sherpa-ncnn", &["zh", "en"])],
+            pool: RecognizerPoolConfig::default(),
+            vad: VadConfig::default(),
+            vad_fallback: VadFallback::default(),
+            diarization: None,
+            postprocess: PostprocessConfig::default(),
+            resample_quality: ResampleQuality::default(),
+            preprocess: None,
+            events: None,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// 模型清单（models_dir/models.json），随模型包一起分发
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelManifest {
+    pub version: u32,
+    pub files: Vec<ModelFileEntry>,
+}
+
+/// 清单中的一个模型文件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelFileEntry {
+    /// 相对 models_dir 的路径，使用 `/` 分隔，如 `sherpa-ncnn/tokens.txt`
+    pub path: String,
+    /// 文件大小（字节）
+    pub size: u64,
+    /// 小写十六进制 SHA-256
+    pub sha256: String,
+}
+
+/// 单个文件的校验问题
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ModelIssueKind {
+    /// 文件不存在
+    Missing,
+    /// 路径为绝对路径或跳出了 models_dir
+    InvalidPath,
+    /// 大小不符，通常是下载被截断
+    SizeMismatch { expected: u64, actual: u64 },
+    /// 大小一致但内容不符
+    ChecksumMismatch { expected: String, actual: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelFileIssue {
+    pub path: String,
+    pub kind: ModelIssueKind,
+}
+
+/// 模型校验结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelValidationReport {
+    /// 清单中的文件数
+    pub checked: u32,
+    /// 是否计算了 SHA-256；为 false 时只比较了大小
+    pub checksums_verified: bool,
+    pub issues: Vec<ModelFileIssue>,
+}
+
+impl ModelValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
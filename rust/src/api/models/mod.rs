@@ -1,3 +1,25 @@
+pub mod asr;
+pub mod manifest;
+pub mod recipe;
+pub mod source;
+pub mod transcript;
 pub mod xhs;
 
-pub use xhs::{NoteType, XhsArticle, XhsAuthor, XhsVideo};
+pub use asr::{
+    AsrConfig, AsrModelFiles, AudioRecognizerConfig, DecodingMethod, DiarizationConfig,
+    EndpointConfig, EventDetectionConfig, Hotword, HotwordConfig, LoudnessConfig, LoudnessMeasure,
+    ModelPackConfig, NoiseReductionConfig, PostprocessConfig, PreprocessConfig, PunctuationMode,
+    RecognizerPoolConfig, ResampleQuality, VadConfig, VadFallback,
+};
+pub use manifest::{
+    ModelFileEntry, ModelFileIssue, ModelIssueKind, ModelManifest, ModelValidationReport,
+};
+pub use recipe::{ImportProgress, ImportStage, ImportedRecipe, ParsedRecipe, RecipeIngredient};
+pub use source::{Platform, SourceArticle, SourceAuthor, SourceVideo};
+pub use transcript::{
+    AudioEvent, AudioEventKind, SegmentationPath, Transcript, TranscriptSegment, TranscriptToken,
+};
+pub use xhs::{
+    NoteType, XhsArticle, XhsAuthor, XhsAuthorProfile, XhsCacheConfig, XhsClientConfig, XhsLink,
+    XhsLinkKind, XhsNoteCard, XhsNotePage, XhsVideo,
+};
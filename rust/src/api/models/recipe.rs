@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::source::SourceArticle;
+use crate::core::video::FrameExtractedInfo;
+
+/// 导入流程阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportStage {
+    /// 解析分享链接并获取笔记或菜谱页面
+    FetchingNote,
+    /// 下载笔记视频
+    DownloadingVideo,
+    /// 原生解码音轨
+    DecodingAudio,
+    /// 语音转写
+    Transcribing,
+    /// 关键帧提取
+    ExtractingFrames,
+    /// 解析菜谱结构
+    ParsingRecipe,
+    /// 完成
+    Complete,
+}
+
+/// 导入进度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProgress {
+    pub stage: ImportStage,
+    pub message: String,
+}
+
+/// 食材条目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecipeIngredient {
+    pub name: String,
+    /// 用量，如 "300克"、"适量"
+    pub amount: Option<String>,
+}
+
+/// 从笔记文本中解析出的菜谱结构
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParsedRecipe {
+    pub title: String,
+    pub ingredients: Vec<RecipeIngredient>,
+    pub steps: Vec<String>,
+    pub tips: Vec<String>,
+}
+
+/// 一次导入的完整结果
+#[derive(Debug, Clone)]
+pub struct ImportedRecipe {
+    /// 规范化后的分享链接
+    pub source_url: String,
+    pub article: SourceArticle,
+    /// 语音转写文本，没有视频时为 None
+    pub transcript: Option<String>,
+    pub keyframes: Vec<FrameExtractedInfo>,
+    /// 平台自带结构化菜谱时直接使用，否则从正文与转写中解析
+    pub recipe: ParsedRecipe,
+}
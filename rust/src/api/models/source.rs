@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::models::recipe::ParsedRecipe;
+use crate::api::models::xhs::{NoteType, XhsArticle};
+
+/// 内容来源平台
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Platform {
+    /// 小红书
+    Xiaohongshu,
+    /// 下厨房
+    Xiachufang,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SourceAuthor {
+    pub name: String,
+    pub id: String,
+    pub avatar: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceVideo {
+    /// 时长（秒）
+    pub duration: i64,
+    pub cover: String,
+    pub play_url: String,
+}
+
+/// 平台无关的内容模型
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceArticle {
+    pub platform: Platform,
+    pub title: String,
+    pub desc: String,
+    pub author: SourceAuthor,
+    pub images: Vec<String>,
+    pub video: Option<SourceVideo>,
+    pub note_type: NoteType,
+    /// 平台自带的结构化菜谱（如下厨房的用料与步骤），没有时为 None
+    pub recipe: Option<ParsedRecipe>,
+}
+
+impl From<XhsArticle> for SourceArticle {
+    fn from(article: XhsArticle) -> Self {
+        Self {
+            platform: Platform::Xiaohongshu,
+            title: article.title,
+            desc: article.desc,
+            author: SourceAuthor {
+                name: article.author.nickname,
+                id: article.author.user_id,
+                avatar: article.author.avatar,
+            },
+            images: article.images,
+            video: article.video.map(|v| SourceVideo {
+                duration: v.duration,
+                cover: v.cover,
+                play_url: v.play_url,
+            }),
+            note_type: article.note_type,
+            recipe: None,
+        }
+    }
+}
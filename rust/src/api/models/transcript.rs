@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+
+/// 带时间戳的识别单元（中文通常为单字，英文为 BPE 子词）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptToken {
+    pub token: String,
+    /// 相对整段音频起点的毫秒数
+    pub start_ms: u64,
+    /// 置信度，0.0 ~ 1.0；识别器没有输出 token 分数时为 None
+    pub confidence: Option<f32>,
+}
+
+/// 一个语音片段的识别结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+    pub tokens: Vec<TranscriptToken>,
+    /// 说话人编号（从 0 开始），未启用说话人分离时为 None
+    pub speaker: Option<u32>,
+}
+
+/// 语音片段的切分方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SegmentationPath {
+    /// VAD 检测到的语音片段
+    #[default]
+    Vad,
+    /// VAD 不可用，按固定窗口切分
+    FixedWindow,
+    /// VAD 不可用，整段识别
+    WholeFile,
+}
+
+/// 烹饪相关的非语音声音
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AudioEventKind {
+    /// 热油下锅的滋滋声
+    Sizzle,
+    /// 水开后翻滚的咕嘟声
+    Boiling,
+    /// 定时器、烤箱的提示音
+    TimerBeep,
+    /// 料理机、破壁机等电机噪声
+    Blender,
+}
+
+/// 非语音区间内检测到的声音事件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioEvent {
+    pub kind: AudioEventKind,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// 置信度，0.0 ~ 1.0
+    pub confidence: f32,
+}
+
+/// 整段音频的识别结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Transcript {
+    pub segments: Vec<TranscriptSegment>,
+    pub segmentation: SegmentationPath,
+    /// 没有走 VAD 时的原因（VAD 出错或未检测到语音）
+    pub fallback_reason: Option<String>,
+    /// 实际使用的模型包目录
+    pub model_pack: String,
+    /// 规范化后的语言代码：调用方指定的语言，或语言识别的结果
+    pub language: Option<String>,
+    /// 语言是否由语言识别得出
+    pub language_detected: bool,
+    /// 非语音区间的声音事件（按时间排序），未启用事件检测时为空
+    pub events: Vec<AudioEvent>,
+}
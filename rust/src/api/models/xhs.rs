@@ -14,6 +14,109 @@ pub enum NoteType {
     Text,
 }
 
+impl NoteType {
+    /// 按媒体构成推断笔记类型；视频笔记自带一张封面图
+    pub(crate) fn from_media(has_video: bool, image_count: usize) -> Self {
+        match (has_video, image_count) {
+            (true, 0 | 1) => NoteType::Video,
+            (true, _) => NoteType::Mixed,
+            (false, 0) => NoteType::Text,
+            (false, _) => NoteType::Images,
+        }
+    }
+}
+
+/// 小红书 HTTP 客户端配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XhsClientConfig {
+    /// 连接超时（毫秒）
+    pub connect_timeout_ms: u64,
+    /// 读取超时（毫秒）；阻塞客户端没有单独的读取超时，作为整体请求超时使用
+    pub read_timeout_ms: u64,
+    /// 代理地址，如 `http://127.0.0.1:7890`
+    pub proxy: Option<String>,
+    /// 是否保存 Cookie，短链跳转时部分 Cookie 是后续请求所必需的
+    pub cookie_store: bool,
+    /// 初始 Cookie，格式同 `Set-Cookie`，如 `web_session=xxx`；未指定 Domain 时对所有
+    /// xiaohongshu.com 子域生效。不为空时总会启用 Cookie 存储
+    pub cookies: Vec<String>,
+    /// 最多跟随的跳转次数，0 表示不跟随
+    pub max_redirects: u32,
+    /// 笔记磁盘缓存，None 表示不缓存
+    pub cache: Option<XhsCacheConfig>,
+}
+
+impl Default for XhsClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout_ms: 10_000,
+            read_timeout_ms: 30_000,
+            proxy: None,
+            cookie_store: true,
+            cookies: Vec::new(),
+            max_redirects: 10,
+            cache: None,
+        }
+    }
+}
+
+/// 笔记磁盘缓存配置，以规范化的笔记 id 为键
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XhsCacheConfig {
+    /// 缓存目录，不存在时自动创建
+    pub cache_dir: String,
+    /// 缓存有效期（秒），0 表示永不过期
+    pub ttl_secs: u64,
+    /// 缓存目录总大小上限（字节），超出时按写入时间淘汰最旧的条目，0 表示不限制
+    pub max_size_bytes: u64,
+    /// 跳过缓存读取，总是重新抓取（结果仍会写回缓存）
+    pub bypass: bool,
+}
+
+impl XhsCacheConfig {
+    pub fn new(cache_dir: String) -> Self {
+        Self {
+            cache_dir,
+            ttl_secs: 7 * 24 * 3600,
+            max_size_bytes: 50 * 1024 * 1024,
+            bypass: false,
+        }
+    }
+}
+
+/// 分享链接类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum XhsLinkKind {
+    /// xhslink.com 短链，需要跟随跳转才能拿到笔记 id
+    ShortLink,
+    /// www.xiaohongshu.com/explore/<id>
+    Explore,
+    /// www.xiaohongshu.com/discovery/item/<id>
+    DiscoveryItem,
+}
+
+/// 规范化后的小红书链接
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XhsLink {
+    pub kind: XhsLinkKind,
+    /// 笔记 id；短链为短链编码
+    pub id: String,
+    pub xsec_token: Option<String>,
+    pub xsec_source: Option<String>,
+    /// 笔记直链统一为 `https://www.xiaohongshu.com/explore/<id>`（保留 xsec 参数），短链保持原样
+    pub url: String,
+}
+
+impl XhsLink {
+    /// 规范化的笔记 id，短链未解析前为 None
+    pub(crate) fn note_id(&self) -> Option<&str> {
+        match self.kind {
+            XhsLinkKind::ShortLink => None,
+            XhsLinkKind::Explore | XhsLinkKind::DiscoveryItem => Some(&self.id),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct XhsArticle {
     pub title: String,
@@ -41,6 +144,47 @@ pub struct XhsVideo {
     pub play_url: String,
 }
 
+/// 作者主页信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XhsAuthorProfile {
+    pub author: XhsAuthor,
+    /// 小红书号
+    pub red_id: String,
+    /// 个人简介
+    pub bio: String,
+    pub ip_location: Option<String>,
+    /// 关注数
+    pub follows: u64,
+    /// 粉丝数
+    pub fans: u64,
+    /// 获赞与收藏数
+    pub liked_and_collected: u64,
+    /// 主页首屏的笔记列表，后续分页不会自动抓取
+    pub notes: XhsNotePage,
+}
+
+/// 一页笔记卡片
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct XhsNotePage {
+    pub notes: Vec<XhsNoteCard>,
+    /// 下一页游标，没有更多时为空
+    pub cursor: String,
+    pub has_more: bool,
+}
+
+/// 作者主页中的笔记卡片
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XhsNoteCard {
+    pub note_id: String,
+    pub title: String,
+    pub cover: String,
+    /// 卡片只区分视频与图文，图文笔记统一为 Images
+    pub note_type: NoteType,
+    pub liked_count: u64,
+    /// 打开笔记详情需要携带的 xsec_token
+    pub xsec_token: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct NoteDetail {
     pub title: String,
@@ -1,66 +1,185 @@
-use crate::api::models::xhs::XhsArticle;
-use regex::Regex;
+use crate::api::models::xhs::{
+    XhsArticle, XhsAuthorProfile, XhsClientConfig, XhsLink, XhsNotePage,
+};
+use crate::core::xhs::{
+    classify_link, parse_note_page_json, profile_link, AsyncXhsParser, ParserError,
+};
+use flutter_rust_bridge::frb;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-/// 小红书 API 错误类型，FRB 友好的设计
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct XhsApiError {
-    pub error_type: String,
-    pub message: String,
+/// 小红书 API 错误，Dart 侧可按变体穷举处理
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+pub enum XhsApiError {
+    #[error("未找到小红书链接")]
+    UrlNotFound,
+    #[error("无法识别的笔记链接: {url}")]
+    InvalidLink { url: String },
+    #[error("HTTP 状态码异常: {status}")]
+    HttpStatus { status: u16, url: Option<String> },
+    #[error("网络请求失败: {message}")]
+    Network { message: String, timeout: bool },
+    #[error("HTTP 客户端配置错误: {message}")]
+    ClientConfig { message: String },
+    #[error("页面解析失败: {message}")]
+    Parse { message: String },
+    #[error("{reason}")]
+    Blocked { reason: XhsBlockReason },
+    #[error("文件读写失败: {message}")]
+    Io { message: String },
+}
+
+/// 无法拿到内容的页面类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Error)]
+pub enum XhsBlockReason {
+    #[error("笔记不存在或已被删除")]
+    NoteDeleted,
+    #[error("需要登录后才能查看")]
+    LoginRequired,
+    #[error("访问过于频繁，请稍后再试")]
+    RateLimited,
+    #[error("触发安全验证，需要完成验证码")]
+    CaptchaRequired,
+    #[error("内容在当前地区不可见")]
+    RegionBlocked,
 }
 
 impl XhsApiError {
-    fn url_not_found() -> Self {
-        Self {
-            error_type: "UrlNotFound".to_string(),
-            message: "未找到小红书链接".to_string(),
-        }
+    /// 稳定的错误码，用于埋点与多语言文案映射，不随提示文案变化
+    #[frb(sync, getter)]
+    pub fn code(&self) -> String {
+        let code = match self {
+            XhsApiError::UrlNotFound => "URL_NOT_FOUND",
+            XhsApiError::InvalidLink { .. } => "INVALID_LINK",
+            XhsApiError::HttpStatus { .. } => "HTTP_STATUS",
+            XhsApiError::Network { .. } => "NETWORK",
+            XhsApiError::ClientConfig { .. } => "CLIENT_CONFIG",
+            XhsApiError::Parse { .. } => "PARSE",
+            XhsApiError::Blocked { reason } => match reason {
+                XhsBlockReason::NoteDeleted => "NOTE_DELETED",
+                XhsBlockReason::LoginRequired => "LOGIN_REQUIRED",
+                XhsBlockReason::RateLimited => "RATE_LIMITED",
+                XhsBlockReason::CaptchaRequired => "CAPTCHA_REQUIRED",
+                XhsBlockReason::RegionBlocked => "REGION_BLOCKED",
+            },
+            XhsApiError::Io { .. } => "IO",
+        };
+        code.to_string()
     }
 
-    fn regex_error(e: String) -> Self {
-        Self {
-            error_type: "RegexError".to_string(),
-            message: format!("正则表达式错误: {}", e),
-        }
+    /// 面向用户的提示文案；不叫 message，避免与变体字段重名
+    #[frb(sync, getter)]
+    pub fn user_message(&self) -> String {
+        self.to_string()
     }
 }
 
-impl std::fmt::Display for XhsApiError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[{}] {}", self.error_type, self.message)
+impl From<ParserError> for XhsApiError {
+    fn from(e: ParserError) -> Self {
+        match e {
+            ParserError::Http(e) => match e.status() {
+                Some(status) => XhsApiError::HttpStatus {
+                    status: status.as_u16(),
+                    url: e.url().map(|u| u.to_string()),
+                },
+                None => XhsApiError::Network {
+                    timeout: e.is_timeout(),
+                    message: e.to_string(),
+                },
+            },
+            ParserError::ClientBuild(e) => XhsApiError::ClientConfig {
+                message: e.to_string(),
+            },
+            ParserError::InvalidLink(url) => XhsApiError::InvalidLink { url },
+            ParserError::NoteDeleted => XhsApiError::Blocked {
+                reason: XhsBlockReason::NoteDeleted,
+            },
+            ParserError::LoginRequired => XhsApiError::Blocked {
+                reason: XhsBlockReason::LoginRequired,
+            },
+            ParserError::RateLimited => XhsApiError::Blocked {
+                reason: XhsBlockReason::RateLimited,
+            },
+            ParserError::CaptchaRequired => XhsApiError::Blocked {
+                reason: XhsBlockReason::CaptchaRequired,
+            },
+            ParserError::RegionBlocked => XhsApiError::Blocked {
+                reason: XhsBlockReason::RegionBlocked,
+            },
+            ParserError::Io(e) => XhsApiError::Io {
+                message: e.to_string(),
+            },
+            e @ (ParserError::InitialStateMissing
+            | ParserError::Json(_)
+            | ParserError::ParseNote(_)) => XhsApiError::Parse {
+                message: e.to_string(),
+            },
+        }
     }
 }
 
-impl std::error::Error for XhsApiError {}
-
 /// 从混合文本中提取小红书 URL 并解析
 ///
 /// # 示例
 /// ```ignore
 /// let text = "家庭版馄饨｜早餐自制馄饨 真的太好吃了～好吃到汤都... http://xhslink.com/o/5ZMAfpDOokl 复制后打开【小红书】查看笔记！";
-/// let article = parse_xhs_from_text(text)?;
+/// let article = parse_xhs_from_text(text, None).await?;
 /// println!("标题: {}", article.title);
 /// ```
-#[flutter_rust_bridge::frb(sync)]
-pub fn parse_xhs_from_text(text: String) -> Result<XhsArticle, XhsApiError> {
+#[frb(dart_async)]
+pub async fn parse_xhs_from_text(
+    text: String,
+    config: Option<XhsClientConfig>,
+) -> Result<XhsArticle, XhsApiError> {
     let url = extract_xhs_url(&text)?;
-    unimplemented!("XHS parsing not yet implemented")
+    parse_xhs_from_url(url, config).await
 }
 
 /// 直接从 URL 解析小红书笔记
-#[flutter_rust_bridge::frb(sync)]
-pub fn parse_xhs_from_url(url: String) -> Result<XhsArticle, XhsApiError> {
-    unimplemented!("XHS parsing not yet implemented")
+///
+/// config 为 None 时使用默认超时、不走代理
+#[frb(dart_async)]
+pub async fn parse_xhs_from_url(
+    url: String,
+    config: Option<XhsClientConfig>,
+) -> Result<XhsArticle, XhsApiError> {
+    let parser = AsyncXhsParser::with_config(&config.unwrap_or_default())?;
+    Ok(parser.parse_by_url(&url).await?)
+}
+
+/// 从文本中的作者主页链接解析作者信息与首屏笔记
+///
+/// 链接中的 `xsec_token`、`xsec_source` 会原样带上。
+///
+/// 只返回主页 HTML 里的首屏笔记，不会自动翻页：`notes.has_more` 为 true 时，
+/// 调用方需带着 `notes.cursor` 自行请求（需要签名的）`user_posted` 接口，
+/// 再把响应交给 [`parse_xhs_note_page`] 解析。
+#[frb(dart_async)]
+pub async fn parse_xhs_profile(
+    text: String,
+    config: Option<XhsClientConfig>,
+) -> Result<XhsAuthorProfile, XhsApiError> {
+    let url = profile_link(&text).ok_or(XhsApiError::UrlNotFound)?;
+    let parser = AsyncXhsParser::with_config(&config.unwrap_or_default())?;
+    Ok(parser.parse_profile_by_url(&url).await?)
 }
 
-fn extract_xhs_url(text: &str) -> Result<String, XhsApiError> {
-    let regex = Regex::new(r"http[s]?://xhslink\.com/o/[a-zA-Z0-9]+")
-        .map_err(|e| XhsApiError::regex_error(e.to_string()))?;
+/// 解析作者笔记分页接口返回的 JSON
+#[frb(sync)]
+pub fn parse_xhs_note_page(json: String) -> Result<XhsNotePage, XhsApiError> {
+    Ok(parse_note_page_json(&json)?)
+}
 
-    regex
-        .find(text)
-        .map(|m| m.as_str().to_string())
-        .ok_or_else(XhsApiError::url_not_found)
+/// 识别并规范化文本中的小红书链接（短链、explore、discovery/item）
+#[frb(sync)]
+pub fn classify_xhs_link(text: String) -> Result<XhsLink, XhsApiError> {
+    classify_link(&text).ok_or(XhsApiError::UrlNotFound)
+}
+
+pub(crate) fn extract_xhs_url(text: &str) -> Result<String, XhsApiError> {
+    classify_link(text)
+        .map(|link| link.url)
+        .ok_or(XhsApiError::UrlNotFound)
 }
 
 #[cfg(test)]
@@ -87,8 +206,43 @@ mod tests {
         let result = extract_xhs_url(text);
         assert!(result.is_err());
         match result {
-            Err(err) => assert_eq!(err.error_type, "UrlNotFound"),
+            Err(err) => assert_eq!(err, XhsApiError::UrlNotFound),
             _ => panic!("应该返回 UrlNotFound 错误"),
         }
     }
+
+    #[test]
+    fn test_parser_errors_map_to_stable_codes() {
+        let cases = [
+            (ParserError::NoteDeleted, "NOTE_DELETED"),
+            (ParserError::LoginRequired, "LOGIN_REQUIRED"),
+            (ParserError::RateLimited, "RATE_LIMITED"),
+            (ParserError::CaptchaRequired, "CAPTCHA_REQUIRED"),
+            (ParserError::RegionBlocked, "REGION_BLOCKED"),
+            (ParserError::InitialStateMissing, "PARSE"),
+            (ParserError::ParseNote("bad".to_string()), "PARSE"),
+            (
+                ParserError::InvalidLink("http://xhslink.com/a".to_string()),
+                "INVALID_LINK",
+            ),
+        ];
+
+        for (err, expected) in cases {
+            assert_eq!(XhsApiError::from(err).code(), expected);
+        }
+        assert_eq!(XhsApiError::UrlNotFound.code(), "URL_NOT_FOUND");
+    }
+
+    #[test]
+    fn test_blocked_error_keeps_reason_and_message() {
+        let err = XhsApiError::from(ParserError::CaptchaRequired);
+
+        assert_eq!(
+            err,
+            XhsApiError::Blocked {
+                reason: XhsBlockReason::CaptchaRequired
+            }
+        );
+        assert_eq!(err.user_message(), "触发安全验证，需要完成验证码");
+    }
 }
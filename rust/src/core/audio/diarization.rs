@@ -0,0 +1,504 @@
+//! 说话人分离 - 为每个语音片段提取声纹并聚类，标注说话人编号
+
+use super::error::AudioError;
+use super::vad::{SpeechSegment, VadHandle};
+use crate::api::models::asr::DiarizationConfig;
+use log::{debug, info};
+use rustdct::rustfft::num_complex::Complex;
+use rustdct::rustfft::{Fft, FftPlanner};
+use std::sync::Arc;
+
+const SAMPLE_RATE: f32 = 16_000.0;
+/// 25ms 帧长、10ms 帧移
+const FRAME_LEN: usize = 400;
+const HOP_LEN: usize = 160;
+const FFT_LEN: usize = 512;
+const MEL_BANDS: usize = 40;
+/// 低于该 RMS 的帧视为静音，不计入声纹
+const SILENCE_RMS: f32 = 1e-4;
+/// 参与层次聚类的声纹数上限，层次聚类为 O(n³) 时间、O(n²) 内存，
+/// 超出时均匀抽样聚类，其余声纹归入最近的簇中心
+const MAX_CLUSTER_SAMPLES: usize = 256;
+
+/// 声纹提取，可替换为神经网络声纹模型
+pub trait SpeakerEmbedder: Send + Sync {
+    /// 输入 16kHz 单声道样本，返回固定维度的声纹向量，按余弦距离比较
+    fn embed(&self, samples: &[f32]) -> Vec<f32>;
+}
+
+/// 轻量频谱声纹：各 mel 频带对数能量的长时均值（去掉整体音量）与标准差
+///
+/// 不需要额外模型，能区分音色差异明显的说话人（如一男一女），相近音色需换用声纹模型
+pub struct SpectralEmbedder {
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    /// 每个 mel 频带的 (FFT bin, 权重)
+    filterbank: Vec<Vec<(usize, f32)>>,
+}
+
+impl SpectralEmbedder {
+    pub fn new() -> Self {
+        let window = (0..FRAME_LEN)
+            .map(|i| {
+                0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / (FRAME_LEN - 1) as f32).cos()
+            })
+            .collect();
+        Self {
+            fft: FftPlanner::new().plan_fft_forward(FFT_LEN),
+            window,
+            filterbank: mel_filterbank(),
+        }
+    }
+}
+
+impl Default for SpectralEmbedder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpeakerEmbedder for SpectralEmbedder {
+    fn embed(&self, samples: &[f32]) -> Vec<f32> {
+        let mut sum = vec![0.0_f64; MEL_BANDS];
+        let mut sum_sq = vec![0.0_f64; MEL_BANDS];
+        let mut frames = 0_usize;
+        let mut buffer = vec![Complex::new(0.0_f32, 0.0); FFT_LEN];
+
+        let mut start = 0;
+        while start + FRAME_LEN <= samples.len() {
+            let frame = &samples[start..start + FRAME_LEN];
+            start += HOP_LEN;
+            let rms = (frame.iter().map(|s| s * s).sum::<f32>() / FRAME_LEN as f32).sqrt();
+            if rms < SILENCE_RMS {
+                continue;
+            }
+
+            for (i, slot) in buffer.iter_mut().enumerate() {
+                let sample = if i < FRAME_LEN {
+                    frame[i] * self.window[i]
+                } else {
+                    0.0
+                };
+                *slot = Complex::new(sample, 0.0);
+            }
+            self.fft.process(&mut buffer);
+
+            for (band, weights) in self.filterbank.iter().enumerate() {
+                let energy: f32 = weights
+                    .iter()
+                    .map(|&(bin, w)| w * buffer[bin].norm_sqr())
+                    .sum();
+                let log_energy = (energy as f64 + 1e-10).ln();
+                sum[band] += log_energy;
+                sum_sq[band] += log_energy * log_energy;
+            }
+            frames += 1;
+        }
+
+        if frames == 0 {
+            return vec![0.0; MEL_BANDS * 2];
+        }
+        let n = frames as f64;
+        let mean: Vec<f64> = sum.iter().map(|s| s / n).collect();
+        let level = mean.iter().sum::<f64>() / MEL_BANDS as f64;
+        let std = sum_sq
+            .iter()
+            .zip(&mean)
+            .map(|(sq, m)| (sq / n - m * m).max(0.0).sqrt());
+
+        mean.iter()
+            .map(|m| m - level)
+            .chain(std)
+            .map(|v| v as f32)
+            .collect()
+    }
+}
+
+fn hz_to_mel(hz: f32) -> f32 {
+    2595.0 * (1.0 + hz / 700.0).log10()
+}
+
+fn mel_to_hz(mel: f32) -> f32 {
+    700.0 * (10_f32.powf(mel / 2595.0) - 1.0)
+}
+
+/// 20Hz ~ 7600Hz 的三角 mel 滤波器组
+fn mel_filterbank() -> Vec<Vec<(usize, f32)>> {
+    let (low, high) = (hz_to_mel(20.0), hz_to_mel(7_600.0));
+    let bin_of = |mel: f32| mel_to_hz(mel) * FFT_LEN as f32 / SAMPLE_RATE;
+    let points: Vec<f32> = (0..MEL_BANDS + 2)
+        .map(|i| bin_of(low + (high - low) * i as f32 / (MEL_BANDS + 1) as f32))
+        .collect();
+
+    (0..MEL_BANDS)
+        .map(|band| {
+            let (left, center, right) = (points[band], points[band + 1], points[band + 2]);
+            (left.floor() as usize..=right.ceil() as usize)
+                .filter(|&bin| bin <= FFT_LEN / 2)
+                .filter_map(|bin| {
+                    let x = bin as f32;
+                    let weight = if x <= center {
+                        (x - left) / (center - left)
+                    } else {
+                        (right - x) / (right - center)
+                    };
+                    (weight > 0.0).then_some((bin, weight))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// 说话人分离：长片段提取声纹后聚类，短片段沿用时间上最近的长片段的说话人
+pub struct Diarizer {
+    config: DiarizationConfig,
+    embedder: Box<dyn SpeakerEmbedder>,
+}
+
+impl Diarizer {
+    pub fn new(config: &DiarizationConfig) -> Result<Self, AudioError> {
+        if config.max_speakers == 0 {
+            return Err(AudioError::InvalidConfig(
+                "diarization max_speakers must be at least 1".to_string(),
+            ));
+        }
+        if !(config.threshold > 0.0 && config.threshold <= 2.0) {
+            return Err(AudioError::InvalidConfig(format!(
+                "diarization threshold must be in (0.0, 2.0], got {}",
+                config.threshold
+            )));
+        }
+        Ok(Self {
+            config: config.clone(),
+            embedder: Box::new(SpectralEmbedder::new()),
+        })
+    }
+
+    /// 替换声纹提取实现（例如接入声纹模型）
+    pub fn with_embedder(mut self, embedder: Box<dyn SpeakerEmbedder>) -> Self {
+        self.embedder = embedder;
+        self
+    }
+
+    /// 为按时间排序的片段标注说话人，编号按首次出现的顺序从 0 开始
+    pub fn label(&self, pcm_16k: &[f32], segments: &mut [SpeechSegment]) {
+        let embeddings: Vec<Vec<f32>> = segments
+            .iter()
+            .map(|segment| self.embed(&VadHandle::extract_segment(pcm_16k, 16_000, segment)))
+            .collect();
+        self.assign(segments, &embeddings);
+    }
+
+    /// 提取一个片段的声纹，流式转录时在片段音频还在内存中时调用
+    pub fn embed(&self, samples: &[f32]) -> Vec<f32> {
+        self.embedder.embed(samples)
+    }
+
+    /// 按各片段的声纹（与 `segments` 一一对应）聚类并标注说话人
+    pub fn assign(&self, segments: &mut [SpeechSegment], embeddings: &[Vec<f32>]) {
+        if segments.is_empty() {
+            return;
+        }
+
+        let min_len = self.config.min_segment_ms as f32 / 1000.0;
+        let mut anchors: Vec<usize> = (0..segments.len())
+            .filter(|&i| segments[i].end - segments[i].start >= min_len)
+            .collect();
+        if anchors.is_empty() {
+            anchors = (0..segments.len()).collect();
+        }
+
+        let anchor_embeddings: Vec<Vec<f32>> =
+            anchors.iter().map(|&i| embeddings[i].clone()).collect();
+        let labels = cluster(
+            &anchor_embeddings,
+            self.config.threshold,
+            self.config.max_speakers as usize,
+        );
+        for (&index, &label) in anchors.iter().zip(&labels) {
+            segments[index].speaker = Some(label as u32);
+        }
+
+        let centers: Vec<f32> = segments.iter().map(|s| (s.start + s.end) / 2.0).collect();
+        for i in 0..segments.len() {
+            if segments[i].speaker.is_some() {
+                continue;
+            }
+            let nearest = anchors
+                .iter()
+                .copied()
+                .min_by(|&a, &b| {
+                    (centers[a] - centers[i])
+                        .abs()
+                        .total_cmp(&(centers[b] - centers[i]).abs())
+                })
+                .and_then(|a| segments[a].speaker);
+            segments[i].speaker = nearest;
+        }
+
+        let speakers = labels.iter().max().map_or(0, |max| max + 1);
+        info!(
+            "🗣️ Diarization: {} speaker(s) across {} segments ({} embedded)",
+            speakers,
+            segments.len(),
+            anchors.len()
+        );
+    }
+}
+
+fn cosine_distance(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let denom = norm(a) * norm(b);
+    if denom <= f32::EPSILON {
+        return 1.0;
+    }
+    1.0 - dot / denom
+}
+
+/// 聚类声纹，返回每个向量的簇编号，按簇内第一个向量的顺序从 0 编号
+///
+/// 不超过 [`MAX_CLUSTER_SAMPLES`] 个时直接层次聚类；更多时（长视频的大量片段）
+/// 只对均匀抽取的样本做层次聚类，其余向量按余弦距离归入最近的簇中心
+pub fn cluster(embeddings: &[Vec<f32>], threshold: f32, max_clusters: usize) -> Vec<usize> {
+    let n = embeddings.len();
+    if n <= MAX_CLUSTER_SAMPLES {
+        return agglomerate(embeddings, threshold, max_clusters);
+    }
+
+    let sample: Vec<usize> = (0..MAX_CLUSTER_SAMPLES)
+        .map(|k| k * n / MAX_CLUSTER_SAMPLES)
+        .collect();
+    let sampled: Vec<Vec<f32>> = sample.iter().map(|&i| embeddings[i].clone()).collect();
+    let sample_labels = agglomerate(&sampled, threshold, max_clusters);
+    debug!(
+        "   clustered {} of {} embeddings, assigning the rest to centroids",
+        sample.len(),
+        n
+    );
+
+    let count = sample_labels.iter().max().map_or(0, |max| max + 1);
+    let dim = embeddings[0].len();
+    let mut centroids = vec![vec![0.0_f32; dim]; count];
+    for (&i, &label) in sample.iter().zip(&sample_labels) {
+        for (c, x) in centroids[label].iter_mut().zip(&embeddings[i]) {
+            *c += x;
+        }
+    }
+
+    let mut labels: Vec<usize> = embeddings
+        .iter()
+        .map(|embedding| {
+            (0..count)
+                .min_by(|&a, &b| {
+                    cosine_distance(embedding, &centroids[a])
+                        .total_cmp(&cosine_distance(embedding, &centroids[b]))
+                })
+                .unwrap_or(0)
+        })
+        .collect();
+    // 抽中的样本保留层次聚类的结果
+    for (&i, &label) in sample.iter().zip(&sample_labels) {
+        labels[i] = label;
+    }
+    renumber_by_first_occurrence(&mut labels);
+    labels
+}
+
+/// 重新编号，使编号按每个簇第一次出现的顺序从 0 递增
+fn renumber_by_first_occurrence(labels: &mut [usize]) {
+    let mut mapping: Vec<Option<usize>> = Vec::new();
+    let mut next = 0;
+    for label in labels.iter_mut() {
+        if mapping.len() <= *label {
+            mapping.resize(*label + 1, None);
+        }
+        *label = *mapping[*label].get_or_insert_with(|| {
+            next += 1;
+            next - 1
+        });
+    }
+}
+
+/// 平均链接层次聚类：不断合并距离最近的两个簇，直到最近距离超过阈值且簇数不超过上限
+fn agglomerate(embeddings: &[Vec<f32>], threshold: f32, max_clusters: usize) -> Vec<usize> {
+    let n = embeddings.len();
+    let mut members: Vec<Option<Vec<usize>>> = (0..n).map(|i| Some(vec![i])).collect();
+    let mut distance = vec![vec![0.0_f32; n]; n];
+    for i in 0..n {
+        for j in i + 1..n {
+            let d = cosine_distance(&embeddings[i], &embeddings[j]);
+            distance[i][j] = d;
+            distance[j][i] = d;
+        }
+    }
+
+    let mut alive = n;
+    while alive > 1 {
+        let mut best: Option<(usize, usize, f32)> = None;
+        for i in (0..n).filter(|&i| members[i].is_some()) {
+            for j in (i + 1..n).filter(|&j| members[j].is_some()) {
+                if best.is_none_or(|(_, _, d)| distance[i][j] < d) {
+                    best = Some((i, j, distance[i][j]));
+                }
+            }
+        }
+        let Some((a, b, d)) = best else { break };
+        if d > threshold && alive <= max_clusters {
+            break;
+        }
+        debug!("   merge clusters {} + {} (distance {:.3})", a, b, d);
+
+        // Lance-Williams 更新：新簇到其他簇的距离为两者按大小加权的平均
+        let merged = members[b].take().unwrap_or_default();
+        let (size_a, size_b) = (
+            members[a].as_ref().map_or(0, Vec::len) as f32,
+            merged.len() as f32,
+        );
+        for k in (0..n).filter(|&k| k != a && members[k].is_some()) {
+            let d = (size_a * distance[a][k] + size_b * distance[b][k]) / (size_a + size_b);
+            distance[a][k] = d;
+            distance[k][a] = d;
+        }
+        if let Some(cluster) = members[a].as_mut() {
+            cluster.extend(merged);
+        }
+        alive -= 1;
+    }
+
+    let mut clusters: Vec<Vec<usize>> = members.into_iter().flatten().collect();
+    clusters.sort_by_key(|cluster| cluster.iter().copied().min());
+    let mut labels = vec![0; n];
+    for (label, cluster) in clusters.iter().enumerate() {
+        for &i in cluster {
+            labels[i] = label;
+        }
+    }
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 合成“说话人”：给定基频的谐波，按频谱包络中心加权，并叠加少量噪声
+    fn voice(f0: f32, formant: f32, secs: f32, seed: u32) -> Vec<f32> {
+        let mut state = seed;
+        (0..(secs * SAMPLE_RATE) as usize)
+            .map(|i| {
+                let t = i as f32 / SAMPLE_RATE;
+                let harmonics: f32 = (1..=30)
+                    .map(|h| {
+                        let f = f0 * h as f32;
+                        let gain = (-((f - formant) / 600.0).powi(2)).exp();
+                        gain * (2.0 * std::f32::consts::PI * f * t).sin()
+                    })
+                    .sum();
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                let noise = (state >> 16) as f32 / 65_536.0 - 0.5;
+                0.1 * harmonics + 0.002 * noise
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cluster_groups_similar_vectors() {
+        let embeddings = vec![
+            vec![1.0, 0.0, 0.1],
+            vec![0.0, 1.0, 0.1],
+            vec![0.98, 0.05, 0.1],
+            vec![0.05, 0.97, 0.1],
+        ];
+
+        assert_eq!(cluster(&embeddings, 0.1, 4), vec![0, 1, 0, 1]);
+        // 阈值很大时全部合并
+        assert_eq!(cluster(&embeddings, 2.0, 4), vec![0, 0, 0, 0]);
+        // 阈值很小但受说话人数上限约束
+        assert_eq!(cluster(&embeddings, 0.0001, 2), vec![0, 1, 0, 1]);
+        assert!(cluster(&[], 0.1, 2).is_empty());
+    }
+
+    #[test]
+    fn test_cluster_large_input_assigns_rest_to_centroids() {
+        // 两个说话人各讲一段后交替，片段数远超层次聚类的上限
+        let n = MAX_CLUSTER_SAMPLES * 8;
+        let speaker = |i: usize| usize::from(i % 7 >= 4);
+        let embeddings: Vec<Vec<f32>> = (0..n)
+            .map(|i| {
+                let jitter = (i % 13) as f32 * 0.002;
+                match speaker(i) {
+                    0 => vec![1.0, jitter, 0.1],
+                    _ => vec![jitter, 1.0, 0.1],
+                }
+            })
+            .collect();
+
+        let labels = cluster(&embeddings, 0.1, 4);
+
+        assert_eq!(labels.len(), n);
+        assert!(labels.iter().enumerate().all(|(i, &l)| l == speaker(i)));
+    }
+
+    #[test]
+    fn test_renumber_by_first_occurrence() {
+        let mut labels = vec![3, 1, 3, 0, 1];
+        renumber_by_first_occurrence(&mut labels);
+        assert_eq!(labels, vec![0, 1, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_label_alternating_speakers() {
+        let host = |seed| voice(110.0, 500.0, 2.0, seed);
+        let guest = |seed| voice(240.0, 2_000.0, 2.0, seed);
+        let mut pcm = Vec::new();
+        for part in [host(1), guest(2), host(3), guest(4)] {
+            pcm.extend(part);
+        }
+        let mut segments: Vec<SpeechSegment> =
+            [(0.0, 2.0), (2.0, 4.0), (4.0, 6.0), (6.0, 7.6), (7.6, 8.0)]
+                .iter()
+                .map(|&(start, end)| SpeechSegment::new(start, end))
+                .collect();
+
+        let diarizer = Diarizer::new(&DiarizationConfig::default()).unwrap();
+        diarizer.label(&pcm, &mut segments);
+
+        let speakers: Vec<_> = segments.iter().map(|s| s.speaker).collect();
+        // 最后一段短于 min_segment_ms，沿用相邻片段的说话人
+        assert_eq!(speakers, vec![Some(0), Some(1), Some(0), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn test_single_speaker_stays_together() {
+        let pcm = voice(150.0, 800.0, 6.0, 7);
+        let mut segments: Vec<SpeechSegment> = [(0.0, 2.0), (2.0, 4.0), (4.0, 6.0)]
+            .iter()
+            .map(|&(start, end)| SpeechSegment::new(start, end))
+            .collect();
+
+        Diarizer::new(&DiarizationConfig::default())
+            .unwrap()
+            .label(&pcm, &mut segments);
+
+        assert!(segments.iter().all(|s| s.speaker == Some(0)));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        for config in [
+            DiarizationConfig {
+                max_speakers: 0,
+                ..Default::default()
+            },
+            DiarizationConfig {
+                threshold: 0.0,
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                Diarizer::new(&config),
+                Err(AudioError::InvalidConfig(_))
+            ));
+        }
+    }
+}
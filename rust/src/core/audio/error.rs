@@ -12,6 +12,12 @@ pub enum AudioError {
     NotInitialized,
     #[error("Model load failed: {0}")]
     ModelLoadFailed(String),
+    #[error("Invalid audio input: {0}")]
+    InvalidInput(String),
+    #[error("Invalid ASR config: {0}")]
+    InvalidConfig(String),
+    #[error("No speech detected")]
+    NoSpeech,
     #[error("Sherpa-NCNN error: {0}")]
     SherpaNcnn(String),
 }
@@ -0,0 +1,432 @@
+//! 声音事件检测 - 在 VAD 之外的非语音区间标注油爆、水开、提示音、料理机等烹饪声音
+//!
+//! 每 32ms 一帧提取频谱特征，每 16 帧（512ms）一块按规则打分；
+//! 音频结束后去掉与语音重叠的块，相邻同类块合并为事件
+
+use super::error::AudioError;
+use super::vad::SpeechSegment;
+use crate::api::models::asr::EventDetectionConfig;
+use crate::api::models::transcript::{AudioEvent, AudioEventKind};
+use log::{debug, info};
+use rustdct::rustfft::num_complex::Complex;
+use rustdct::rustfft::{Fft, FftPlanner};
+use std::sync::Arc;
+
+const FRAME_LEN: usize = 512;
+const BLOCK_FRAMES: usize = 16;
+const BLOCK_LEN: usize = FRAME_LEN * BLOCK_FRAMES;
+const BIN_HZ: f32 = 16_000.0 / FRAME_LEN as f32;
+/// 低于该电平的帧视为静音
+const SILENCE_DB: f32 = -55.0;
+/// 比块内最响的帧低这么多的帧不参与频谱统计（提示音的间隙等）
+const ACTIVE_RANGE_DB: f32 = 15.0;
+
+/// 单帧特征
+#[derive(Debug, Clone, Copy)]
+struct FrameFeatures {
+    energy_db: f32,
+    /// 300Hz 以下、300Hz ~ 2kHz、2kHz 以上的能量占比
+    low: f32,
+    mid: f32,
+    high: f32,
+    /// 频谱平坦度（几何平均 / 算术平均），噪声接近 1，纯音接近 0
+    flatness: f32,
+    /// 最强频点及相邻两个频点的能量占比
+    tonality: f32,
+    peak_hz: f32,
+}
+
+/// 一块（512ms）的分类结果
+#[derive(Debug, Clone, Copy)]
+struct BlockLabel {
+    start: f32,
+    end: f32,
+    kind: AudioEventKind,
+    confidence: f32,
+}
+
+/// 流式声音事件检测器，每段音频新建一个
+pub struct EventDetector {
+    config: EventDetectionConfig,
+    fft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    pending: Vec<f32>,
+    frames: Vec<FrameFeatures>,
+    labels: Vec<BlockLabel>,
+    /// 已完成的块数
+    blocks: usize,
+}
+
+impl EventDetector {
+    pub fn new(config: &EventDetectionConfig) -> Result<Self, AudioError> {
+        if !(0.0..=1.0).contains(&config.min_confidence) {
+            return Err(AudioError::InvalidConfig(format!(
+                "event min_confidence must be in [0.0, 1.0], got {}",
+                config.min_confidence
+            )));
+        }
+        let window = (0..FRAME_LEN)
+            .map(|n| 0.5 - 0.5 * (2.0 * std::f32::consts::PI * n as f32 / FRAME_LEN as f32).cos())
+            .collect();
+        Ok(Self {
+            config: config.clone(),
+            fft: FftPlanner::new().plan_fft_forward(FRAME_LEN),
+            window,
+            pending: Vec::with_capacity(FRAME_LEN),
+            frames: Vec::with_capacity(BLOCK_FRAMES),
+            labels: Vec::new(),
+            blocks: 0,
+        })
+    }
+
+    /// 送入一块 16kHz 原始音频（未经降噪，避免把油爆声当噪声滤掉）
+    pub fn accept(&mut self, samples: &[f32]) {
+        for chunk in samples.chunks(FRAME_LEN) {
+            let take = (FRAME_LEN - self.pending.len()).min(chunk.len());
+            self.pending.extend_from_slice(&chunk[..take]);
+            if self.pending.len() == FRAME_LEN {
+                self.end_frame();
+            }
+            self.pending.extend_from_slice(&chunk[take..]);
+        }
+    }
+
+    /// 音频结束：去掉与语音片段重叠过半的块，合并相邻同类块
+    ///
+    /// 不足一块的结尾被忽略
+    pub fn finish(self, speech: &[SpeechSegment]) -> Vec<AudioEvent> {
+        let non_speech: Vec<BlockLabel> = self
+            .labels
+            .into_iter()
+            .filter(|label| speech_overlap(label.start, label.end, speech) < 0.5)
+            .collect();
+
+        let block_secs = BLOCK_LEN as f32 / 16_000.0;
+        let mut merged: Vec<(BlockLabel, usize)> = Vec::new();
+        for label in non_speech {
+            match merged.last_mut() {
+                Some((last, count))
+                    if last.kind == label.kind && label.start - last.end < block_secs / 2.0 =>
+                {
+                    last.end = label.end;
+                    last.confidence += label.confidence;
+                    *count += 1;
+                }
+                _ => merged.push((label, 1)),
+            }
+        }
+
+        let min_len = self.config.min_event_ms as f32 / 1000.0;
+        let events: Vec<AudioEvent> = merged
+            .into_iter()
+            .filter(|(label, _)| label.end - label.start >= min_len)
+            .map(|(label, count)| AudioEvent {
+                kind: label.kind,
+                start_ms: (label.start * 1000.0).round() as u64,
+                end_ms: (label.end * 1000.0).round() as u64,
+                confidence: label.confidence / count as f32,
+            })
+            .collect();
+        info!(
+            "🍳 Audio events: {} event(s) in {} blocks",
+            events.len(),
+            self.blocks
+        );
+        events
+    }
+
+    fn end_frame(&mut self) {
+        let mut spectrum: Vec<Complex<f32>> = self
+            .pending
+            .iter()
+            .zip(&self.window)
+            .map(|(x, w)| Complex::new(x * w, 0.0))
+            .collect();
+        self.fft.process(&mut spectrum);
+        let powers: Vec<f32> = spectrum[..=FRAME_LEN / 2]
+            .iter()
+            .map(|c| c.norm_sqr())
+            .collect();
+        let energy = self.pending.iter().map(|x| x * x).sum::<f32>() / FRAME_LEN as f32;
+        self.frames.push(frame_features(energy, &powers));
+        self.pending.clear();
+
+        if self.frames.len() == BLOCK_FRAMES {
+            let start = (self.blocks * BLOCK_LEN) as f32 / 16_000.0;
+            let end = ((self.blocks + 1) * BLOCK_LEN) as f32 / 16_000.0;
+            if let Some((kind, confidence)) = classify(&self.frames) {
+                if confidence >= self.config.min_confidence {
+                    debug!(
+                        "   Block {:.2}s - {:.2}s: {:?} ({:.2})",
+                        start, end, kind, confidence
+                    );
+                    self.labels.push(BlockLabel {
+                        start,
+                        end,
+                        kind,
+                        confidence,
+                    });
+                }
+            }
+            self.frames.clear();
+            self.blocks += 1;
+        }
+    }
+}
+
+fn frame_features(energy: f32, powers: &[f32]) -> FrameFeatures {
+    // 跳过 100Hz 以下的频点，避免直流和电源嗡声干扰
+    let first = (100.0 / BIN_HZ) as usize;
+    let bins = &powers[first..];
+    let total: f32 = bins.iter().sum::<f32>().max(1e-12);
+    let band = |lo: f32, hi: f32| -> f32 {
+        bins.iter()
+            .enumerate()
+            .filter(|(i, _)| {
+                let hz = (first + i) as f32 * BIN_HZ;
+                hz >= lo && hz < hi
+            })
+            .map(|(_, p)| p)
+            .sum::<f32>()
+            / total
+    };
+
+    let log_mean = bins.iter().map(|p| (p + 1e-12).ln()).sum::<f32>() / bins.len() as f32;
+    let flatness = log_mean.exp() / (total / bins.len() as f32);
+
+    let (peak, _) =
+        bins.iter().enumerate().fold(
+            (0, 0.0_f32),
+            |best, (i, &p)| if p > best.1 { (i, p) } else { best },
+        );
+    let lobe: f32 = bins[peak.saturating_sub(1)..(peak + 2).min(bins.len())]
+        .iter()
+        .sum();
+
+    FrameFeatures {
+        energy_db: 10.0 * energy.max(1e-12).log10(),
+        low: band(0.0, 300.0),
+        mid: band(300.0, 2_000.0),
+        high: band(2_000.0, 8_001.0),
+        flatness: flatness.min(1.0),
+        tonality: lobe / total,
+        peak_hz: (first + peak) as f32 * BIN_HZ,
+    }
+}
+
+/// 按块统计打分，返回得分最高的事件；静音块返回 None
+fn classify(frames: &[FrameFeatures]) -> Option<(AudioEventKind, f32)> {
+    let max_db = frames.iter().map(|f| f.energy_db).fold(f32::MIN, f32::max);
+    if max_db < SILENCE_DB {
+        return None;
+    }
+    let gate = (max_db - ACTIVE_RANGE_DB).max(SILENCE_DB);
+    let active: Vec<&FrameFeatures> = frames.iter().filter(|f| f.energy_db >= gate).collect();
+    let n = active.len() as f32;
+    let mean = |f: fn(&FrameFeatures) -> f32| active.iter().map(|x| f(x)).sum::<f32>() / n;
+
+    let tonality = mean(|f| f.tonality);
+    let flatness = mean(|f| f.flatness);
+    let low_mid = mean(|f| f.low + f.mid);
+    let high = mean(|f| f.high);
+
+    // 电平起伏：静音帧按 SILENCE_DB 计，体现提示音、气泡的断续
+    let levels: Vec<f32> = frames.iter().map(|f| f.energy_db.max(SILENCE_DB)).collect();
+    let level_mean = levels.iter().sum::<f32>() / levels.len() as f32;
+    let level_std =
+        (levels.iter().map(|l| (l - level_mean).powi(2)).sum::<f32>() / levels.len() as f32).sqrt();
+
+    let mut peaks: Vec<f32> = active.iter().map(|f| f.peak_hz).collect();
+    peaks.sort_by(f32::total_cmp);
+    let peak_hz = peaks[peaks.len() / 2];
+    let stability = peaks
+        .iter()
+        .filter(|&&hz| (hz - peak_hz).abs() <= 2.0 * BIN_HZ)
+        .count() as f32
+        / n;
+
+    let steady = 1.0 - ramp(level_std, 3.0, 6.0);
+    let in_beep_range = (1_000.0..=5_000.0).contains(&peak_hz) as u8 as f32;
+    let scores = [
+        (
+            AudioEventKind::TimerBeep,
+            ramp(tonality, 0.4, 0.7) * stability * in_beep_range,
+        ),
+        (
+            AudioEventKind::Blender,
+            ramp(level_mean, -35.0, -25.0)
+                * steady
+                * ramp(low_mid, 0.5, 0.7)
+                * (1.0 - ramp(tonality, 0.4, 0.7)),
+        ),
+        (
+            AudioEventKind::Sizzle,
+            ramp(high, 0.4, 0.6) * ramp(flatness, 0.1, 0.25) * steady,
+        ),
+        (
+            AudioEventKind::Boiling,
+            ramp(low_mid, 0.5, 0.7) * ramp(level_std, 3.0, 6.0) * (1.0 - ramp(tonality, 0.4, 0.7)),
+        ),
+    ];
+    scores
+        .into_iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .filter(|(_, score)| *score > 0.0)
+}
+
+/// 线性映射到 [0, 1]：x ≤ lo 为 0，x ≥ hi 为 1
+fn ramp(x: f32, lo: f32, hi: f32) -> f32 {
+    ((x - lo) / (hi - lo)).clamp(0.0, 1.0)
+}
+
+/// [start, end) 与语音片段重叠部分的占比，片段按时间排序
+fn speech_overlap(start: f32, end: f32, speech: &[SpeechSegment]) -> f32 {
+    let overlap: f32 = speech
+        .iter()
+        .filter(|seg| seg.end > start && seg.start < end)
+        .map(|seg| seg.end.min(end) - seg.start.max(start))
+        .sum();
+    overlap / (end - start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    /// 确定性的均匀白噪声
+    fn noise(amplitude: f32, samples: usize, seed: u32) -> Vec<f32> {
+        let mut state = seed;
+        (0..samples)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state as f32 / u32::MAX as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    fn tone(freq: f64, amplitude: f64, i: usize) -> f32 {
+        let cycles = (freq * i as f64 / 16_000.0).fract();
+        (amplitude * (2.0 * PI * cycles).sin()) as f32
+    }
+
+    /// 一阶低通（alpha 越小截止频率越低）
+    fn low_pass(samples: &[f32], alpha: f32) -> Vec<f32> {
+        let mut y = 0.0;
+        samples
+            .iter()
+            .map(|&x| {
+                y += alpha * (x - y);
+                y
+            })
+            .collect()
+    }
+
+    fn detect(samples: &[f32]) -> Vec<AudioEvent> {
+        let mut detector = EventDetector::new(&EventDetectionConfig::default()).unwrap();
+        for chunk in samples.chunks(3_000) {
+            detector.accept(chunk);
+        }
+        detector.finish(&[])
+    }
+
+    /// 4 秒音频应被识别为一个覆盖大部分时长的事件
+    fn assert_single(events: &[AudioEvent], kind: AudioEventKind) {
+        assert_eq!(events.len(), 1, "{:?}", events);
+        assert_eq!(events[0].kind, kind);
+        assert!(
+            events[0].end_ms - events[0].start_ms >= 3_000,
+            "{:?}",
+            events
+        );
+    }
+
+    const LEN: usize = 64_000;
+
+    #[test]
+    fn test_detects_timer_beep() {
+        // 2.5kHz，响 100ms 停 100ms
+        let samples: Vec<f32> = (0..LEN)
+            .map(|i| {
+                let on = (i / 1_600) % 2 == 0;
+                if on {
+                    tone(2_500.0, 0.3, i)
+                } else {
+                    0.0
+                }
+            })
+            .zip(noise(0.001, LEN, 1))
+            .map(|(t, n)| t + n)
+            .collect();
+        assert_single(&detect(&samples), AudioEventKind::TimerBeep);
+    }
+
+    #[test]
+    fn test_detects_sizzle() {
+        // 白噪声一阶差分：能量集中在高频的平稳嘶嘶声
+        let white = noise(0.1, LEN + 1, 2);
+        let samples: Vec<f32> = white.windows(2).map(|w| w[1] - w[0]).collect();
+        assert_single(&detect(&samples), AudioEventKind::Sizzle);
+    }
+
+    #[test]
+    fn test_detects_boiling() {
+        // 低频噪声组成的短促气泡，间隔不等
+        let rumble = low_pass(&noise(0.5, LEN, 3), 0.1);
+        let mut state = 7_u32;
+        let mut next_bubble = 0;
+        let mut bubble_end = 0;
+        let samples: Vec<f32> = (0..LEN)
+            .map(|i| {
+                if i >= next_bubble {
+                    state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                    bubble_end = i + 480;
+                    next_bubble = i + 1_600 + (state >> 20) as usize % 3_200;
+                }
+                if i < bubble_end {
+                    rumble[i]
+                } else {
+                    rumble[i] * 0.01
+                }
+            })
+            .collect();
+        assert_single(&detect(&samples), AudioEventKind::Boiling);
+    }
+
+    #[test]
+    fn test_detects_blender() {
+        // 响亮平稳的电机噪声：低频噪声加 180Hz 嗡声
+        let rumble = low_pass(&noise(0.8, LEN, 4), 0.15);
+        let samples: Vec<f32> = rumble
+            .iter()
+            .enumerate()
+            .map(|(i, r)| r + tone(180.0, 0.05, i))
+            .collect();
+        assert_single(&detect(&samples), AudioEventKind::Blender);
+    }
+
+    #[test]
+    fn test_silence_has_no_events() {
+        assert!(detect(&noise(0.0005, LEN, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_speech_regions_are_excluded() {
+        let white = noise(0.1, LEN + 1, 2);
+        let samples: Vec<f32> = white.windows(2).map(|w| w[1] - w[0]).collect();
+        let mut detector = EventDetector::new(&EventDetectionConfig::default()).unwrap();
+        detector.accept(&samples);
+
+        // 1.0 ~ 2.2 秒是语音：与其重叠过半的块被去掉，事件被拆成前后两段
+        let events = detector.finish(&[SpeechSegment::new(1.0, 2.2)]);
+        let spans: Vec<(u64, u64)> = events.iter().map(|e| (e.start_ms, e.end_ms)).collect();
+        assert_eq!(spans, vec![(0, 1024), (2048, 3584)]);
+    }
+
+    #[test]
+    fn test_speech_overlap() {
+        let speech = [SpeechSegment::new(0.0, 1.0), SpeechSegment::new(1.5, 3.0)];
+        assert!((speech_overlap(0.5, 2.5, &speech) - 0.75).abs() < 1e-6);
+        assert_eq!(speech_overlap(1.0, 1.5, &speech), 0.0);
+    }
+}
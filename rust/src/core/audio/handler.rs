@@ -1,31 +1,83 @@
 //! Sherpa-NCNN ASR handler
 
 use super::error::AudioError;
-use log::{debug, error, info};
+use crate::api::models::asr::{AsrConfig, DecodingMethod};
+use crate::api::models::transcript::{TranscriptSegment, TranscriptToken};
+use log::{debug, error, info, warn};
 use sherpa_ncnn::{Recognizer, RecognizerConfig};
+use std::path::Path;
 
 /// NCNN Recognizer 实例（非全局，由 RecipeProcessor 持有）
 pub struct NcnnHandle {
     recognizer: Recognizer,
 }
 
+/// 线程数上限，超过后收益很小且明显增加功耗
+const MAX_THREADS: u32 = 8;
+/// 束搜索路径数上限
+const MAX_ACTIVE_PATHS: u32 = 32;
+
 impl NcnnHandle {
     /// Initialize the NCNN recognizer with model files
     ///
     /// # Arguments
-    /// * `model_dir` - Directory containing ncnn model files:
-    ///   - encoder_jit_trace-pnnx.ncnn.param/bin
-    ///   - decoder_jit_trace-pnnx.ncnn.param/bin
-    ///   - joiner_jit_trace-pnnx.ncnn.param/bin
-    ///   - tokens.txt
-    pub fn new(model_dir: &str) -> Result<Self, AudioError> {
+    /// * `model_dir` - Directory containing the ncnn model files named in `config.model_files`
+    ///   (encoder/decoder/joiner `.param` + `.bin` and tokens.txt)
+    /// * `config` - Decoding parameters, validated before the model is loaded
+    /// * `hotwords_file` - Optional hotwords file, forces modified beam search
+    pub fn new(
+        model_dir: &str,
+        config: &AsrConfig,
+        hotwords_file: Option<&str>,
+    ) -> Result<Self, AudioError> {
         info!("🔧 Loading Sherpa-NCNN model from: {}", model_dir);
+        validate_config(Path::new(model_dir), config)?;
 
-        let num_threads = num_cpus::get().min(4) as i32;
+        let num_threads = match config.num_threads {
+            0 => num_cpus::get().min(4) as i32,
+            n => n as i32,
+        };
         debug!("Using {} threads for NCNN", num_threads);
 
-        let config = RecognizerConfig::new(model_dir).with_num_threads(num_threads);
-        let recognizer = Recognizer::new(config).map_err(|e| {
+        let decoding_method = match (hotwords_file, config.decoding_method) {
+            (Some(_), DecodingMethod::GreedySearch) => {
+                warn!("⚠️ Hotwords require modified beam search, overriding greedy search");
+                DecodingMethod::ModifiedBeamSearch
+            }
+            (_, method) => method,
+        };
+        info!(
+            "🎛️ Decoding: {}, active paths: {}",
+            decoding_method.as_str(),
+            config.num_active_paths
+        );
+
+        let path = |name: &str| {
+            Path::new(model_dir)
+                .join(name)
+                .to_string_lossy()
+                .to_string()
+        };
+        let files = &config.model_files;
+        let endpoint = &config.endpoint;
+        let mut recognizer_config = RecognizerConfig::new(model_dir)
+            .with_encoder(&path(&files.encoder_param), &path(&files.encoder_bin))
+            .with_decoder(&path(&files.decoder_param), &path(&files.decoder_bin))
+            .with_joiner(&path(&files.joiner_param), &path(&files.joiner_bin))
+            .with_tokens(&path(&files.tokens))
+            .with_num_threads(num_threads)
+            .with_decoding_method(decoding_method.as_str())
+            .with_num_active_paths(config.num_active_paths as i32)
+            .with_enable_endpoint(endpoint.enable)
+            .with_rule1_min_trailing_silence(endpoint.rule1_min_trailing_silence)
+            .with_rule2_min_trailing_silence(endpoint.rule2_min_trailing_silence)
+            .with_rule3_min_utterance_length(endpoint.rule3_min_utterance_length);
+        if let Some(file) = hotwords_file {
+            info!("🔥 Contextual biasing enabled, hotwords: {}", file);
+            recognizer_config = recognizer_config.with_hotwords_file(file);
+        }
+
+        let recognizer = Recognizer::new(recognizer_config).map_err(|e| {
             error!("❌ Failed to create NCNN recognizer: {}", e);
             AudioError::SherpaNcnn(format!("Failed to create recognizer: {}", e))
         })?;
@@ -39,13 +91,29 @@ impl NcnnHandle {
     /// # Arguments
     /// * `samples` - Audio samples as f32 array (normalized to [-1.0, 1.0])
     /// * `sample_rate` - Sample rate in Hz (must be 16000)
-    /// * `_language` - Language hint (unused, kept for API compatibility)
+    /// * `language` - Language hint (unused, `AudioRecognizer` routes languages to model packs)
     pub fn transcribe(
         &self,
         samples: &[f32],
         sample_rate: u32,
-        _language: Option<&str>,
+        language: Option<&str>,
     ) -> Result<String, AudioError> {
+        self.transcribe_segment(samples, sample_rate, language, 0.0)
+            .map(|segment| segment.text)
+    }
+
+    /// Transcribe one speech segment with per-token timestamps and confidences
+    ///
+    /// # Arguments
+    /// * `offset_secs` - Start of the segment within the whole audio; token timestamps
+    ///   returned by the recognizer are relative to the segment and get shifted by it
+    pub fn transcribe_segment(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        _language: Option<&str>,
+        offset_secs: f32,
+    ) -> Result<TranscriptSegment, AudioError> {
         debug!(
             "🎤 Transcribing {} samples at {}Hz",
             samples.len(),
@@ -63,23 +131,256 @@ impl NcnnHandle {
             )));
         }
 
-        let result = self.recognizer.transcribe(samples, sample_rate as f32).map_err(|e| {
-            error!("❌ Transcription failed: {}", e);
-            AudioError::SherpaNcnn(e.to_string())
-        })?;
+        let result = self
+            .recognizer
+            .transcribe_detailed(samples, sample_rate as f32)
+            .map_err(|e| {
+                error!("❌ Transcription failed: {}", e);
+                AudioError::SherpaNcnn(e.to_string())
+            })?;
 
         info!(
-            "✅ Transcription complete! Length: {} chars",
-            result.len()
+            "✅ Transcription complete! Length: {} chars, {} tokens",
+            result.text.len(),
+            result.tokens.len()
         );
-        debug!("Transcribed text: {}", result);
+        debug!("Transcribed text: {}", result.text);
 
-        Ok(result)
+        let duration_secs = samples.len() as f32 / sample_rate as f32;
+        Ok(TranscriptSegment {
+            start_ms: secs_to_ms(offset_secs),
+            end_ms: secs_to_ms(offset_secs + duration_secs),
+            text: result.text.trim().to_string(),
+            tokens: build_tokens(
+                &result.tokens,
+                &result.timestamps,
+                &result.scores,
+                offset_secs,
+            ),
+            speaker: None,
+        })
     }
 }
 
+/// 合并识别结果中的 token、时间戳与对数概率，时间戳加上片段偏移
+///
+/// 三个数组按下标对应；缺少时间戳的 token 沿用前一个 token 的时间。
+/// `log_probs` 来自识别结果的 `scores`，部分解码方式不给出或长度不足，
+/// 缺少分数的 token 置信度为 None，而不是伪造一个 0
+fn build_tokens(
+    tokens: &[String],
+    timestamps: &[f32],
+    log_probs: &[f32],
+    offset_secs: f32,
+) -> Vec<TranscriptToken> {
+    let mut last_start = 0.0_f32;
+    tokens
+        .iter()
+        .enumerate()
+        .map(|(i, token)| {
+            let start = timestamps.get(i).copied().unwrap_or(last_start);
+            last_start = start;
+            TranscriptToken {
+                token: token.clone(),
+                start_ms: secs_to_ms(offset_secs + start),
+                confidence: log_probs.get(i).map(|p| p.exp().clamp(0.0, 1.0)),
+            }
+        })
+        .collect()
+}
+
+/// 创建识别器前检查配置，避免把非法参数交给 C 层
+fn validate_config(model_dir: &Path, config: &AsrConfig) -> Result<(), AudioError> {
+    if config.num_threads > MAX_THREADS {
+        return Err(AudioError::InvalidConfig(format!(
+            "num_threads must be 0 (auto) or 1..={}, got {}",
+            MAX_THREADS, config.num_threads
+        )));
+    }
+    if config.decoding_method == DecodingMethod::ModifiedBeamSearch
+        && !(1..=MAX_ACTIVE_PATHS).contains(&config.num_active_paths)
+    {
+        return Err(AudioError::InvalidConfig(format!(
+            "num_active_paths must be in 1..={}, got {}",
+            MAX_ACTIVE_PATHS, config.num_active_paths
+        )));
+    }
+
+    let endpoint = &config.endpoint;
+    let rules = [
+        (
+            "rule1_min_trailing_silence",
+            endpoint.rule1_min_trailing_silence,
+        ),
+        (
+            "rule2_min_trailing_silence",
+            endpoint.rule2_min_trailing_silence,
+        ),
+        (
+            "rule3_min_utterance_length",
+            endpoint.rule3_min_utterance_length,
+        ),
+    ];
+    if let Some((name, value)) = rules.iter().find(|(_, v)| !v.is_finite() || *v <= 0.0) {
+        return Err(AudioError::InvalidConfig(format!(
+            "endpoint {} must be positive, got {}",
+            name, value
+        )));
+    }
+
+    if let Some(missing) = config
+        .model_files
+        .all()
+        .into_iter()
+        .find(|name| name.trim().is_empty() || !model_dir.join(name).is_file())
+    {
+        return Err(AudioError::ModelLoadFailed(format!(
+            "model file {:?} not found in {:?}",
+            missing, model_dir
+        )));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn secs_to_ms(seconds: f32) -> u64 {
+    (seconds.max(0.0) * 1000.0).round() as u64
+}
+
 impl Drop for NcnnHandle {
     fn drop(&mut self) {
         info!("🗑️ NcnnHandle: releasing NCNN recognizer");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::asr::{AsrModelFiles, EndpointConfig};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+    fn model_dir_with_files(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "cook_lib_asr_{}_{}_{}",
+            name,
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for file in AsrModelFiles::default().all() {
+            std::fs::write(dir.join(file), b"").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let dir = model_dir_with_files("validate");
+        assert!(validate_config(&dir, &AsrConfig::default()).is_ok());
+
+        let invalid = [
+            AsrConfig {
+                num_threads: 64,
+                ..Default::default()
+            },
+            AsrConfig {
+                decoding_method: DecodingMethod::ModifiedBeamSearch,
+                num_active_paths: 0,
+                ..Default::default()
+            },
+            AsrConfig {
+                endpoint: EndpointConfig {
+                    rule2_min_trailing_silence: -1.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        ];
+        for config in invalid {
+            assert!(matches!(
+                validate_config(&dir, &config),
+                Err(AudioError::InvalidConfig(_))
+            ));
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_validate_config_reports_missing_model_file() {
+        let dir = model_dir_with_files("missing");
+        let config = AsrConfig {
+            model_files: AsrModelFiles {
+                tokens: "tokens-v2.txt".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        match validate_config(&dir, &config) {
+            Err(AudioError::ModelLoadFailed(msg)) => assert!(msg.contains("tokens-v2.txt")),
+            other => panic!("应该提示缺少模型文件: {:?}", other.err()),
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_build_tokens_applies_segment_offset() {
+        let tokens = vec!["番".to_string(), "茄".to_string(), "炒".to_string()];
+        let timestamps = [0.0, 0.24, 0.52];
+        let log_probs = [0.0, 0.9_f32.ln(), 0.1_f32.ln()];
+
+        let result = build_tokens(&tokens, &timestamps, &log_probs, 12.5);
+
+        assert_eq!(
+            result.iter().map(|t| t.start_ms).collect::<Vec<_>>(),
+            vec![12_500, 12_740, 13_020]
+        );
+        let confidence = |i: usize| result[i].confidence.expect("应该有置信度");
+        assert!((confidence(0) - 1.0).abs() < 1e-4);
+        assert!((confidence(1) - 0.9).abs() < 1e-4);
+        assert!((confidence(2) - 0.1).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_build_tokens_tolerates_missing_timestamps() {
+        let tokens = vec!["a".to_string(), "b".to_string()];
+
+        let result = build_tokens(&tokens, &[0.3], &[], 1.0);
+
+        assert_eq!(result[0].start_ms, 1_300);
+        assert_eq!(result[1].start_ms, 1_300);
+        assert_eq!(result[1].confidence, None);
+    }
+
+    #[test]
+    fn test_build_tokens_with_fewer_log_probs_than_tokens() {
+        let tokens = vec!["番".to_string(), "茄".to_string(), "炒".to_string()];
+        let timestamps = [0.0, 0.24, 0.52];
+
+        let result = build_tokens(&tokens, &timestamps, &[0.5_f32.ln()], 0.0);
+
+        assert_eq!(result.len(), 3);
+        assert!((result[0].confidence.expect("应该有置信度") - 0.5).abs() < 1e-4);
+        assert_eq!(result[1].confidence, None);
+        assert_eq!(result[2].confidence, None);
+    }
+
+    #[test]
+    fn test_build_tokens_without_log_probs() {
+        let tokens = vec!["番".to_string(), "茄".to_string()];
+        let timestamps = [0.0, 0.24];
+
+        let result = build_tokens(&tokens, &timestamps, &[], 2.0);
+
+        assert_eq!(
+            result.iter().map(|t| t.start_ms).collect::<Vec<_>>(),
+            vec![2_000, 2_240]
+        );
+        assert!(result.iter().all(|t| t.confidence.is_none()));
+    }
+}
@@ -0,0 +1,334 @@
+//! 热词文件生成 - 供 sherpa-ncnn 的 contextual biasing 使用
+
+use super::error::AudioError;
+use crate::api::models::asr::HotwordConfig;
+use log::{info, warn};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// 内置烹饪词表：调料、食材与常见烹饪手法
+#[rustfmt::skip]
+pub const COOKING_LEXICON: &[&str] = &[
+    // 调料
+    "花椒", "麻椒", "八角", "桂皮", "香叶", "草果", "丁香", "陈皮", "孜然", "五香粉",
+    "十三香", "白胡椒粉", "生抽", "老抽", "蚝油", "料酒", "陈醋", "香醋", "豆瓣酱",
+    "郫县豆瓣", "冰糖", "淀粉", "水淀粉", "生粉", "小苏打", "酵母", "小米辣", "干辣椒",
+    "香油", "芝麻油", "猪油", "鸡精", "味精",
+    // 食材
+    "蹄花", "五花肉", "排骨", "里脊", "鸡胸肉", "白芸豆", "西红柿", "番茄", "土豆",
+    "茄子", "香菜", "小葱", "姜片", "蒜末", "葱姜蒜",
+    // 烹饪手法
+    "焯水", "飞水", "勾芡", "腌制", "煸炒", "爆香", "翻炒", "收汁", "大火收汁",
+    "小火慢炖", "焖煮", "红烧", "清蒸", "凉拌", "复炸", "过油", "滑油", "挂糊", "上浆",
+    "炝锅", "炒糖色", "改刀", "切丁", "切丝",
+];
+
+/// 词表中单词开头的标记（SentencePiece 的 `▁`）
+const WORD_BOUNDARY: char = '\u{2581}';
+
+static NEXT_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// 模型包 `tokens.txt` 中的建模单元
+pub struct TokenVocab {
+    tokens: HashSet<String>,
+    /// 最长建模单元的字符数，限制切分时的查找范围
+    max_chars: usize,
+}
+
+impl TokenVocab {
+    pub fn load(path: &Path) -> Result<Self, AudioError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AudioError::ModelLoadFailed(format!("Failed to read tokens {:?}: {}", path, e))
+        })?;
+        Ok(Self::parse(&content))
+    }
+
+    /// 每行 `<建模单元> <id>`
+    fn parse(content: &str) -> Self {
+        let tokens: HashSet<String> = content
+            .lines()
+            .filter_map(|line| line.split_whitespace().next())
+            .map(str::to_string)
+            .collect();
+        let max_chars = tokens.iter().map(|t| t.chars().count()).max().unwrap_or(0);
+        Self { tokens, max_chars }
+    }
+
+    /// 按词表切分热词：中文逐字，英文单词按 BPE 子词切分；含词表外字符时返回 None
+    fn encode(&self, phrase: &str) -> Option<Vec<String>> {
+        let mut units = Vec::new();
+        let mut word = String::new();
+
+        for ch in phrase.chars() {
+            if ch.is_ascii_alphanumeric() || ch == '\'' {
+                word.push(ch);
+                continue;
+            }
+            if !word.is_empty() {
+                units.extend(self.encode_word(&std::mem::take(&mut word))?);
+            }
+            if !ch.is_whitespace() {
+                let unit = ch.to_string();
+                if !self.tokens.contains(&unit) {
+                    return None;
+                }
+                units.push(unit);
+            }
+        }
+        if !word.is_empty() {
+            units.extend(self.encode_word(&word)?);
+        }
+
+        Some(units)
+    }
+
+    /// 英文单词切成最少的子词；词表大小写与输入不一致时依次尝试原样、大写、小写
+    fn encode_word(&self, word: &str) -> Option<Vec<String>> {
+        [word.to_string(), word.to_uppercase(), word.to_lowercase()]
+            .iter()
+            .find_map(|variant| {
+                let chars: Vec<char> = std::iter::once(WORD_BOUNDARY)
+                    .chain(variant.chars())
+                    .collect();
+                self.segment(&chars)
+            })
+    }
+
+    /// 动态规划求覆盖整个单词的最少子词序列
+    fn segment(&self, chars: &[char]) -> Option<Vec<String>> {
+        let n = chars.len();
+        // best[i]: 覆盖 chars[i..] 的最少子词数与第一个子词的长度
+        let mut best: Vec<Option<(usize, usize)>> = vec![None; n + 1];
+        best[n] = Some((0, 0));
+        for i in (0..n).rev() {
+            for len in 1..=self.max_chars.min(n - i) {
+                let Some((rest, _)) = best[i + len] else {
+                    continue;
+                };
+                let piece: String = chars[i..i + len].iter().collect();
+                if self.tokens.contains(&piece) && best[i].is_none_or(|(count, _)| rest + 1 < count)
+                {
+                    best[i] = Some((rest + 1, len));
+                }
+            }
+        }
+
+        let mut pieces = Vec::new();
+        let mut i = 0;
+        while i < n {
+            let (_, len) = best[i]?;
+            pieces.push(chars[i..i + len].iter().collect());
+            i += len;
+        }
+        Some(pieces)
+    }
+}
+
+/// 识别器实例独占的热词文件，drop 时删除
+///
+/// 文件写在调用方指定的可写目录（默认系统临时目录），不写进模型目录：
+/// iOS 上 bundle 内的模型目录只读，多个识别器共用一个文件也会互相覆盖。
+pub struct HotwordsFile {
+    path: PathBuf,
+}
+
+impl HotwordsFile {
+    /// 按模型包词表生成热词文件，没有可用热词时返回 None
+    pub fn create(
+        dir: &Path,
+        config: &HotwordConfig,
+        vocab: &TokenVocab,
+    ) -> Result<Option<Self>, AudioError> {
+        let lines = render_hotwords(config, vocab);
+        if lines.is_empty() {
+            return Ok(None);
+        }
+
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!(
+            "cook_lib_hotwords_{}_{}.txt",
+            std::process::id(),
+            NEXT_FILE_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::write(&path, lines.join("\n"))?;
+        info!("🔥 Hotwords written: {} entries -> {:?}", lines.len(), path);
+        Ok(Some(Self { path }))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for HotwordsFile {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            warn!("⚠️ Failed to remove hotwords file {:?}: {}", self.path, e);
+        }
+    }
+}
+
+/// 合并内置词表与自定义热词，每行 `<建模单元序列> :<分数>`，词表外的热词跳过
+fn render_hotwords(config: &HotwordConfig, vocab: &TokenVocab) -> Vec<String> {
+    let lexicon: &[&str] = if config.use_cooking_lexicon {
+        COOKING_LEXICON
+    } else {
+        &[]
+    };
+    let lexicon = lexicon
+        .iter()
+        .map(|phrase| (phrase.to_string(), config.default_score));
+    let custom = config.hotwords.iter().map(|h| {
+        (
+            h.phrase.trim().to_string(),
+            h.score.unwrap_or(config.default_score),
+        )
+    });
+
+    // 后出现的自定义热词覆盖词表中的同名条目
+    let mut entries: Vec<(String, f32)> = Vec::new();
+    for (phrase, score) in lexicon.chain(custom) {
+        if phrase.is_empty() {
+            continue;
+        }
+        match entries.iter_mut().find(|(p, _)| *p == phrase) {
+            Some(entry) => entry.1 = score,
+            None => entries.push((phrase, score)),
+        }
+    }
+
+    entries
+        .into_iter()
+        .filter_map(|(phrase, score)| match vocab.encode(&phrase) {
+            Some(units) => Some(format!("{} :{}", units.join(" "), score)),
+            None => {
+                warn!(
+                    "⚠️ Hotword {:?} is not covered by the model tokens, skipped",
+                    phrase
+                );
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::asr::Hotword;
+
+    /// 中英双语模型词表片段：中文单字与大写的 BPE 子词
+    fn vocab() -> TokenVocab {
+        let chars = COOKING_LEXICON.concat() + "鱼香肉丝烤鸡翅";
+        let mut content: String = [
+            "<blk> 0", "▁AIR 1", "▁F 2", "RY 3", "ER 4", "▁FRY 5", "▁OK 6",
+        ]
+        .join("\n");
+        for (i, ch) in chars
+            .chars()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .enumerate()
+        {
+            content.push_str(&format!("\n{} {}", ch, i + 10));
+        }
+        TokenVocab::parse(&content)
+    }
+
+    fn encode(phrase: &str) -> Option<String> {
+        vocab().encode(phrase).map(|units| units.join(" "))
+    }
+
+    #[test]
+    fn test_encode_hotword() {
+        assert_eq!(encode("花椒").as_deref(), Some("花 椒"));
+        assert_eq!(encode("OK").as_deref(), Some("▁OK"));
+    }
+
+    #[test]
+    fn test_encode_splits_english_into_bpe_pieces() {
+        // fryer 不在词表中，需要切成多个子词，且优先用更少的子词（▁FRY ER 而非 ▁F RY ER）
+        assert_eq!(
+            encode("air fryer 烤鸡翅").as_deref(),
+            Some("▁AIR ▁FRY ER 烤 鸡 翅")
+        );
+    }
+
+    #[test]
+    fn test_out_of_vocab_phrases_are_rejected() {
+        assert_eq!(encode("wok"), None);
+        assert_eq!(encode("锅气"), None);
+
+        let config = HotwordConfig {
+            hotwords: vec![Hotword {
+                phrase: "wok hei".to_string(),
+                score: None,
+            }],
+            use_cooking_lexicon: false,
+            ..Default::default()
+        };
+        assert!(render_hotwords(&config, &vocab()).is_empty());
+    }
+
+    #[test]
+    fn test_custom_hotwords_override_lexicon() {
+        let config = HotwordConfig {
+            hotwords: vec![
+                Hotword {
+                    phrase: "焯水".to_string(),
+                    score: Some(3.0),
+                },
+                Hotword {
+                    phrase: " 鱼香肉丝 ".to_string(),
+                    score: None,
+                },
+                Hotword {
+                    phrase: "  ".to_string(),
+                    score: None,
+                },
+            ],
+            use_cooking_lexicon: true,
+            default_score: 1.5,
+            ..Default::default()
+        };
+
+        let lines = render_hotwords(&config, &vocab());
+
+        assert_eq!(lines.len(), COOKING_LEXICON.len() + 1);
+        assert!(lines.contains(&"焯 水 :3".to_string()));
+        assert!(lines.contains(&"花 椒 :1.5".to_string()));
+        assert_eq!(lines.last().unwrap(), "鱼 香 肉 丝 :1.5");
+    }
+
+    #[test]
+    fn test_lexicon_can_be_disabled() {
+        let config = HotwordConfig {
+            use_cooking_lexicon: false,
+            ..Default::default()
+        };
+
+        assert!(render_hotwords(&config, &vocab()).is_empty());
+    }
+
+    #[test]
+    fn test_hotwords_file_is_per_instance_and_removed_on_drop() {
+        let dir = std::env::temp_dir();
+        let config = HotwordConfig::default();
+        let vocab = vocab();
+
+        let first = HotwordsFile::create(&dir, &config, &vocab)
+            .unwrap()
+            .expect("应该生成热词文件");
+        let second = HotwordsFile::create(&dir, &config, &vocab)
+            .unwrap()
+            .expect("应该生成热词文件");
+        assert_ne!(first.path(), second.path());
+
+        let path = first.path().to_path_buf();
+        assert!(path.exists());
+        drop(first);
+        assert!(!path.exists());
+        assert!(second.path().exists());
+    }
+}
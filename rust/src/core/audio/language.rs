@@ -0,0 +1,272 @@
+//! 语言路由 - 按语言提示选择模型包，没有提示时在开头几个片段上做语言识别
+
+use super::error::AudioError;
+use super::hotwords::HotwordsFile;
+use super::pool::RecognizerPool;
+use super::vad::SpeechSegment;
+use crate::api::models::asr::ModelPackConfig;
+use crate::api::models::transcript::TranscriptSegment;
+use std::collections::HashSet;
+use std::path::{Component, Path};
+
+/// 语言识别时最多试听的语音时长（秒）
+pub const PROBE_SECS: f32 = 20.0;
+/// 流式转录时等待试听片段的最大音频跨度（秒），语音稀疏时不为凑够试听时长缓存过多音频
+pub const PROBE_SPAN_SECS: f32 = 120.0;
+/// 默认包的试听得分达到该值时直接采用，不再让其余模型包试听
+pub const ACCEPT_SCORE: f32 = 0.75;
+/// 正常语速下每秒语音输出的 token 数，用于把发射率归一化到 0 ~ 1
+const EXPECTED_TOKENS_PER_SEC: f32 = 4.0;
+
+/// 已加载的模型包
+pub struct ModelPack {
+    pub dir: String,
+    /// 规范化后的语言代码
+    pub languages: Vec<String>,
+    pub pool: RecognizerPool,
+    /// 识别器加载的热词文件，与模型包同生命周期
+    pub hotwords: Option<HotwordsFile>,
+}
+
+/// 规范化语言提示，`auto` 或空字符串返回 None
+///
+/// 川渝等方言口音的普通话归为 `zh`，粤语单独为 `yue`
+pub fn normalize_language(hint: &str) -> Option<String> {
+    let hint = hint.trim().to_lowercase().replace('_', "-");
+    let language = match hint.as_str() {
+        "" | "auto" => return None,
+        "zh" | "cmn" | "mandarin" | "chinese" | "sichuanese" | "zh-cn" | "zh-hans" | "zh-sg"
+        | "zh-tw" | "zh-hant" => "zh",
+        "yue" | "cantonese" | "zh-hk" | "zh-mo" | "zh-yue" => "yue",
+        "english" => "en",
+        other => other.split('-').next().unwrap_or(other),
+    };
+    Some(language.to_string())
+}
+
+/// 返回第一个支持该语言的模型包下标
+pub fn route_language(pack_languages: &[&[String]], language: &str) -> Option<usize> {
+    pack_languages
+        .iter()
+        .position(|languages| languages.iter().any(|l| l == language))
+}
+
+/// 从开头取若干片段用于语言识别，总时长不超过 `max_secs`，但至少一个
+pub fn probe_count(segments: &[SpeechSegment], max_secs: f32) -> usize {
+    let mut total = 0.0;
+    let count = segments
+        .iter()
+        .take_while(|segment| {
+            total += segment.end - segment.start;
+            total <= max_secs
+        })
+        .count();
+    count.max(segments.len().min(1))
+}
+
+/// 所有带分数 token 的平均置信度，没有分数时返回 None
+pub fn mean_confidence(segments: &[TranscriptSegment]) -> Option<f32> {
+    let (sum, count) = segments
+        .iter()
+        .flat_map(|segment| &segment.tokens)
+        .filter_map(|token| token.confidence)
+        .fold((0.0_f32, 0_usize), |(sum, count), confidence| {
+            (sum + confidence, count + 1)
+        });
+    (count > 0).then(|| sum / count as f32)
+}
+
+/// 模型包在试听片段上的得分（0.0 ~ 1.0），没有语音时返回 None
+///
+/// 识别器给出 token 分数时取平均置信度。sherpa-ncnn 的 C 接口没有分数，此时用发射率：
+/// transducer 听到不认识的语言时大多数帧输出 blank，每秒语音产出的 token 明显偏少
+pub fn probe_score(segments: &[TranscriptSegment]) -> Option<f32> {
+    if let Some(confidence) = mean_confidence(segments) {
+        return Some(confidence);
+    }
+
+    let speech_ms: u64 = segments
+        .iter()
+        .map(|segment| segment.end_ms.saturating_sub(segment.start_ms))
+        .sum();
+    if speech_ms == 0 {
+        return None;
+    }
+    let tokens: usize = segments.iter().map(|segment| segment.tokens.len()).sum();
+    let tokens_per_sec = tokens as f32 * 1000.0 / speech_ms as f32;
+    Some((tokens_per_sec / EXPECTED_TOKENS_PER_SEC).min(1.0))
+}
+
+/// 选出得分最高的模型包，得分相同时取靠前的，都没有得分时取默认包
+pub fn pick_best(scores: &[Option<f32>]) -> usize {
+    scores
+        .iter()
+        .enumerate()
+        .filter_map(|(index, score)| score.map(|s| (index, s)))
+        .fold(
+            None,
+            |best: Option<(usize, f32)>, (index, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((index, score)),
+            },
+        )
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
+
+/// 检查模型包配置：至少一个，目录名非空、位于 models_dir 内且不重复
+pub fn validate_packs(packs: &[ModelPackConfig]) -> Result<(), AudioError> {
+    if packs.is_empty() {
+        return Err(AudioError::InvalidConfig(
+            "at least one model pack is required".to_string(),
+        ));
+    }
+    let mut seen = HashSet::new();
+    for pack in packs {
+        let path = Path::new(&pack.dir);
+        let inside = !pack.dir.trim().is_empty()
+            && path.components().all(|c| matches!(c, Component::Normal(_)));
+        if !inside {
+            return Err(AudioError::InvalidConfig(format!(
+                "invalid model pack dir {:?}",
+                pack.dir
+            )));
+        }
+        if !seen.insert(pack.dir.as_str()) {
+            return Err(AudioError::InvalidConfig(format!(
+                "duplicate model pack dir {:?}",
+                pack.dir
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::transcript::TranscriptToken;
+
+    fn segment(start: f32, end: f32) -> SpeechSegment {
+        SpeechSegment::new(start, end)
+    }
+
+    fn result(confidences: &[f32]) -> TranscriptSegment {
+        TranscriptSegment {
+            start_ms: 0,
+            end_ms: 0,
+            text: String::new(),
+            tokens: confidences
+                .iter()
+                .map(|&confidence| TranscriptToken {
+                    token: "字".to_string(),
+                    start_ms: 0,
+                    confidence: Some(confidence),
+                })
+                .collect(),
+            speaker: None,
+        }
+    }
+
+    /// 没有 token 分数的识别结果，与 sherpa-ncnn 的 C 接口一致
+    fn unscored(text: &str, duration_ms: u64) -> TranscriptSegment {
+        TranscriptSegment {
+            start_ms: 1_000,
+            end_ms: 1_000 + duration_ms,
+            text: text.to_string(),
+            tokens: text
+                .chars()
+                .map(|ch| TranscriptToken {
+                    token: ch.to_string(),
+                    start_ms: 1_000,
+                    confidence: None,
+                })
+                .collect(),
+            speaker: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_language() {
+        assert_eq!(normalize_language("zh-CN").as_deref(), Some("zh"));
+        assert_eq!(normalize_language("zh_HK").as_deref(), Some("yue"));
+        assert_eq!(normalize_language("Cantonese").as_deref(), Some("yue"));
+        assert_eq!(normalize_language("sichuanese").as_deref(), Some("zh"));
+        assert_eq!(normalize_language("en-US").as_deref(), Some("en"));
+        assert_eq!(normalize_language(" auto "), None);
+        assert_eq!(normalize_language(""), None);
+    }
+
+    #[test]
+    fn test_route_language() {
+        let bilingual = vec!["zh".to_string(), "en".to_string()];
+        let cantonese = vec!["yue".to_string()];
+        let packs = [bilingual.as_slice(), cantonese.as_slice()];
+
+        assert_eq!(route_language(&packs, "en"), Some(0));
+        assert_eq!(route_language(&packs, "yue"), Some(1));
+        assert_eq!(route_language(&packs, "ja"), None);
+    }
+
+    #[test]
+    fn test_probe_count() {
+        let segments = [segment(0.0, 8.0), segment(9.0, 17.0), segment(18.0, 26.0)];
+        assert_eq!(probe_count(&segments, 20.0), 2);
+        // 第一个片段就超长时仍试听一个
+        assert_eq!(probe_count(&segments, 5.0), 1);
+        assert_eq!(probe_count(&[], 20.0), 0);
+    }
+
+    #[test]
+    fn test_pick_best_by_mean_confidence() {
+        let mandarin = mean_confidence(&[result(&[0.9, 0.8]), result(&[0.7])]);
+        let cantonese = mean_confidence(&[result(&[0.4, 0.5])]);
+        assert!((mandarin.unwrap() - 0.8).abs() < 1e-4);
+
+        assert_eq!(pick_best(&[mandarin, cantonese]), 0);
+        assert_eq!(pick_best(&[cantonese, mandarin]), 1);
+        assert_eq!(pick_best(&[Some(0.5), Some(0.5)]), 0);
+        assert_eq!(pick_best(&[None, None]), 0);
+        assert_eq!(mean_confidence(&[result(&[])]), None);
+    }
+
+    #[test]
+    fn test_packs_disagree_without_token_scores() {
+        // 同一段粤语：普通话包大多输出 blank，粤语包正常出字
+        let mandarin = probe_score(&[unscored("今日", 3_000), unscored("", 2_000)]);
+        let cantonese = probe_score(&[
+            unscored("今日我哋整个咖喱鱼蛋先", 3_000),
+            unscored("落啲咖喱粉炒香佢", 2_000),
+        ]);
+
+        assert!(mandarin.unwrap() < 0.2);
+        assert!(cantonese.unwrap() >= ACCEPT_SCORE);
+        assert_eq!(pick_best(&[mandarin, cantonese]), 1);
+        assert_eq!(pick_best(&[cantonese, mandarin]), 0);
+    }
+
+    #[test]
+    fn test_probe_score_prefers_token_scores() {
+        assert!((probe_score(&[result(&[0.6, 0.8])]).unwrap() - 0.7).abs() < 1e-4);
+        assert_eq!(probe_score(&[unscored("", 0)]), None);
+        assert_eq!(probe_score(&[]), None);
+    }
+
+    #[test]
+    fn test_validate_packs() {
+        let pack = |dir: &str| ModelPackConfig::new(dir, &["zh"]);
+
+        assert!(validate_packs(&[pack("sherpa-ncnn"), pack("sherpa-ncnn-yue")]).is_ok());
+        for invalid in [
+            vec![],
+            vec![pack("")],
+            vec![pack("../models")],
+            vec![pack("sherpa-ncnn"), pack("sherpa-ncnn")],
+        ] {
+            assert!(matches!(
+                validate_packs(&invalid),
+                Err(AudioError::InvalidConfig(_))
+            ));
+        }
+    }
+}
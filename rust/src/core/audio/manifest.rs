@@ -0,0 +1,264 @@
+//! 模型清单 - 校验 models_dir 下的模型文件是否完整
+
+use super::error::AudioError;
+use crate::api::models::manifest::{
+    ModelFileEntry, ModelFileIssue, ModelIssueKind, ModelManifest, ModelValidationReport,
+};
+use log::{info, warn};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+pub const MANIFEST_FILE: &str = "models.json";
+const MANIFEST_VERSION: u32 = 1;
+
+/// 读取 models_dir/models.json
+pub fn load_manifest(models_dir: &Path) -> Result<ModelManifest, AudioError> {
+    let path = models_dir.join(MANIFEST_FILE);
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| AudioError::ModelLoadFailed(format!("failed to read {:?}: {}", path, e)))?;
+    let manifest: ModelManifest = serde_json::from_str(&content)
+        .map_err(|e| AudioError::ModelLoadFailed(format!("invalid {}: {}", MANIFEST_FILE, e)))?;
+    if manifest.version != MANIFEST_VERSION {
+        return Err(AudioError::ModelLoadFailed(format!(
+            "unsupported {} version {}, expected {}",
+            MANIFEST_FILE, manifest.version, MANIFEST_VERSION
+        )));
+    }
+    Ok(manifest)
+}
+
+/// 按清单校验模型文件
+///
+/// `verify_checksums` 为 false 时只比较大小，足以发现被截断的下载，且不需要读取整个文件
+pub fn validate_models(
+    models_dir: &Path,
+    verify_checksums: bool,
+) -> Result<ModelValidationReport, AudioError> {
+    let manifest = load_manifest(models_dir)?;
+    info!(
+        "🔎 Validating {} model files in {:?} (checksums: {})",
+        manifest.files.len(),
+        models_dir,
+        verify_checksums
+    );
+
+    let mut report = ModelValidationReport {
+        checked: manifest.files.len() as u32,
+        checksums_verified: verify_checksums,
+        issues: Vec::new(),
+    };
+    for entry in &manifest.files {
+        if let Some(kind) = check_entry(models_dir, entry, verify_checksums)? {
+            warn!("⚠️ Model file {}: {:?}", entry.path, kind);
+            report.issues.push(ModelFileIssue {
+                path: entry.path.clone(),
+                kind,
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// 为 models_dir 下的文件生成清单，供打包模型时使用
+pub fn build_manifest(models_dir: &Path, paths: &[&str]) -> Result<ModelManifest, AudioError> {
+    let files = paths
+        .iter()
+        .map(|path| {
+            let file = models_dir.join(path);
+            Ok(ModelFileEntry {
+                path: path.to_string(),
+                size: std::fs::metadata(&file)?.len(),
+                sha256: sha256_file(&file)?,
+            })
+        })
+        .collect::<Result<Vec<_>, AudioError>>()?;
+    Ok(ModelManifest {
+        version: MANIFEST_VERSION,
+        files,
+    })
+}
+
+fn check_entry(
+    models_dir: &Path,
+    entry: &ModelFileEntry,
+    verify_checksums: bool,
+) -> Result<Option<ModelIssueKind>, AudioError> {
+    let Some(path) = resolve_entry_path(models_dir, &entry.path) else {
+        return Ok(Some(ModelIssueKind::InvalidPath));
+    };
+    let Ok(meta) = std::fs::metadata(&path) else {
+        return Ok(Some(ModelIssueKind::Missing));
+    };
+    if !meta.is_file() {
+        return Ok(Some(ModelIssueKind::Missing));
+    }
+    if meta.len() != entry.size {
+        return Ok(Some(ModelIssueKind::SizeMismatch {
+            expected: entry.size,
+            actual: meta.len(),
+        }));
+    }
+    if verify_checksums {
+        let actual = sha256_file(&path)?;
+        if !actual.eq_ignore_ascii_case(&entry.sha256) {
+            return Ok(Some(ModelIssueKind::ChecksumMismatch {
+                expected: entry.sha256.to_lowercase(),
+                actual,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// 清单路径只允许指向 models_dir 内部
+fn resolve_entry_path(models_dir: &Path, relative: &str) -> Option<PathBuf> {
+    let relative = Path::new(relative);
+    let inside = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    (inside && !relative.as_os_str().is_empty()).then(|| models_dir.join(relative))
+}
+
+fn sha256_file(path: &Path) -> Result<String, AudioError> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// 校验结果的单行摘要，用于日志与错误信息
+pub fn describe_issues(report: &ModelValidationReport) -> String {
+    report
+        .issues
+        .iter()
+        .map(|issue| match &issue.kind {
+            ModelIssueKind::Missing => format!("{}: missing", issue.path),
+            ModelIssueKind::InvalidPath => format!("{}: invalid path", issue.path),
+            ModelIssueKind::SizeMismatch { expected, actual } => format!(
+                "{}: size {} bytes, expected {}",
+                issue.path, actual, expected
+            ),
+            ModelIssueKind::ChecksumMismatch { .. } => {
+                format!("{}: checksum mismatch", issue.path)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+    fn models_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "cook_lib_manifest_{}_{}_{}",
+            name,
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("sherpa-ncnn")).unwrap();
+        std::fs::write(dir.join("sherpa-ncnn/tokens.txt"), b"abc").unwrap();
+        std::fs::write(dir.join("sherpa-ncnn/encoder.bin"), vec![7u8; 4096]).unwrap();
+        dir
+    }
+
+    fn write_manifest(dir: &Path, manifest: &ModelManifest) {
+        std::fs::write(
+            dir.join(MANIFEST_FILE),
+            serde_json::to_string_pretty(manifest).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_sha256_known_value() {
+        let dir = models_dir("sha");
+        assert_eq!(
+            sha256_file(&dir.join("sherpa-ncnn/tokens.txt")).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_valid_models_pass() {
+        let dir = models_dir("valid");
+        let manifest =
+            build_manifest(&dir, &["sherpa-ncnn/tokens.txt", "sherpa-ncnn/encoder.bin"]).unwrap();
+        write_manifest(&dir, &manifest);
+
+        let report = validate_models(&dir, true).unwrap();
+        assert!(report.is_valid());
+        assert_eq!(report.checked, 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_reports_missing_truncated_and_corrupt_files() {
+        let dir = models_dir("broken");
+        let mut manifest =
+            build_manifest(&dir, &["sherpa-ncnn/tokens.txt", "sherpa-ncnn/encoder.bin"]).unwrap();
+        manifest.files.push(ModelFileEntry {
+            path: "silero-vad/silero.ncnn.bin".to_string(),
+            size: 10,
+            sha256: String::new(),
+        });
+        manifest.files.push(ModelFileEntry {
+            path: "../outside.bin".to_string(),
+            size: 10,
+            sha256: String::new(),
+        });
+        write_manifest(&dir, &manifest);
+        // 截断 encoder，篡改 tokens（大小不变）
+        std::fs::write(dir.join("sherpa-ncnn/encoder.bin"), vec![7u8; 1024]).unwrap();
+        std::fs::write(dir.join("sherpa-ncnn/tokens.txt"), b"abd").unwrap();
+
+        let quick = validate_models(&dir, false).unwrap();
+        assert_eq!(quick.issues.len(), 3);
+
+        let report = validate_models(&dir, true).unwrap();
+        let kinds: Vec<_> = report.issues.iter().map(|i| &i.kind).collect();
+        assert!(matches!(kinds[0], ModelIssueKind::ChecksumMismatch { .. }));
+        assert_eq!(
+            kinds[1],
+            &ModelIssueKind::SizeMismatch {
+                expected: 4096,
+                actual: 1024
+            }
+        );
+        assert_eq!(kinds[2], &ModelIssueKind::Missing);
+        assert_eq!(kinds[3], &ModelIssueKind::InvalidPath);
+        assert!(describe_issues(&report).contains("sherpa-ncnn/encoder.bin: size 1024 bytes"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_missing_manifest_is_an_error() {
+        let dir = models_dir("no_manifest");
+        assert!(matches!(
+            validate_models(&dir, false),
+            Err(AudioError::ModelLoadFailed(_))
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,9 +1,27 @@
+pub mod decimator;
+pub mod diarization;
 pub mod error;
+pub mod events;
 pub mod handler;
+pub mod hotwords;
+pub mod language;
+pub mod manifest;
+pub mod pool;
+pub mod postprocess;
+pub mod preprocess;
+pub mod stream;
 pub mod utils;
 pub mod vad;
 
+pub use diarization::{Diarizer, SpeakerEmbedder};
 pub use error::AudioError;
+pub use events::EventDetector;
 pub use handler::NcnnHandle;
-pub use utils::{load_wav_mono_f32, resample_to_16k_mono};
-pub use vad::{SpeechSegment, VadHandle};
+pub use hotwords::{HotwordsFile, TokenVocab, COOKING_LEXICON};
+pub use language::ModelPack;
+pub use pool::RecognizerPool;
+pub use postprocess::{Postprocessor, Punctuator};
+pub use preprocess::{PreprocessStream, Preprocessor};
+pub use stream::{PcmWindow, Wav16kStream, STREAM_CHUNK};
+pub use utils::{downmix_to_mono, i16_to_f32, load_wav_mono_f32, resample_to_16k_mono};
+pub use vad::{fixed_windows, SpeechSegment, VadHandle};
@@ -0,0 +1,266 @@
+//! 识别器池 - 多个 NCNN 实例并行转录 VAD 片段
+
+use super::error::AudioError;
+use super::handler::NcnnHandle;
+use super::stream::PcmWindow;
+use super::vad::SpeechSegment;
+use crate::api::models::asr::{AsrConfig, RecognizerPoolConfig};
+use crate::api::models::transcript::TranscriptSegment;
+use log::{debug, info, warn};
+use rayon::prelude::*;
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::{Condvar, Mutex, PoisonError};
+
+/// 自动模式下的实例数上限
+const MAX_AUTO_INSTANCES: usize = 4;
+/// 模型加载后的常驻内存约为文件大小的 1.5 倍（权重 + 推理缓冲区）
+const MEMORY_OVERHEAD_NUM: u64 = 3;
+const MEMORY_OVERHEAD_DEN: u64 = 2;
+
+/// NCNN 识别器池
+///
+/// 片段在专用的 rayon 线程池上并行转录，线程数与实例数相同，每个任务独占一个实例。
+pub struct RecognizerPool {
+    handles: HandleQueue<NcnnHandle>,
+    size: usize,
+    workers: rayon::ThreadPool,
+}
+
+impl RecognizerPool {
+    /// 按配置加载识别器实例
+    ///
+    /// 第一个实例加载失败直接报错；后续实例加载失败只记录日志，按已加载的实例数运行
+    pub fn new(
+        model_dir: &str,
+        config: &AsrConfig,
+        hotwords_file: Option<&str>,
+        pool: &RecognizerPoolConfig,
+    ) -> Result<Self, AudioError> {
+        let cpus = num_cpus::get();
+        let instance_bytes = estimate_instance_bytes(Path::new(model_dir), config);
+        let target = plan_pool_size(pool, cpus, instance_bytes);
+        info!(
+            "🧵 Recognizer pool: {} instance(s), ~{} MB each",
+            target,
+            instance_bytes / (1024 * 1024)
+        );
+
+        // 自动线程数时按实例数均分 CPU，避免多个实例互相抢占
+        let mut instance_config = config.clone();
+        if config.num_threads == 0 && target > 1 {
+            instance_config.num_threads = (cpus / target).max(1) as u32;
+        }
+
+        let mut handles = vec![NcnnHandle::new(model_dir, &instance_config, hotwords_file)?];
+        while handles.len() < target {
+            match NcnnHandle::new(model_dir, &instance_config, hotwords_file) {
+                Ok(handle) => handles.push(handle),
+                Err(e) => {
+                    warn!(
+                        "⚠️ Failed to load recognizer instance {}: {}, continuing with {}",
+                        handles.len() + 1,
+                        e,
+                        handles.len()
+                    );
+                    break;
+                }
+            }
+        }
+
+        let size = handles.len();
+        let workers = rayon::ThreadPoolBuilder::new()
+            .num_threads(size)
+            .thread_name(|i| format!("cook-asr-{}", i))
+            .build()
+            .map_err(|e| AudioError::SherpaNcnn(format!("Failed to create worker pool: {}", e)))?;
+
+        Ok(Self {
+            handles: HandleQueue::new(handles),
+            size,
+            workers,
+        })
+    }
+
+    /// 已加载的实例数
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// 并行转录所有片段，结果与 `segments` 一一对应，保持时间顺序
+    ///
+    /// 片段时间为整段音频中的绝对时间，`window` 需包含所有片段的音频
+    pub fn transcribe_segments(
+        &self,
+        window: &PcmWindow,
+        segments: &[SpeechSegment],
+        language: Option<&str>,
+    ) -> Vec<Result<TranscriptSegment, AudioError>> {
+        self.workers.install(|| {
+            segments
+                .par_iter()
+                .enumerate()
+                .map(|(index, segment)| {
+                    let samples = window.extract(segment);
+                    debug!(
+                        "   Extracted {} samples for segment {}",
+                        samples.len(),
+                        index + 1
+                    );
+                    let handle = self.handles.checkout()?;
+                    handle.transcribe_segment(&samples, 16_000, language, segment.start)
+                })
+                .collect()
+        })
+    }
+}
+
+/// 空闲实例队列，取出的实例由 [`Checkout`] 持有，离开作用域时自动归还
+struct HandleQueue<T> {
+    idle: Mutex<Vec<T>>,
+    available: Condvar,
+}
+
+impl<T> HandleQueue<T> {
+    fn new(handles: Vec<T>) -> Self {
+        Self {
+            idle: Mutex::new(handles),
+            available: Condvar::new(),
+        }
+    }
+
+    /// 取出一个空闲实例，没有时阻塞等待
+    fn checkout(&self) -> Result<Checkout<'_, T>, AudioError> {
+        let mut idle = self.idle.lock().map_err(pool_poisoned)?;
+        loop {
+            if let Some(handle) = idle.pop() {
+                return Ok(Checkout {
+                    queue: self,
+                    handle: Some(handle),
+                });
+            }
+            idle = self.available.wait(idle).map_err(pool_poisoned)?;
+        }
+    }
+}
+
+/// 借出的实例；转录出错或 panic 时也会在 drop 中归还，等待中的任务不会永远阻塞
+struct Checkout<'a, T> {
+    queue: &'a HandleQueue<T>,
+    handle: Option<T>,
+}
+
+impl<T> Deref for Checkout<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.handle.as_ref().expect("handle is present until drop")
+    }
+}
+
+impl<T> Drop for Checkout<'_, T> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            // 归还时不持有其他锁，锁中毒也照常放回
+            self.queue
+                .idle
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(handle);
+            self.queue.available.notify_one();
+        }
+    }
+}
+
+fn pool_poisoned<T>(e: PoisonError<T>) -> AudioError {
+    AudioError::SherpaNcnn(format!("Recognizer pool lock poisoned: {}", e))
+}
+
+/// 按模型文件大小估算单个实例的常驻内存
+fn estimate_instance_bytes(model_dir: &Path, config: &AsrConfig) -> u64 {
+    let file_bytes: u64 = config
+        .model_files
+        .all()
+        .into_iter()
+        .filter_map(|name| std::fs::metadata(model_dir.join(name)).ok())
+        .map(|meta| meta.len())
+        .sum();
+    file_bytes * MEMORY_OVERHEAD_NUM / MEMORY_OVERHEAD_DEN
+}
+
+/// 实例数 = min(配置上限或自动值, 内存上限可容纳的数量)，至少为 1
+fn plan_pool_size(pool: &RecognizerPoolConfig, cpus: usize, instance_bytes: u64) -> usize {
+    let requested = match pool.max_instances {
+        0 => (cpus / 2).clamp(1, MAX_AUTO_INSTANCES),
+        n => n as usize,
+    };
+    let by_memory = match (pool.max_memory_mb, instance_bytes) {
+        (0, _) | (_, 0) => usize::MAX,
+        (mb, bytes) => (mb as u64 * 1024 * 1024 / bytes) as usize,
+    };
+    requested.min(by_memory).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MB: u64 = 1024 * 1024;
+
+    fn pool(max_instances: u32, max_memory_mb: u32) -> RecognizerPoolConfig {
+        RecognizerPoolConfig {
+            max_instances,
+            max_memory_mb,
+        }
+    }
+
+    #[test]
+    fn test_plan_pool_size_auto() {
+        assert_eq!(plan_pool_size(&pool(0, 0), 8, 100 * MB), 4);
+        assert_eq!(plan_pool_size(&pool(0, 0), 4, 100 * MB), 2);
+        assert_eq!(plan_pool_size(&pool(0, 0), 1, 100 * MB), 1);
+        assert_eq!(
+            plan_pool_size(&pool(0, 0), 32, 100 * MB),
+            MAX_AUTO_INSTANCES
+        );
+    }
+
+    #[test]
+    fn test_plan_pool_size_respects_memory_cap() {
+        assert_eq!(plan_pool_size(&pool(6, 512), 8, 100 * MB), 5);
+        assert_eq!(plan_pool_size(&pool(6, 250), 8, 100 * MB), 2);
+        // 内存上限连一个实例都放不下时仍加载 1 个
+        assert_eq!(plan_pool_size(&pool(6, 50), 8, 100 * MB), 1);
+        // 估算不出模型大小时不按内存限制
+        assert_eq!(plan_pool_size(&pool(6, 50), 8, 0), 6);
+    }
+
+    #[test]
+    fn test_checkout_is_returned_after_panic() {
+        let queue = HandleQueue::new(vec![7_u32]);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let handle = queue.checkout().unwrap();
+            assert_eq!(*handle, 7);
+            panic!("转录中途 panic");
+        }));
+        assert!(result.is_err());
+
+        // 唯一的实例已归还，再次借出不会阻塞
+        let handle = queue.checkout().expect("实例应该已归还");
+        assert_eq!(*handle, 7);
+    }
+
+    #[test]
+    fn test_checkout_waits_for_returned_handle() {
+        let queue = HandleQueue::new(vec![1_u32]);
+        let first = queue.checkout().unwrap();
+
+        std::thread::scope(|scope| {
+            let waiter = scope.spawn(|| *queue.checkout().unwrap());
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            drop(first);
+            assert_eq!(waiter.join().unwrap(), 1);
+        });
+    }
+}
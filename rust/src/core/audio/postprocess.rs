@@ -0,0 +1,519 @@
+//! 识别结果后处理 - 标点恢复、中英混排规范、中文数字规整（ITN）
+
+use crate::api::models::asr::{HotwordConfig, PostprocessConfig, PunctuationMode};
+use crate::api::models::transcript::TranscriptSegment;
+use std::collections::HashSet;
+
+/// 标点恢复，可替换为基于模型的实现
+pub trait Punctuator: Send + Sync {
+    /// 返回加好标点的片段文本
+    fn punctuate(&self, segment: &TranscriptSegment) -> String;
+}
+
+/// 基于停顿的规则标点：停顿处加逗号，句末加句号或问号
+pub struct RulePunctuator {
+    pause_ms: u64,
+}
+
+impl RulePunctuator {
+    pub fn new(pause_ms: u32) -> Self {
+        Self {
+            pause_ms: pause_ms as u64,
+        }
+    }
+}
+
+impl Punctuator for RulePunctuator {
+    fn punctuate(&self, segment: &TranscriptSegment) -> String {
+        if segment.tokens.is_empty() {
+            return end_sentence(segment.text.trim());
+        }
+
+        let mut text = String::new();
+        let mut prev_start: Option<u64> = None;
+        for token in &segment.tokens {
+            // BPE 子词用 ▁ 表示词首空格
+            let piece = token.token.replace('▁', " ");
+            let paused =
+                prev_start.is_some_and(|prev| token.start_ms.saturating_sub(prev) >= self.pause_ms);
+            let open = text.trim_end();
+            if paused && !open.is_empty() && !open.ends_with(is_punctuation) {
+                text.truncate(open.len());
+                text.push('，');
+                text.push_str(piece.trim_start());
+            } else {
+                text.push_str(&piece);
+            }
+            prev_start = Some(token.start_ms);
+        }
+        end_sentence(text.trim())
+    }
+}
+
+/// 片段后处理流水线
+pub struct Postprocessor {
+    punctuator: Option<Box<dyn Punctuator>>,
+    mixed_english: bool,
+    /// 热词中的英文缩写，中英混排规范时保持大写
+    acronyms: HashSet<String>,
+    inverse_text_normalization: bool,
+}
+
+impl Postprocessor {
+    pub fn new(config: &PostprocessConfig, hotwords: Option<&HotwordConfig>) -> Self {
+        let punctuator: Option<Box<dyn Punctuator>> = match config.punctuation {
+            PunctuationMode::Off => None,
+            PunctuationMode::Rules { pause_ms } => Some(Box::new(RulePunctuator::new(pause_ms))),
+        };
+        let acronyms = hotwords
+            .into_iter()
+            .flat_map(|config| &config.hotwords)
+            .flat_map(|hotword| uppercase_words(&hotword.phrase))
+            .map(str::to_string)
+            .collect();
+        Self {
+            punctuator,
+            mixed_english: config.mixed_english,
+            acronyms,
+            inverse_text_normalization: config.inverse_text_normalization,
+        }
+    }
+
+    /// 替换标点实现（例如接入标点模型）
+    pub fn with_punctuator(mut self, punctuator: Box<dyn Punctuator>) -> Self {
+        self.punctuator = Some(punctuator);
+        self
+    }
+
+    /// 就地处理片段文本，空文本保持不变
+    pub fn process(&self, segment: &mut TranscriptSegment) {
+        if segment.text.trim().is_empty() {
+            return;
+        }
+        if let Some(punctuator) = &self.punctuator {
+            segment.text = punctuator.punctuate(segment);
+        }
+        if self.mixed_english {
+            segment.text = fix_mixed_english(&segment.text, &self.acronyms);
+        }
+        if self.inverse_text_normalization {
+            segment.text = normalize_numbers(&segment.text);
+        }
+    }
+}
+
+fn is_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '，' | '。' | '？' | '！' | '、' | '；' | '：' | ',' | '.' | '?' | '!'
+    )
+}
+
+fn end_sentence(text: &str) -> String {
+    if text.is_empty() || text.ends_with(is_punctuation) {
+        return text.to_string();
+    }
+    let mark = if text.ends_with(['吗', '呢', '么']) {
+        '？'
+    } else {
+        '。'
+    };
+    format!("{}{}", text, mark)
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{9fff}' | '\u{f900}'..='\u{faff}')
+}
+
+/// 文本中两个字母以上、全部大写的英文单词
+fn uppercase_words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|word| word.len() > 1 && !word.contains(|c: char| c.is_ascii_lowercase()))
+}
+
+/// 中英混排规范：模型的大写 BPE 输出的多字母英文单词转小写，单个字母（如 I）与
+/// `acronyms` 中的缩写保持原样；合并多余空格，汉字之间不留空格，汉字与英文字母之间留一个空格
+pub fn fix_mixed_english(text: &str, acronyms: &HashSet<String>) -> String {
+    let mut lowered = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_alphabetic()) {
+        lowered.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let word = &rest[..end];
+        let from_model = word.len() > 1 && !word.contains(|c: char| c.is_ascii_lowercase());
+        if from_model && !acronyms.contains(word) {
+            lowered.push_str(&word.to_ascii_lowercase());
+        } else {
+            lowered.push_str(word);
+        }
+        rest = &rest[end..];
+    }
+    lowered.push_str(rest);
+
+    let chars: Vec<char> = lowered.chars().collect();
+    let mut out = String::with_capacity(lowered.len());
+    let mut last: Option<char> = None;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            let next = chars[i..].iter().copied().find(|c| !c.is_whitespace());
+            if let (Some(prev), Some(next)) = (last, next) {
+                if needs_space(prev, next)
+                    || (prev.is_ascii_alphanumeric() && next.is_ascii_alphanumeric())
+                {
+                    out.push(' ');
+                }
+            }
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            continue;
+        }
+        if let Some(prev) = last {
+            if needs_space(prev, c) && !out.ends_with(' ') {
+                out.push(' ');
+            }
+        }
+        out.push(c);
+        last = Some(c);
+        i += 1;
+    }
+    out
+}
+
+fn needs_space(a: char, b: char) -> bool {
+    (is_cjk(a) && b.is_ascii_alphabetic()) || (a.is_ascii_alphabetic() && is_cjk(b))
+}
+
+/// 数字后可接的计量单位，长的在前
+const UNITS: &[&str] = &[
+    "千克", "公斤", "毫升", "汤匙", "茶匙", "小时", "分钟", "厘米", "毫米", "克", "斤", "升", "勺",
+    "杯", "秒", "度",
+];
+
+fn digit_value(c: char) -> Option<u64> {
+    let value = match c {
+        '零' | '〇' => 0,
+        '一' => 1,
+        '二' | '两' => 2,
+        '三' => 3,
+        '四' => 4,
+        '五' => 5,
+        '六' => 6,
+        '七' => 7,
+        '八' => 8,
+        '九' => 9,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn unit_value(c: char) -> Option<u64> {
+    match c {
+        '十' => Some(10),
+        '百' => Some(100),
+        '千' => Some(1_000),
+        '万' => Some(10_000),
+        '亿' => Some(100_000_000),
+        _ => None,
+    }
+}
+
+fn is_numeral(c: char) -> bool {
+    digit_value(c).is_some() || unit_value(c).is_some()
+}
+
+/// 解析中文整数：十五、三百、一百八（180）、一千零五、两万、二零二四
+fn parse_integer(chars: &[char]) -> Option<u64> {
+    if chars.is_empty() {
+        return None;
+    }
+    // 逐位读法只用于年份、编号这类三位以上或以零开头的串；“两三”“七八”是约数，不转换
+    let positional = chars.len() >= 3 || matches!(chars[0], '零' | '〇');
+    if positional && chars.iter().all(|&c| digit_value(c).is_some()) {
+        return chars.iter().try_fold(0_u64, |acc, &c| {
+            acc.checked_mul(10)?.checked_add(digit_value(c)?)
+        });
+    }
+
+    let mut total = 0_u64;
+    let mut section = 0_u64;
+    let mut number = 0_u64;
+    let mut last_unit = 1_u64;
+    let mut after_unit = false;
+    let mut after_digit = false;
+    for &c in chars {
+        if let Some(d) = digit_value(c) {
+            // 非零数字后紧跟数字（三四、七八十）是约数，无法确定具体值
+            if after_digit {
+                return None;
+            }
+            number = d;
+            after_digit = d != 0;
+            after_unit = d != 0 && after_unit;
+            continue;
+        }
+        let unit = unit_value(c)?;
+        if unit >= 10_000 {
+            total = (total + section + number).checked_mul(unit)?;
+            section = 0;
+        } else {
+            let base = if number == 0 && section == 0 && total == 0 {
+                1
+            } else {
+                number
+            };
+            section += base * unit;
+        }
+        number = 0;
+        last_unit = unit;
+        after_unit = true;
+        after_digit = false;
+    }
+    // 口语省略末位单位：一百八 = 180，三千五 = 3500，两万五 = 25000
+    if after_unit && number > 0 && last_unit >= 100 {
+        number = number.checked_mul(last_unit / 10)?;
+    }
+    Some(total + section + number)
+}
+
+/// 从 `start` 开始读取连续的中文数字
+fn numeral_run(chars: &[char], start: usize) -> usize {
+    chars[start..]
+        .iter()
+        .take_while(|&&c| is_numeral(c))
+        .count()
+}
+
+fn starts_with_at(chars: &[char], at: usize, word: &str) -> bool {
+    word.chars()
+        .enumerate()
+        .all(|(offset, w)| chars.get(at + offset) == Some(&w))
+}
+
+/// 中文数字规整，只转换带计量单位、百分比与时间的数字，避免误伤“一点盐”“一下”等
+pub fn normalize_numbers(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        match convert_at(&chars, i) {
+            Some((replacement, consumed)) => {
+                out.push_str(&replacement);
+                i += consumed;
+            }
+            None => {
+                out.push(chars[i]);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// 尝试在位置 `i` 转换一个数字表达式，返回替换文本与消耗的字符数
+fn convert_at(chars: &[char], i: usize) -> Option<(String, usize)> {
+    // 百分之三十 → 30%
+    if starts_with_at(chars, i, "百分之") {
+        let run = numeral_run(chars, i + 3);
+        let value = parse_integer(&chars[i + 3..i + 3 + run])?;
+        return Some((format!("{}%", value), 3 + run));
+    }
+
+    // 前一个字也是数字时不从中间开始转换
+    if i > 0 && is_numeral(chars[i - 1]) {
+        return None;
+    }
+    let mut run = numeral_run(chars, i);
+    // 五千克 = 5 千克，而不是 5000 克
+    if run > 1 && starts_with_at(chars, i + run - 1, "千克") {
+        run -= 1;
+    }
+    if run == 0 {
+        return None;
+    }
+    let integer = parse_integer(&chars[i..i + run])?;
+    let mut end = i + run;
+
+    if chars.get(end) == Some(&'点') {
+        let fraction_run = numeral_run(chars, end + 1);
+        let fraction = &chars[end + 1..end + 1 + fraction_run];
+        let after = end + 1 + fraction_run;
+
+        // 八点十五分 → 8:15（后面接“钟”时是时长，不按时间处理）
+        if chars.get(after) == Some(&'分') && chars.get(after + 1) != Some(&'钟') {
+            let minutes = parse_integer(fraction)?;
+            if integer <= 24 && minutes < 60 {
+                return Some((format!("{}:{:02}", integer, minutes), after + 1 - i));
+            }
+        }
+
+        // 一点五升 → 1.5升
+        let decimal: Option<String> = fraction
+            .iter()
+            .map(|&c| digit_value(c).map(|d| char::from(b'0' + d as u8)))
+            .collect();
+        match decimal {
+            Some(decimal) if !decimal.is_empty() => {
+                let unit = unit_at(chars, after)?;
+                return Some((
+                    format!("{}.{}{}", integer, decimal, unit),
+                    after + unit.chars().count() - i,
+                ));
+            }
+            _ => return None,
+        }
+    }
+
+    let unit = unit_at(chars, end)?;
+    // “一度以为”“再一度”里的度不是温度
+    if unit == "度" && run == 1 && chars[i] == '一' {
+        return None;
+    }
+    end += unit.chars().count();
+    Some((format!("{}{}", integer, unit), end - i))
+}
+
+fn unit_at(chars: &[char], at: usize) -> Option<&'static str> {
+    UNITS
+        .iter()
+        .copied()
+        .find(|unit| starts_with_at(chars, at, unit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::asr::Hotword;
+    use crate::api::models::transcript::TranscriptToken;
+
+    fn segment(tokens: &[(&str, u64)]) -> TranscriptSegment {
+        TranscriptSegment {
+            start_ms: 0,
+            end_ms: 0,
+            text: tokens.iter().map(|(t, _)| *t).collect::<String>(),
+            tokens: tokens
+                .iter()
+                .map(|&(token, start_ms)| TranscriptToken {
+                    token: token.to_string(),
+                    start_ms,
+                    confidence: Some(1.0),
+                })
+                .collect(),
+            speaker: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_integer() {
+        let parse = |s: &str| parse_integer(&s.chars().collect::<Vec<_>>());
+        assert_eq!(parse("十五"), Some(15));
+        assert_eq!(parse("三百"), Some(300));
+        assert_eq!(parse("一百八十"), Some(180));
+        assert_eq!(parse("一百八"), Some(180));
+        assert_eq!(parse("一千零五"), Some(1005));
+        assert_eq!(parse("两万"), Some(20_000));
+        assert_eq!(parse("二零二四"), Some(2024));
+        assert_eq!(parse("零五"), Some(5));
+        assert_eq!(parse("两万五"), Some(25_000));
+        assert_eq!(parse("一万二"), Some(12_000));
+        assert_eq!(parse("三万零五"), Some(30_005));
+        assert_eq!(parse("一万二千五"), Some(12_500));
+        assert_eq!(parse("两三"), None);
+        assert_eq!(parse("七八十"), None);
+    }
+
+    #[test]
+    fn test_normalize_numbers() {
+        assert_eq!(normalize_numbers("加三百克面粉"), "加300克面粉");
+        assert_eq!(normalize_numbers("倒一点五升水"), "倒1.5升水");
+        assert_eq!(
+            normalize_numbers("烤箱一百八十度烤十五分钟"),
+            "烤箱180度烤15分钟"
+        );
+        assert_eq!(normalize_numbers("两勺生抽"), "2勺生抽");
+        assert_eq!(normalize_numbers("五千克牛肉"), "5千克牛肉");
+        assert_eq!(normalize_numbers("早上八点十五分出门"), "早上8:15出门");
+        assert_eq!(normalize_numbers("含糖量百分之三十"), "含糖量30%");
+        // 没有单位的数字不转换
+        assert_eq!(normalize_numbers("放一点盐，搅一下"), "放一点盐，搅一下");
+        assert_eq!(normalize_numbers("两个鸡蛋"), "两个鸡蛋");
+        // 一度是副词，不是温度
+        assert_eq!(normalize_numbers("我一度以为要糊了"), "我一度以为要糊了");
+        assert_eq!(normalize_numbers("油温七十度左右"), "油温70度左右");
+        // 相邻的两个数字是约数，保持原样
+        assert_eq!(normalize_numbers("煮两三分钟"), "煮两三分钟");
+        assert_eq!(normalize_numbers("放三四勺糖"), "放三四勺糖");
+        assert_eq!(normalize_numbers("油温七八十度"), "油温七八十度");
+        assert_eq!(normalize_numbers("一共两万五克"), "一共25000克");
+    }
+
+    #[test]
+    fn test_fix_mixed_english() {
+        let none = HashSet::new();
+        assert_eq!(
+            fix_mixed_english("加一点OLIVE  OIL 然后 放 进 AIR FRYER", &none),
+            "加一点 olive oil 然后放进 air fryer"
+        );
+        assert_eq!(fix_mixed_english("I 觉得 OK", &none), "I 觉得 ok");
+        assert_eq!(fix_mixed_english("烤 20 分钟", &none), "烤20分钟");
+    }
+
+    #[test]
+    fn test_hotword_acronyms_stay_uppercase() {
+        let hotwords = HotwordConfig {
+            hotwords: vec![Hotword {
+                phrase: "BBQ酱".to_string(),
+                score: None,
+            }],
+            ..Default::default()
+        };
+        let config = PostprocessConfig {
+            mixed_english: true,
+            ..Default::default()
+        };
+        let mut seg = segment(&[("刷", 0), ("▁BBQ", 200), ("酱", 400), ("▁OK", 600)]);
+        seg.text = "刷 BBQ 酱 OK".to_string();
+
+        Postprocessor::new(&config, Some(&hotwords)).process(&mut seg);
+        assert_eq!(seg.text, "刷 BBQ 酱 ok");
+    }
+
+    #[test]
+    fn test_rule_punctuation() {
+        let punctuator = RulePunctuator::new(600);
+        let seg = segment(&[
+            ("先", 0),
+            ("放", 200),
+            ("油", 400),
+            ("再", 1_400),
+            ("放", 1_600),
+            ("盐", 1_800),
+        ]);
+        assert_eq!(punctuator.punctuate(&seg), "先放油，再放盐。");
+
+        let question = segment(&[("好", 0), ("吃", 200), ("吗", 400)]);
+        assert_eq!(punctuator.punctuate(&question), "好吃吗？");
+    }
+
+    #[test]
+    fn test_stages_are_opt_in() {
+        let mut seg = segment(&[("三", 0), ("百", 200), ("克", 400)]);
+        Postprocessor::new(&PostprocessConfig::default(), None).process(&mut seg);
+        assert_eq!(seg.text, "三百克");
+
+        let mut seg = segment(&[("三", 0), ("百", 200), ("克", 400)]);
+        let config = PostprocessConfig {
+            punctuation: PunctuationMode::Rules { pause_ms: 600 },
+            mixed_english: true,
+            inverse_text_normalization: true,
+        };
+        Postprocessor::new(&config, None).process(&mut seg);
+        assert_eq!(seg.text, "300克。");
+        assert_eq!(seg.tokens.len(), 3);
+    }
+}
@@ -0,0 +1,728 @@
+//! 音频预处理 - 去直流、高通、谱减降噪、响度归一化
+//!
+//! 处理 16kHz 单声道音频，可分块送入；输出与输入逐样本对齐，总长度不变
+
+use super::error::AudioError;
+use crate::api::models::asr::{
+    LoudnessConfig, LoudnessMeasure, NoiseReductionConfig, PreprocessConfig,
+};
+use log::info;
+use rustdct::rustfft::num_complex::Complex;
+use rustdct::rustfft::{Fft, FftPlanner};
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+const SAMPLE_RATE: f64 = 16_000.0;
+/// 去直流极点
+const DC_POLE: f64 = 0.995;
+/// 四阶 Butterworth 高通拆成两节二阶滤波器的 Q 值
+const BUTTERWORTH_Q: [f64; 2] = [0.541_196_1, 1.306_563];
+
+/// 谱减帧长与帧移（32ms / 16ms）
+const FRAME_LEN: usize = 512;
+const HOP_LEN: usize = FRAME_LEN / 2;
+const BINS: usize = FRAME_LEN / 2 + 1;
+/// 功率谱时间平滑系数
+const PSD_SMOOTHING: f32 = 0.8;
+/// 最小值跟踪：8 个子窗口 × 12 帧，约 1.5 秒
+const MIN_SUBWINDOWS: usize = 8;
+const MIN_SUBWINDOW_FRAMES: usize = 12;
+/// 平滑功率谱的最小值低于噪声均值，乘以该系数补偿
+const MIN_BIAS: f32 = 2.0;
+
+/// 响度测量块长（100ms）
+const LOUDNESS_BLOCK: usize = 1_600;
+/// 低于该电平的块视为静音，不参与响度测量
+const SILENCE_GATE_DB: f64 = -50.0;
+
+pub fn validate_config(config: &PreprocessConfig) -> Result<(), AudioError> {
+    if config.high_pass_hz >= 4_000 {
+        return Err(AudioError::InvalidConfig(format!(
+            "high_pass_hz must be below 4000, got {}",
+            config.high_pass_hz
+        )));
+    }
+    if let Some(noise) = &config.noise_reduction {
+        if !(noise.over_subtraction.is_finite() && noise.over_subtraction > 0.0) {
+            return Err(AudioError::InvalidConfig(format!(
+                "noise reduction over_subtraction must be positive, got {}",
+                noise.over_subtraction
+            )));
+        }
+        if !(noise.floor_db.is_finite() && noise.floor_db <= 0.0) {
+            return Err(AudioError::InvalidConfig(format!(
+                "noise reduction floor_db must be at most 0, got {}",
+                noise.floor_db
+            )));
+        }
+    }
+    if let Some(loudness) = &config.loudness {
+        if !(loudness.target_db.is_finite() && loudness.target_db < 0.0) {
+            return Err(AudioError::InvalidConfig(format!(
+                "loudness target_db must be negative, got {}",
+                loudness.target_db
+            )));
+        }
+        if !(loudness.max_gain_db.is_finite() && loudness.max_gain_db >= 0.0) {
+            return Err(AudioError::InvalidConfig(format!(
+                "loudness max_gain_db must be non-negative, got {}",
+                loudness.max_gain_db
+            )));
+        }
+        if loudness.window_ms < 100 {
+            return Err(AudioError::InvalidConfig(format!(
+                "loudness window_ms must be at least 100, got {}",
+                loudness.window_ms
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// 预处理链，每段音频新建一个（内部保存滤波器与噪声估计状态）
+pub struct Preprocessor {
+    dc: Option<DcBlocker>,
+    high_pass: Vec<Biquad>,
+    noise: Option<NoiseSuppressor>,
+    loudness: Option<LoudnessNormalizer>,
+}
+
+impl Preprocessor {
+    pub fn new(config: &PreprocessConfig) -> Result<Self, AudioError> {
+        validate_config(config)?;
+        let high_pass = match config.high_pass_hz {
+            0 => Vec::new(),
+            hz => BUTTERWORTH_Q
+                .iter()
+                .map(|&q| Biquad::high_pass(hz as f64, q))
+                .collect(),
+        };
+        info!(
+            "🎚️ Preprocessing: dc={}, high-pass={}Hz, noise reduction={}, loudness={}",
+            config.remove_dc,
+            config.high_pass_hz,
+            config.noise_reduction.is_some(),
+            config.loudness.is_some()
+        );
+        Ok(Self {
+            dc: config.remove_dc.then(DcBlocker::default),
+            high_pass,
+            noise: config.noise_reduction.as_ref().map(NoiseSuppressor::new),
+            loudness: config.loudness.as_ref().map(LoudnessNormalizer::new),
+        })
+    }
+
+    /// 处理一块音频；降噪有一帧的延迟，返回的样本可能少于输入，剩余部分由 [`Self::finish`] 输出
+    pub fn process(&mut self, chunk: &[f32]) -> Vec<f32> {
+        let mut samples = chunk.to_vec();
+        self.filter(&mut samples);
+        let mut samples = match &mut self.noise {
+            Some(noise) => noise.process(&samples),
+            None => samples,
+        };
+        if let Some(loudness) = &mut self.loudness {
+            loudness.process(&mut samples);
+        }
+        samples
+    }
+
+    pub fn finish(&mut self) -> Vec<f32> {
+        let mut samples = match &mut self.noise {
+            Some(noise) => noise.finish(),
+            None => Vec::new(),
+        };
+        if let Some(loudness) = &mut self.loudness {
+            loudness.process(&mut samples);
+        }
+        samples
+    }
+
+    fn filter(&mut self, samples: &mut [f32]) {
+        for sample in samples.iter_mut() {
+            let mut x = *sample as f64;
+            if let Some(dc) = &mut self.dc {
+                x = dc.process(x);
+            }
+            for biquad in &mut self.high_pass {
+                x = biquad.process(x);
+            }
+            *sample = x as f32;
+        }
+    }
+}
+
+/// 对 16kHz 分块流逐块预处理，config 为 None 时原样透传
+pub struct PreprocessStream<I> {
+    inner: I,
+    chain: Option<Preprocessor>,
+    done: bool,
+}
+
+impl<I> PreprocessStream<I> {
+    pub fn new(inner: I, config: Option<&PreprocessConfig>) -> Result<Self, AudioError> {
+        Ok(Self {
+            inner,
+            chain: config.map(Preprocessor::new).transpose()?,
+            done: false,
+        })
+    }
+}
+
+impl<I> Iterator for PreprocessStream<I>
+where
+    I: Iterator<Item = Result<Vec<f32>, AudioError>>,
+{
+    type Item = Result<Vec<f32>, AudioError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let Some(chain) = &mut self.chain else {
+            return self.inner.next();
+        };
+        match self.inner.next() {
+            Some(Ok(chunk)) => Some(Ok(chain.process(&chunk))),
+            Some(Err(e)) => {
+                self.done = true;
+                Some(Err(e))
+            }
+            None => {
+                self.done = true;
+                Some(Ok(chain.finish()))
+            }
+        }
+    }
+}
+
+/// 一阶去直流滤波：y[n] = x[n] - x[n-1] + R·y[n-1]
+#[derive(Default)]
+struct DcBlocker {
+    x1: f64,
+    y1: f64,
+}
+
+impl DcBlocker {
+    fn process(&mut self, x: f64) -> f64 {
+        let y = x - self.x1 + DC_POLE * self.y1;
+        self.x1 = x;
+        self.y1 = y;
+        y
+    }
+}
+
+/// 二阶 IIR 滤波器（转置直接 II 型），系数按 RBJ Audio EQ Cookbook 计算
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    z1: f64,
+    z2: f64,
+}
+
+impl Biquad {
+    fn new(b: [f64; 3], a: [f64; 3]) -> Self {
+        Self {
+            b: [b[0] / a[0], b[1] / a[0], b[2] / a[0]],
+            a: [a[1] / a[0], a[2] / a[0]],
+            z1: 0.0,
+            z2: 0.0,
+        }
+    }
+
+    fn high_pass(cutoff_hz: f64, q: f64) -> Self {
+        let w0 = 2.0 * PI * cutoff_hz / SAMPLE_RATE;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * q);
+        Self::new(
+            [(1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    fn high_shelf(center_hz: f64, gain_db: f64, q: f64) -> Self {
+        let a = 10_f64.powf(gain_db / 40.0);
+        let w0 = 2.0 * PI * center_hz / SAMPLE_RATE;
+        let (sin, cos) = w0.sin_cos();
+        let alpha = sin / (2.0 * q);
+        let root = 2.0 * a.sqrt() * alpha;
+        Self::new(
+            [
+                a * ((a + 1.0) + (a - 1.0) * cos + root),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - root),
+            ],
+            [
+                (a + 1.0) - (a - 1.0) * cos + root,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - root,
+            ],
+        )
+    }
+
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.z1;
+        self.z1 = self.b[1] * x - self.a[0] * y + self.z2;
+        self.z2 = self.b[2] * x - self.a[1] * y;
+        y
+    }
+}
+
+/// 谱减降噪：STFT（sqrt-Hann 窗，50% 重叠）→ 最小值跟踪估计噪声谱 → 按频点衰减 → 重叠相加
+struct NoiseSuppressor {
+    over_subtraction: f32,
+    floor: f32,
+    fft: Arc<dyn Fft<f32>>,
+    ifft: Arc<dyn Fft<f32>>,
+    window: Vec<f32>,
+    input: Vec<f32>,
+    overlap: Vec<f32>,
+    smoothed: Vec<f32>,
+    /// 当前子窗口内的最小值与已完成子窗口的最小值
+    current_min: Vec<f32>,
+    past_mins: VecDeque<Vec<f32>>,
+    frames_in_subwindow: usize,
+    frames: usize,
+    /// 开头补零带来的延迟，输出时丢弃
+    latency_left: usize,
+    consumed: u64,
+    produced: u64,
+}
+
+impl NoiseSuppressor {
+    fn new(config: &NoiseReductionConfig) -> Self {
+        let mut planner = FftPlanner::new();
+        let window = (0..FRAME_LEN)
+            .map(|n| {
+                let hann = 0.5 - 0.5 * (2.0 * PI * n as f64 / FRAME_LEN as f64).cos();
+                hann.sqrt() as f32
+            })
+            .collect();
+        Self {
+            over_subtraction: config.over_subtraction,
+            floor: 10_f32.powf(config.floor_db / 20.0),
+            fft: planner.plan_fft_forward(FRAME_LEN),
+            ifft: planner.plan_fft_inverse(FRAME_LEN),
+            window,
+            input: vec![0.0; FRAME_LEN - HOP_LEN],
+            overlap: vec![0.0; FRAME_LEN],
+            smoothed: vec![0.0; BINS],
+            current_min: vec![f32::INFINITY; BINS],
+            past_mins: VecDeque::with_capacity(MIN_SUBWINDOWS),
+            frames_in_subwindow: 0,
+            frames: 0,
+            latency_left: FRAME_LEN - HOP_LEN,
+            consumed: 0,
+            produced: 0,
+        }
+    }
+
+    fn process(&mut self, samples: &[f32]) -> Vec<f32> {
+        self.consumed += samples.len() as u64;
+        self.input.extend_from_slice(samples);
+        let mut output = Vec::with_capacity(samples.len() + HOP_LEN);
+        while self.input.len() >= FRAME_LEN {
+            self.process_frame(&mut output);
+        }
+        output
+    }
+
+    fn finish(&mut self) -> Vec<f32> {
+        let mut output = Vec::new();
+        while self.produced < self.consumed {
+            self.input.resize(self.input.len() + FRAME_LEN, 0.0);
+            while self.input.len() >= FRAME_LEN {
+                self.process_frame(&mut output);
+            }
+        }
+        let excess = (self.produced - self.consumed) as usize;
+        output.truncate(output.len() - excess);
+        self.produced = self.consumed;
+        output
+    }
+
+    fn process_frame(&mut self, output: &mut Vec<f32>) {
+        let mut spectrum: Vec<Complex<f32>> = self.input[..FRAME_LEN]
+            .iter()
+            .zip(&self.window)
+            .map(|(x, w)| Complex::new(x * w, 0.0))
+            .collect();
+        self.fft.process(&mut spectrum);
+
+        let powers: Vec<f32> = spectrum[..BINS].iter().map(|c| c.norm_sqr()).collect();
+        let noise = self.track_noise(&powers);
+        for (k, (&power, &noise)) in powers.iter().zip(&noise).enumerate() {
+            let gain = if power > 0.0 {
+                (1.0 - self.over_subtraction * noise / power)
+                    .max(0.0)
+                    .sqrt()
+                    .max(self.floor)
+            } else {
+                self.floor
+            };
+            spectrum[k] *= gain;
+            if k > 0 && k < FRAME_LEN / 2 {
+                spectrum[FRAME_LEN - k] *= gain;
+            }
+        }
+
+        self.ifft.process(&mut spectrum);
+        let scale = 1.0 / FRAME_LEN as f32;
+        for ((acc, value), w) in self.overlap.iter_mut().zip(&spectrum).zip(&self.window) {
+            *acc += value.re * scale * w;
+        }
+
+        let skip = self.latency_left.min(HOP_LEN);
+        self.latency_left -= skip;
+        output.extend_from_slice(&self.overlap[skip..HOP_LEN]);
+        self.produced += (HOP_LEN - skip) as u64;
+        self.overlap.drain(..HOP_LEN);
+        self.overlap.resize(FRAME_LEN, 0.0);
+        self.input.drain(..HOP_LEN);
+    }
+
+    /// 平滑功率谱在最近约 1.5 秒内的最小值作为噪声估计，噪声变大时最多 1.5 秒跟上
+    fn track_noise(&mut self, powers: &[f32]) -> Vec<f32> {
+        for (k, &power) in powers.iter().enumerate() {
+            self.smoothed[k] = if self.frames == 0 {
+                power
+            } else {
+                PSD_SMOOTHING * self.smoothed[k] + (1.0 - PSD_SMOOTHING) * power
+            };
+            self.current_min[k] = self.current_min[k].min(self.smoothed[k]);
+        }
+        self.frames += 1;
+
+        let noise = (0..BINS)
+            .map(|k| {
+                self.past_mins
+                    .iter()
+                    .map(|mins| mins[k])
+                    .fold(self.current_min[k], f32::min)
+                    * MIN_BIAS
+            })
+            .collect();
+
+        self.frames_in_subwindow += 1;
+        if self.frames_in_subwindow == MIN_SUBWINDOW_FRAMES {
+            if self.past_mins.len() == MIN_SUBWINDOWS {
+                self.past_mins.pop_front();
+            }
+            let finished = std::mem::replace(&mut self.current_min, vec![f32::INFINITY; BINS]);
+            self.past_mins.push_back(finished);
+            self.frames_in_subwindow = 0;
+        }
+        noise
+    }
+}
+
+/// 响度归一化：每 100ms 按最近一段时间（跳过静音块）的响度计算目标增益，
+/// 下一个块内线性过渡，没有延迟
+struct LoudnessNormalizer {
+    target_db: f64,
+    max_gain_db: f64,
+    /// LUFS 测量用的 K 加权滤波器
+    weighting: Vec<Biquad>,
+    offset_db: f64,
+    window_blocks: usize,
+    blocks: VecDeque<f64>,
+    block_sum: f64,
+    block_len: usize,
+    gain: f32,
+    step: f32,
+    ramp_left: usize,
+}
+
+impl LoudnessNormalizer {
+    fn new(config: &LoudnessConfig) -> Self {
+        let (weighting, offset_db) = match config.measure {
+            LoudnessMeasure::Rms => (Vec::new(), 0.0),
+            // ITU-R BS.1770：高频搁架 + RLB 高通，响度 = -0.691 + 10·log10(均方)
+            LoudnessMeasure::Lufs => (
+                vec![
+                    Biquad::high_shelf(1_681.974, 3.999_844, 0.707_175),
+                    Biquad::high_pass(38.135_47, 0.500_327),
+                ],
+                -0.691,
+            ),
+        };
+        Self {
+            target_db: config.target_db as f64,
+            max_gain_db: config.max_gain_db as f64,
+            weighting,
+            offset_db,
+            window_blocks: (config.window_ms as usize * 16 / LOUDNESS_BLOCK).max(1),
+            blocks: VecDeque::new(),
+            block_sum: 0.0,
+            block_len: 0,
+            gain: 1.0,
+            step: 0.0,
+            ramp_left: 0,
+        }
+    }
+
+    fn process(&mut self, samples: &mut [f32]) {
+        for sample in samples.iter_mut() {
+            let mut weighted = *sample as f64;
+            for biquad in &mut self.weighting {
+                weighted = biquad.process(weighted);
+            }
+            self.block_sum += weighted * weighted;
+            self.block_len += 1;
+
+            *sample = (*sample * self.gain).clamp(-1.0, 1.0);
+            if self.ramp_left > 0 {
+                self.gain += self.step;
+                self.ramp_left -= 1;
+            }
+
+            if self.block_len == LOUDNESS_BLOCK {
+                self.end_block();
+            }
+        }
+    }
+
+    fn end_block(&mut self) {
+        let mean_square = self.block_sum / LOUDNESS_BLOCK as f64;
+        self.block_sum = 0.0;
+        self.block_len = 0;
+        if to_db(mean_square) < SILENCE_GATE_DB {
+            return;
+        }
+
+        if self.blocks.len() == self.window_blocks {
+            self.blocks.pop_front();
+        }
+        self.blocks.push_back(mean_square);
+        let mean = self.blocks.iter().sum::<f64>() / self.blocks.len() as f64;
+        let level = to_db(mean) + self.offset_db;
+        let gain_db = (self.target_db - level).clamp(-self.max_gain_db, self.max_gain_db);
+        let target = 10_f64.powf(gain_db / 20.0) as f32;
+        self.step = (target - self.gain) / LOUDNESS_BLOCK as f32;
+        self.ramp_left = LOUDNESS_BLOCK;
+    }
+}
+
+fn to_db(mean_square: f64) -> f64 {
+    10.0 * mean_square.max(1e-20).log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(freq: f64, amplitude: f64, samples: usize) -> Vec<f32> {
+        (0..samples)
+            .map(|i| {
+                let cycles = (freq * i as f64 / SAMPLE_RATE).fract();
+                (amplitude * (2.0 * PI * cycles).sin()) as f32
+            })
+            .collect()
+    }
+
+    /// 确定性的均匀白噪声
+    fn noise(amplitude: f32, samples: usize) -> Vec<f32> {
+        let mut state = 0x2545_f491_u32;
+        (0..samples)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state as f32 / u32::MAX as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|x| x * x).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    fn db(ratio: f32) -> f32 {
+        20.0 * ratio.log10()
+    }
+
+    fn only(config: PreprocessConfig) -> Preprocessor {
+        Preprocessor::new(&config).unwrap()
+    }
+
+    fn run(chain: &mut Preprocessor, input: &[f32]) -> Vec<f32> {
+        let mut output = Vec::new();
+        for chunk in input.chunks(3_000) {
+            output.extend(chain.process(chunk));
+        }
+        output.extend(chain.finish());
+        output
+    }
+
+    fn bare() -> PreprocessConfig {
+        PreprocessConfig {
+            remove_dc: false,
+            high_pass_hz: 0,
+            noise_reduction: None,
+            loudness: None,
+        }
+    }
+
+    #[test]
+    fn test_dc_offset_removed() {
+        let input: Vec<f32> = tone(440.0, 0.2, 32_000).iter().map(|x| x + 0.3).collect();
+        let output = run(
+            &mut only(PreprocessConfig {
+                remove_dc: true,
+                ..bare()
+            }),
+            &input,
+        );
+        let tail = &output[16_000..];
+        let mean = tail.iter().sum::<f32>() / tail.len() as f32;
+        assert!(mean.abs() < 1e-3, "mean {}", mean);
+    }
+
+    #[test]
+    fn test_high_pass_removes_hum() {
+        let hum = tone(50.0, 0.3, 32_000);
+        let voice = tone(1_000.0, 0.3, 32_000);
+        let config = PreprocessConfig {
+            high_pass_hz: 100,
+            ..bare()
+        };
+
+        let hum_out = run(&mut only(config.clone()), &hum);
+        let voice_out = run(&mut only(config), &voice);
+
+        assert!(db(rms(&hum_out[8_000..]) / rms(&hum)) < -20.0);
+        assert!(db(rms(&voice_out[8_000..]) / rms(&voice)).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_loudness_reaches_target() {
+        for measure in [LoudnessMeasure::Rms, LoudnessMeasure::Lufs] {
+            // 1kHz 处 K 加权增益约为 0dB，两种测量结果应接近
+            let input = tone(1_000.0, 0.01, 80_000);
+            let output = run(
+                &mut only(PreprocessConfig {
+                    loudness: Some(LoudnessConfig {
+                        measure,
+                        target_db: -20.0,
+                        max_gain_db: 30.0,
+                        window_ms: 1_000,
+                    }),
+                    ..bare()
+                }),
+                &input,
+            );
+            let level = db(rms(&output[48_000..]));
+            assert!((level + 20.0).abs() < 1.0, "{:?}: {:.2} dB", measure, level);
+        }
+    }
+
+    #[test]
+    fn test_loudness_respects_gain_limit_and_silence() {
+        let config = PreprocessConfig {
+            loudness: Some(LoudnessConfig {
+                measure: LoudnessMeasure::Rms,
+                target_db: -20.0,
+                max_gain_db: 6.0,
+                window_ms: 1_000,
+            }),
+            ..bare()
+        };
+
+        let quiet = tone(1_000.0, 0.01, 48_000);
+        let output = run(&mut only(config.clone()), &quiet);
+        assert!((db(rms(&output[32_000..]) / rms(&quiet)) - 6.0).abs() < 0.1);
+
+        let hiss = noise(0.001, 32_000);
+        let output = run(&mut only(config), &hiss);
+        assert_eq!(output, hiss);
+    }
+
+    #[test]
+    fn test_spectral_subtraction_improves_snr() {
+        let len = 64_000;
+        // 间断的音调模拟语音：持续不变的音调在最小值跟踪看来就是噪声
+        let clean: Vec<f32> = tone(1_000.0, 0.3, len)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| if i % 16_000 < 6_400 { x } else { 0.0 })
+            .collect();
+        let hiss = noise(0.05, len);
+        let noisy: Vec<f32> = clean.iter().zip(&hiss).map(|(c, n)| c + n).collect();
+
+        let output = run(
+            &mut only(PreprocessConfig {
+                noise_reduction: Some(NoiseReductionConfig::default()),
+                ..bare()
+            }),
+            &noisy,
+        );
+        assert_eq!(output.len(), len);
+
+        let residual = |signal: &[f32]| -> Vec<f32> {
+            signal[24_000..]
+                .iter()
+                .zip(&clean[24_000..])
+                .map(|(s, c)| s - c)
+                .collect()
+        };
+        let before = db(rms(&clean[24_000..]) / rms(&residual(&noisy)));
+        let after = db(rms(&clean[24_000..]) / rms(&residual(&output)));
+        assert!(after - before > 6.0, "SNR {:.1} -> {:.1} dB", before, after);
+    }
+
+    #[test]
+    fn test_spectral_subtraction_keeps_alignment() {
+        // 没有噪声时输出应与输入基本一致，且不产生时移
+        let input = tone(440.0, 0.5, 20_000);
+        let mut chain = only(PreprocessConfig {
+            noise_reduction: Some(NoiseReductionConfig {
+                over_subtraction: 1e-6,
+                floor_db: 0.0,
+            }),
+            ..bare()
+        });
+        let output = run(&mut chain, &input);
+        assert_eq!(output.len(), input.len());
+        let max_error = output[1_000..19_000]
+            .iter()
+            .zip(&input[1_000..19_000])
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f32::max);
+        assert!(max_error < 1e-3, "max error {}", max_error);
+    }
+
+    #[test]
+    fn test_stream_chunking_does_not_change_output() {
+        let input: Vec<f32> = tone(300.0, 0.2, 20_000)
+            .iter()
+            .zip(noise(0.02, 20_000))
+            .map(|(t, n)| t + n + 0.1)
+            .collect();
+        let config = PreprocessConfig::default();
+
+        let mut whole = only(config.clone());
+        let mut expected = whole.process(&input);
+        expected.extend(whole.finish());
+
+        let chunks = input.chunks(777).map(|c| Ok(c.to_vec()));
+        let streamed: Vec<f32> = PreprocessStream::new(chunks, Some(&config))
+            .unwrap()
+            .flat_map(|chunk| chunk.unwrap())
+            .collect();
+        assert_eq!(streamed.len(), input.len());
+        assert_eq!(streamed, expected);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let config = PreprocessConfig {
+            noise_reduction: Some(NoiseReductionConfig {
+                over_subtraction: 0.0,
+                floor_db: -20.0,
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_config(&config),
+            Err(AudioError::InvalidConfig(_))
+        ));
+    }
+}
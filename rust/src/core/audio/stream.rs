@@ -0,0 +1,450 @@
+//! 流式音频读取 - 分块读取 WAV、分块重采样，峰值内存与音频时长无关
+
+use super::decimator::PolyphaseDecimator;
+use super::error::AudioError;
+use super::vad::{SpeechSegment, VadHandle};
+use crate::api::models::asr::ResampleQuality;
+use log::{debug, error, info};
+use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType};
+use std::fs::File;
+use std::io::BufReader;
+
+/// 每次从 WAV 读取的时长（秒）
+const READ_CHUNK_SECS: usize = 1;
+/// Sinc 重采样器每次处理的输入帧数
+const RESAMPLE_CHUNK: usize = 1024;
+/// 流式处理时 16kHz 音频的分块大小
+pub const STREAM_CHUNK: usize = 16_000;
+
+/// 分块读取 WAV，多声道取平均混为单声道
+pub struct WavChunkReader {
+    reader: hound::WavReader<BufReader<File>>,
+    spec: hound::WavSpec,
+    /// 整型样本的满幅值
+    full_scale: f32,
+}
+
+impl WavChunkReader {
+    pub fn open(path: &str) -> Result<Self, AudioError> {
+        let reader = hound::WavReader::open(path)?;
+        let spec = reader.spec();
+        info!(
+            "📊 WAV spec: {} Hz, {} channels, {} bits",
+            spec.sample_rate, spec.channels, spec.bits_per_sample
+        );
+
+        if spec.sample_rate == 0 || spec.channels == 0 {
+            return Err(AudioError::Wav(hound::Error::FormatError(
+                "invalid sample rate or channel count",
+            )));
+        }
+        let full_scale = match (spec.sample_format, spec.bits_per_sample) {
+            (hound::SampleFormat::Int, bits @ 8..=32) => ((1_i64 << (bits - 1)) - 1) as f32,
+            (hound::SampleFormat::Float, 32) => 1.0,
+            _ => return Err(AudioError::Wav(hound::Error::Unsupported)),
+        };
+
+        Ok(Self {
+            reader,
+            spec,
+            full_scale,
+        })
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.spec.sample_rate
+    }
+
+    /// 按文件头计算的时长（秒）
+    pub fn duration_secs(&self) -> f32 {
+        self.reader.duration() as f32 / self.spec.sample_rate as f32
+    }
+
+    /// 读取最多 `max_frames` 帧，文件读完时返回 None
+    pub fn next_chunk(&mut self, max_frames: usize) -> Result<Option<Vec<f32>>, AudioError> {
+        let channels = self.spec.channels as usize;
+        let wanted = max_frames * channels;
+        let interleaved: Vec<f32> = match self.spec.sample_format {
+            hound::SampleFormat::Int => {
+                let scale = self.full_scale;
+                self.reader
+                    .samples::<i32>()
+                    .take(wanted)
+                    .map(|s| s.map(|v| v as f32 / scale))
+                    .collect::<Result<_, _>>()?
+            }
+            hound::SampleFormat::Float => self
+                .reader
+                .samples::<f32>()
+                .take(wanted)
+                .collect::<Result<_, _>>()?,
+        };
+        if interleaved.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            interleaved
+                .chunks_exact(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+                .collect(),
+        ))
+    }
+}
+
+/// 分块重采样到 16kHz，内部状态跨块保留，分块结果与整体重采样一致
+pub struct StreamingResampler {
+    mode: ResampleMode,
+}
+
+enum ResampleMode {
+    Passthrough,
+    /// 整数倍降采样
+    Decimate(PolyphaseDecimator),
+    Sinc(Box<SincStream>),
+}
+
+struct SincStream {
+    resampler: SincFixedIn<f32>,
+    ratio: f64,
+    pending: Vec<f32>,
+    /// 重采样器的固有延迟，输出开头需要丢弃的帧数
+    delay_left: usize,
+    consumed: u64,
+    produced: u64,
+}
+
+impl StreamingResampler {
+    pub fn new(in_rate: u32, quality: ResampleQuality) -> Result<Self, AudioError> {
+        let mode = match in_rate {
+            0 => {
+                return Err(AudioError::Resample("invalid sample rate 0".to_string()));
+            }
+            16_000 => ResampleMode::Passthrough,
+            rate if rate % 16_000 == 0 => {
+                let factor = (rate / 16_000) as usize;
+                info!(
+                    "⚡ Polyphase downsample from {} Hz to 16 kHz (factor {}, {:?})",
+                    rate, factor, quality
+                );
+                ResampleMode::Decimate(PolyphaseDecimator::new(factor, quality))
+            }
+            rate => {
+                info!("🔧 Resampling from {} Hz to 16 kHz", rate);
+                let ratio = 16_000.0 / rate as f64;
+                let (sinc_len, f_cutoff) = match quality {
+                    ResampleQuality::Fast => (24, 0.85),
+                    ResampleQuality::Balanced => (48, 0.90),
+                    ResampleQuality::High => (128, 0.95),
+                };
+                let params = SincInterpolationParameters {
+                    sinc_len,
+                    f_cutoff,
+                    interpolation: SincInterpolationType::Cubic,
+                    oversampling_factor: 4,
+                    window: rubato::WindowFunction::BlackmanHarris2,
+                };
+                let resampler = SincFixedIn::<f32>::new(ratio, 1.0, params, RESAMPLE_CHUNK, 1)
+                    .map_err(|e| {
+                        error!("❌ Resample creation failed: {}", e);
+                        AudioError::Resample(e.to_string())
+                    })?;
+                ResampleMode::Sinc(Box::new(SincStream {
+                    delay_left: resampler.output_delay(),
+                    resampler,
+                    ratio,
+                    pending: Vec::with_capacity(RESAMPLE_CHUNK),
+                    consumed: 0,
+                    produced: 0,
+                }))
+            }
+        };
+        Ok(Self { mode })
+    }
+
+    /// 处理一块输入，返回已经可以输出的 16kHz 样本
+    pub fn process(&mut self, input: &[f32]) -> Result<Vec<f32>, AudioError> {
+        match &mut self.mode {
+            ResampleMode::Passthrough => Ok(input.to_vec()),
+            ResampleMode::Decimate(decimator) => Ok(decimator.process(input)),
+            ResampleMode::Sinc(stream) => stream.process(input),
+        }
+    }
+
+    /// 输入结束，输出剩余样本
+    pub fn finish(&mut self) -> Result<Vec<f32>, AudioError> {
+        match &mut self.mode {
+            ResampleMode::Passthrough => Ok(Vec::new()),
+            ResampleMode::Decimate(decimator) => Ok(decimator.finish()),
+            ResampleMode::Sinc(stream) => stream.finish(),
+        }
+    }
+}
+
+impl SincStream {
+    fn process(&mut self, mut input: &[f32]) -> Result<Vec<f32>, AudioError> {
+        self.consumed += input.len() as u64;
+        let mut output = Vec::with_capacity((input.len() as f64 * self.ratio) as usize + 16);
+        while !input.is_empty() {
+            let take = (RESAMPLE_CHUNK - self.pending.len()).min(input.len());
+            self.pending.extend_from_slice(&input[..take]);
+            input = &input[take..];
+            if self.pending.len() == RESAMPLE_CHUNK {
+                let frames = self
+                    .resampler
+                    .process(&[&self.pending], None)
+                    .map_err(resample_error)?;
+                self.pending.clear();
+                self.emit(&frames[0], &mut output);
+            }
+        }
+        Ok(output)
+    }
+
+    fn finish(&mut self) -> Result<Vec<f32>, AudioError> {
+        let expected = (self.consumed as f64 * self.ratio).round() as u64;
+        let mut output = Vec::new();
+        if !self.pending.is_empty() {
+            let frames = self
+                .resampler
+                .process_partial(Some(&[&self.pending]), None)
+                .map_err(resample_error)?;
+            self.pending.clear();
+            self.emit(&frames[0], &mut output);
+        }
+        // 补零冲刷延迟中的样本
+        while self.produced < expected {
+            let frames = self
+                .resampler
+                .process_partial::<&[f32]>(None, None)
+                .map_err(resample_error)?;
+            if frames[0].is_empty() {
+                break;
+            }
+            self.emit(&frames[0], &mut output);
+        }
+        let excess = self.produced.saturating_sub(expected) as usize;
+        output.truncate(output.len().saturating_sub(excess));
+        self.produced -= excess as u64;
+        debug!(
+            "✓ Streaming resample complete: {} -> {} samples",
+            self.consumed, self.produced
+        );
+        Ok(output)
+    }
+
+    fn emit(&mut self, frames: &[f32], output: &mut Vec<f32>) {
+        let skip = self.delay_left.min(frames.len());
+        self.delay_left -= skip;
+        output.extend_from_slice(&frames[skip..]);
+        self.produced += (frames.len() - skip) as u64;
+    }
+}
+
+fn resample_error(e: rubato::ResampleError) -> AudioError {
+    error!("❌ Resample processing failed: {}", e);
+    AudioError::Resample(e.to_string())
+}
+
+/// 把 WAV 文件读成 16kHz 单声道分块流
+pub struct Wav16kStream {
+    reader: WavChunkReader,
+    resampler: StreamingResampler,
+    done: bool,
+}
+
+impl Wav16kStream {
+    pub fn open(path: &str, quality: ResampleQuality) -> Result<Self, AudioError> {
+        let reader = WavChunkReader::open(path)?;
+        let resampler = StreamingResampler::new(reader.sample_rate(), quality)?;
+        Ok(Self {
+            reader,
+            resampler,
+            done: false,
+        })
+    }
+}
+
+impl Iterator for Wav16kStream {
+    type Item = Result<Vec<f32>, AudioError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let frames = self.reader.sample_rate() as usize * READ_CHUNK_SECS;
+        match self.reader.next_chunk(frames) {
+            Ok(Some(chunk)) => Some(self.resampler.process(&chunk)),
+            Ok(None) => {
+                self.done = true;
+                Some(self.resampler.finish())
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// 滑动的 16kHz 音频窗口，只保留尚未处理完的部分
+#[derive(Default)]
+pub struct PcmWindow {
+    /// 窗口第一个样本在整段音频中的位置
+    start: usize,
+    samples: Vec<f32>,
+}
+
+impl PcmWindow {
+    pub fn append(&mut self, chunk: &[f32]) {
+        self.samples.extend_from_slice(chunk);
+    }
+
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    pub fn start_secs(&self) -> f32 {
+        self.start as f32 / 16_000.0
+    }
+
+    pub fn end_secs(&self) -> f32 {
+        (self.start + self.samples.len()) as f32 / 16_000.0
+    }
+
+    /// 取出片段（绝对时间）对应的样本，超出窗口的部分被截掉
+    pub fn extract(&self, segment: &SpeechSegment) -> Vec<f32> {
+        let base = self.start_secs();
+        let relative = SpeechSegment::new(segment.start - base, segment.end - base);
+        VadHandle::extract_segment(&self.samples, 16_000, &relative)
+    }
+
+    /// 丢弃 `secs` 之前的样本；可丢弃的部分不足窗口一半时先不动，摊平搬移开销
+    pub fn trim_before(&mut self, secs: f32) {
+        let target = (secs.max(0.0) * 16_000.0) as usize;
+        let drop = target.saturating_sub(self.start).min(self.samples.len());
+        if drop == 0 || drop * 2 < self.samples.len() {
+            return;
+        }
+        self.samples.drain(..drop);
+        self.start += drop;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+
+    fn write_wav(name: &str, spec: hound::WavSpec, frames: usize) -> String {
+        let path = std::env::temp_dir().join(format!(
+            "cook_lib_stream_{}_{}_{}.wav",
+            name,
+            std::process::id(),
+            NEXT_FILE.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for i in 0..frames {
+            let v = ((i as f32 * 0.05).sin() * 10_000.0) as i32;
+            for channel in 0..spec.channels {
+                let sample = if channel == 0 { v } else { -v / 2 };
+                match spec.bits_per_sample {
+                    16 => writer.write_sample(sample as i16).unwrap(),
+                    _ => writer.write_sample(sample << 8).unwrap(),
+                }
+            }
+        }
+        writer.finalize().unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn spec(channels: u16, sample_rate: u32, bits: u16) -> hound::WavSpec {
+        hound::WavSpec {
+            channels,
+            sample_rate,
+            bits_per_sample: bits,
+            sample_format: hound::SampleFormat::Int,
+        }
+    }
+
+    fn collect(stream: Wav16kStream) -> Vec<f32> {
+        stream.flat_map(|chunk| chunk.unwrap()).collect()
+    }
+
+    #[test]
+    fn test_reader_downmixes_in_chunks() {
+        let path = write_wav("stereo", spec(2, 16_000, 16), 40_000);
+        let mut reader = WavChunkReader::open(&path).unwrap();
+        assert!((reader.duration_secs() - 2.5).abs() < 1e-6);
+
+        let mut lengths = Vec::new();
+        while let Some(chunk) = reader.next_chunk(16_000).unwrap() {
+            lengths.push(chunk.len());
+        }
+        assert_eq!(lengths, vec![16_000, 16_000, 8_000]);
+
+        let mono = collect(Wav16kStream::open(&path, ResampleQuality::default()).unwrap());
+        let expected = ((5.0_f32).sin() * 10_000.0) as i32;
+        let expected = (expected - expected / 2) as f32 / 2.0 / i16::MAX as f32;
+        assert!((mono[100] - expected).abs() < 1e-4);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_reader_handles_24bit_multichannel() {
+        let path = write_wav("24bit", spec(3, 16_000, 24), 1_000);
+        let mono = collect(Wav16kStream::open(&path, ResampleQuality::default()).unwrap());
+        assert_eq!(mono.len(), 1_000);
+        assert!(mono.iter().all(|s| s.abs() <= 1.0));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_streaming_sinc_matches_expected_length() {
+        let input: Vec<f32> = (0..44_100 * 3).map(|i| (i as f32 * 0.01).sin()).collect();
+        let mut resampler = StreamingResampler::new(44_100, ResampleQuality::default()).unwrap();
+        let mut output = Vec::new();
+        for chunk in input.chunks(7_777) {
+            output.extend(resampler.process(chunk).unwrap());
+        }
+        output.extend(resampler.finish().unwrap());
+        assert_eq!(output.len(), 48_000);
+
+        // 分块大小不影响结果
+        let mut whole = StreamingResampler::new(44_100, ResampleQuality::default()).unwrap();
+        let mut reference = whole.process(&input).unwrap();
+        reference.extend(whole.finish().unwrap());
+        assert_eq!(reference.len(), output.len());
+        assert!(reference
+            .iter()
+            .zip(&output)
+            .all(|(a, b)| (a - b).abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_streaming_decimate_carries_state() {
+        let input = vec![0.5_f32; 4_801];
+        let mut resampler = StreamingResampler::new(48_000, ResampleQuality::Fast).unwrap();
+        let mut output = resampler.process(&input[..1_000]).unwrap();
+        output.extend(resampler.process(&input[1_000..]).unwrap());
+        output.extend(resampler.finish().unwrap());
+        assert_eq!(output.len(), 1_601);
+        assert!((output[800] - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_pcm_window_trims_lazily() {
+        let mut window = PcmWindow::default();
+        window.append(&vec![0.0; 32_000]);
+        window.trim_before(0.5);
+        assert_eq!(window.start_secs(), 0.0);
+
+        window.trim_before(1.5);
+        assert_eq!(window.start_secs(), 1.5);
+        assert_eq!(window.samples().len(), 8_000);
+        assert_eq!(window.end_secs(), 2.0);
+        assert_eq!(window.extract(&SpeechSegment::new(1.75, 3.0)).len(), 4_000);
+    }
+}
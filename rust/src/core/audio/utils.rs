@@ -1,127 +1,80 @@
 use super::error::AudioError;
-use log::{error, info};
-use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType};
+use super::stream::{StreamingResampler, Wav16kStream};
+use crate::api::models::asr::ResampleQuality;
+use log::info;
 
+/// 读取整个 WAV 文件为 16kHz 单声道样本，内部按块解码和重采样
 pub fn load_wav_mono_f32(path: &str) -> Result<Vec<f32>, AudioError> {
     info!("📖 Reading WAV file: {}", path);
-    let mut reader = hound::WavReader::open(path)?;
-    let spec = reader.spec();
-    let channels = spec.channels.max(1) as usize;
-    let estimated_samples = reader.duration() as usize / channels;
-    let mut samples = Vec::with_capacity(estimated_samples.max(128));
-
-    info!(
-        "📊 WAV spec: {} Hz, {} channels, {} bits",
-        spec.sample_rate, spec.channels, spec.bits_per_sample
-    );
-
-    if spec.sample_rate == 0 {
-        return Err(AudioError::Wav(hound::Error::FormatError(
-            "invalid sample rate".into(),
-        )));
-    }
-
-    if spec.channels == 1 {
-        for s in reader.samples::<i16>() {
-            let v = s? as f32 / i16::MAX as f32;
-            samples.push(v);
-        }
-    } else {
-        let mut iter = reader.samples::<i16>();
-        loop {
-            let l = match iter.next() {
-                Some(v) => v?,
-                None => break,
-            };
-            let r = match iter.next() {
-                Some(v) => v?,
-                None => break,
-            };
-            let m = ((l as f32 + r as f32) * 0.5) / i16::MAX as f32;
-            samples.push(m);
-        }
+    let mut samples = Vec::new();
+    for chunk in Wav16kStream::open(path, ResampleQuality::default())? {
+        samples.extend(chunk?);
     }
-
     info!("✓ Loaded {} mono samples from file", samples.len());
-
-    if spec.sample_rate != 16_000 {
-        Ok(resample_to_16k_mono(&samples, spec.sample_rate)?)
-    } else {
-        Ok(samples)
-    }
+    Ok(samples)
 }
 
-pub fn resample_to_16k_mono(input: &[f32], in_rate: u32) -> Result<Vec<f32>, AudioError> {
-    if in_rate == 16_000 {
-        return Ok(input.to_vec());
-    }
-
-    if in_rate % 16_000 == 0 {
-        let factor = (in_rate / 16_000) as usize;
+pub fn resample_to_16k_mono(
+    input: &[f32],
+    in_rate: u32,
+    quality: ResampleQuality,
+) -> Result<Vec<f32>, AudioError> {
+    let mut resampler = StreamingResampler::new(in_rate, quality)?;
+    let mut output = resampler.process(input)?;
+    output.extend(resampler.finish()?);
+    if in_rate != 16_000 {
         info!(
-            "⚡ Fast downsample from {} Hz to 16 kHz (factor {})",
-            in_rate, factor
+            "✓ Resampling complete: {} -> {} samples",
+            input.len(),
+            output.len()
         );
-        return Ok(downsample_by_factor(input, factor));
     }
+    Ok(output)
+}
 
-    info!(
-        "🔧 Resampling {} samples from {} Hz to 16 kHz",
-        input.len(),
-        in_rate
-    );
-    let ratio = 16_000.0 / in_rate as f64;
-    let params = SincInterpolationParameters {
-        sinc_len: 48,
-        f_cutoff: 0.90,
-        interpolation: SincInterpolationType::Cubic,
-        oversampling_factor: 4,
-        window: rubato::WindowFunction::BlackmanHarris2,
-    };
+/// 16 位整型 PCM 转为 [-1.0, 1.0] 的浮点样本
+pub fn i16_to_f32(samples: &[i16]) -> Vec<f32> {
+    samples
+        .iter()
+        .map(|&s| s as f32 / i16::MAX as f32)
+        .collect()
+}
 
-    let mut resampler =
-        SincFixedIn::<f32>::new(ratio, 1.0, params, input.len(), 1).map_err(|e| {
-            error!("❌ Resample creation failed: {}", e);
-            AudioError::Resample(e.to_string())
-        })?;
+/// 交错排列的多声道样本取各声道平均值混为单声道，末尾不完整的帧被丢弃
+pub fn downmix_to_mono(samples: &[f32], channels: u16) -> Result<Vec<f32>, AudioError> {
+    match channels {
+        0 => Err(AudioError::InvalidInput(
+            "channels must be at least 1".to_string(),
+        )),
+        1 => Ok(samples.to_vec()),
+        n => Ok(samples
+            .chunks_exact(n as usize)
+            .map(|frame| frame.iter().sum::<f32>() / n as f32)
+            .collect()),
+    }
+}
 
-    let mut output = vec![vec![0.0f32; input.len() * 2]];
-    resampler
-        .process_into_buffer(&[input], &mut output, None)
-        .map_err(|e| {
-            error!("❌ Resample processing failed: {}", e);
-            AudioError::Resample(e.to_string())
-        })?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let result: Vec<f32> = output.into_iter().flatten().collect();
-    info!(
-        "✓ Resampling complete: {} -> {} samples",
-        input.len(),
-        result.len()
-    );
-    Ok(result)
-}
+    #[test]
+    fn test_downmix_to_mono() {
+        let stereo = [0.5, -0.5, 1.0, 0.0, 0.2];
+        assert_eq!(downmix_to_mono(&stereo, 2).unwrap(), vec![0.0, 0.5]);
 
-/// Quickly downsample by averaging consecutive frames when the ratio is an integer
-fn downsample_by_factor(input: &[f32], factor: usize) -> Vec<f32> {
-    debug_assert!(factor > 0);
-    let mut output = Vec::with_capacity((input.len() + factor - 1) / factor);
-    let mut accumulator = 0.0_f32;
-    let mut count = 0;
+        let surround = [0.3, 0.3, 0.3, 0.6, 0.0, 0.0];
+        let mono = downmix_to_mono(&surround, 3).unwrap();
+        assert!((mono[0] - 0.3).abs() < 1e-6 && (mono[1] - 0.2).abs() < 1e-6);
 
-    for &sample in input {
-        accumulator += sample;
-        count += 1;
-        if count == factor {
-            output.push(accumulator / factor as f32);
-            accumulator = 0.0;
-            count = 0;
-        }
+        assert!(matches!(
+            downmix_to_mono(&stereo, 0),
+            Err(AudioError::InvalidInput(_))
+        ));
     }
 
-    if count > 0 {
-        output.push(accumulator / count as f32);
+    #[test]
+    fn test_i16_to_f32() {
+        assert_eq!(i16_to_f32(&[0, i16::MAX, -i16::MAX]), vec![0.0, 1.0, -1.0]);
     }
-
-    output
 }
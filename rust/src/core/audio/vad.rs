@@ -3,8 +3,9 @@
 //! Uses the real Silero VAD model for accurate speech detection.
 
 use super::error::AudioError;
+use crate::api::models::asr::VadConfig;
 use log::{debug, info};
-use sherpa_ncnn::{Vad, VadConfig};
+use sherpa_ncnn::{Vad, VadConfig as SherpaVadConfig};
 
 /// Chunk size for VAD processing (16000 samples = 1 second at 16kHz)
 const VAD_CHUNK_SIZE: usize = 16000;
@@ -13,25 +14,49 @@ const VAD_CHUNK_SIZE: usize = 16000;
 pub struct SpeechSegment {
     pub start: f32,
     pub end: f32,
+    /// 说话人编号，未做说话人分离时为 None
+    pub speaker: Option<u32>,
+}
+
+impl SpeechSegment {
+    pub fn new(start: f32, end: f32) -> Self {
+        Self {
+            start,
+            end,
+            speaker: None,
+        }
+    }
 }
 
 /// VAD 实例（非全局，由 RecipeProcessor 持有）
 pub struct VadHandle {
     vad: Vad,
+    config: VadConfig,
+    /// 当前流已送入的样本数
+    fed: usize,
 }
 
 impl VadHandle {
     /// Initialize VAD with Silero model
-    pub fn new(model_path: &str) -> Result<Self, AudioError> {
+    pub fn new(model_path: &str, config: &VadConfig) -> Result<Self, AudioError> {
         info!("🔧 Initializing Silero VAD with model: {}", model_path);
+        validate_config(config)?;
 
-        let config = VadConfig::new(model_path).with_num_threads(2);
-        let vad = Vad::new(config, 60.0).map_err(|e| {
+        let sherpa_config = SherpaVadConfig::new(model_path)
+            .with_num_threads(config.num_threads as i32)
+            .with_threshold(config.threshold)
+            .with_min_silence_duration(config.min_silence_duration_ms as f32 / 1000.0)
+            .with_min_speech_duration(config.min_speech_duration_ms as f32 / 1000.0);
+        let vad = Vad::new(sherpa_config, config.buffer_size_secs).map_err(|e| {
             AudioError::SherpaNcnn(format!("Failed to create VAD: {}", e))
         })?;
 
         info!("✅ Silero VAD initialized successfully");
-        Ok(Self { vad })
+        Ok(Self {
+            vad,
+            config: config.clone(),
+            fed: 0,
+        })
     }
 
     /// Detect speech segments using Silero VAD
@@ -47,51 +72,20 @@ impl VadHandle {
             )));
         }
 
-        let total_samples = samples.len();
-        let duration_secs = total_samples as f32 / sample_rate as f32;
-        info!("🔍 Running Silero VAD on {:.1}s audio ({} samples)", duration_secs, total_samples);
-
-        // Reset VAD state for new audio
-        self.vad.reset();
-        self.vad.clear();
-
-        // Feed audio in larger chunks for efficiency
-        let num_chunks = (total_samples + VAD_CHUNK_SIZE - 1) / VAD_CHUNK_SIZE;
-        debug!("📦 Processing {} chunks of up to {} samples", num_chunks, VAD_CHUNK_SIZE);
-
-        for i in 0..num_chunks {
-            let start = i * VAD_CHUNK_SIZE;
-            let end = (start + VAD_CHUNK_SIZE).min(total_samples);
-            let chunk = &samples[start..end];
-            self.vad.accept_waveform(chunk);
-        }
-
-        // Flush to detect the last segment
-        self.vad.flush();
-
-        // Collect all speech segments
-        let raw_segments = self.vad.get_all_segments();
         let total_duration = samples.len() as f32 / sample_rate as f32;
+        info!(
+            "🔍 Running Silero VAD on {:.1}s audio ({} samples)",
+            total_duration,
+            samples.len()
+        );
 
+        self.begin_stream();
+        let mut raw_segments = self.accept(samples);
+        raw_segments.extend(self.finish_stream());
         info!("📊 Raw VAD segments: {}", raw_segments.len());
 
-        // Convert to time-based segments
-        let segments: Vec<SpeechSegment> = raw_segments
-            .into_iter()
-            .map(|seg| {
-                let start = seg.start as f32 / sample_rate as f32;
-                let end = (seg.start + seg.samples.len() as i32) as f32 / sample_rate as f32;
-                SpeechSegment {
-                    start,
-                    end: end.min(total_duration),
-                }
-            })
-            .collect();
-
-        info!(
-            "✅ Silero VAD: {} speech segments found",
-            segments.len()
-        );
+        let segments = postprocess_segments(raw_segments, total_duration, &self.config);
+        info!("✅ Silero VAD: {} speech segments found", segments.len());
 
         for (i, seg) in segments.iter().enumerate() {
             debug!(
@@ -103,18 +97,67 @@ impl VadHandle {
             );
         }
 
-        // Fallback if no segments detected
+        // 未检测到语音时返回空列表，由调用方按回退策略处理
         if segments.is_empty() {
-            info!("⚠️  No speech detected, using full audio as single segment");
-            return Ok(vec![SpeechSegment {
-                start: 0.0,
-                end: total_duration,
-            }]);
+            info!("⚠️  No speech detected");
         }
 
         Ok(segments)
     }
 
+    /// 开始一段新的流式检测，清空上一段音频的状态
+    pub fn begin_stream(&mut self) {
+        self.vad.reset();
+        self.vad.clear();
+        self.fed = 0;
+    }
+
+    /// 送入一块 16kHz 样本，返回已经结束的原始语音片段（未外扩、未合并）
+    pub fn accept(&mut self, samples: &[f32]) -> Vec<SpeechSegment> {
+        for chunk in samples.chunks(VAD_CHUNK_SIZE) {
+            self.vad.accept_waveform(chunk);
+        }
+        self.fed += samples.len();
+        self.drain_segments()
+    }
+
+    /// 输入结束，冲刷出最后一个语音片段
+    pub fn finish_stream(&mut self) -> Vec<SpeechSegment> {
+        self.vad.flush();
+        self.drain_segments()
+    }
+
+    /// 已结束片段的起点最多落后当前输入多少秒，之前的音频可以丢弃
+    pub fn lookback_secs(&self) -> f32 {
+        self.config.buffer_size_secs + self.config.pad_ms as f32 / 1000.0
+    }
+
+    pub fn config(&self) -> &VadConfig {
+        &self.config
+    }
+
+    fn drain_segments(&mut self) -> Vec<SpeechSegment> {
+        let fed_secs = self.fed as f32 / 16_000.0;
+        let segments: Vec<SpeechSegment> = self
+            .vad
+            .get_all_segments()
+            .into_iter()
+            .map(|seg| {
+                let start = seg.start as f32 / 16_000.0;
+                let end = (seg.start + seg.samples.len() as i32) as f32 / 16_000.0;
+                SpeechSegment::new(start, end.min(fed_secs))
+            })
+            .collect();
+        // 只清空已取出的片段队列，不影响检测状态
+        self.vad.clear();
+        debug!(
+            "📦 VAD emitted {} segments at {:.1}s",
+            segments.len(),
+            fed_secs
+        );
+        segments
+    }
+
     pub fn extract_segment(samples: &[f32], sample_rate: u32, segment: &SpeechSegment) -> Vec<f32> {
         let start_sample = (segment.start * sample_rate as f32) as usize;
         let end_sample = (segment.end * sample_rate as f32) as usize;
@@ -126,6 +169,156 @@ impl VadHandle {
     }
 }
 
+/// 片段后处理：外扩 → 合并重叠与短间隔 → 均分过长片段
+pub fn postprocess_segments(
+    mut segments: Vec<SpeechSegment>,
+    total_duration: f32,
+    config: &VadConfig,
+) -> Vec<SpeechSegment> {
+    let pad = config.pad_ms as f32 / 1000.0;
+    let merge_gap = config.merge_gap_ms as f32 / 1000.0;
+    let max_len = config.max_segment_ms as f32 / 1000.0;
+
+    segments.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+    let mut merged: Vec<SpeechSegment> = Vec::with_capacity(segments.len());
+    for seg in segments {
+        let seg = SpeechSegment::new(
+            (seg.start - pad).max(0.0),
+            (seg.end + pad).min(total_duration),
+        );
+        if seg.end <= seg.start {
+            continue;
+        }
+        match merged.last_mut() {
+            Some(last) if seg.start - last.end <= merge_gap => last.end = last.end.max(seg.end),
+            _ => merged.push(seg),
+        }
+    }
+
+    if max_len <= 0.0 {
+        return merged;
+    }
+
+    let mut result = Vec::with_capacity(merged.len());
+    for seg in merged {
+        let len = seg.end - seg.start;
+        let pieces = (len / max_len).ceil().max(1.0) as usize;
+        let piece_len = len / pieces as f32;
+        for i in 0..pieces {
+            let start = seg.start + piece_len * i as f32;
+            let end = if i + 1 == pieces { seg.end } else { start + piece_len };
+            result.push(SpeechSegment::new(start, end));
+        }
+    }
+    result
+}
+
+/// 未设置 `max_segment_ms` 时，流式处理中一组待合并片段的最大跨度（秒）
+const MAX_PENDING_SECS: f32 = 30.0;
+
+/// 流式片段后处理：缓存还可能与后续片段合并的原始片段，
+/// 确定不会再合并后按 [`postprocess_segments`] 的规则输出，结果与整段处理一致
+///
+/// 语音连续不断时（间隔都小于 `merge_gap_ms`）一组会无限增长，
+/// 因此一组的跨度超过 `max_segment_ms`（未设置时为 30 秒）后强制输出，
+/// 此时切分点与整段处理不同，但缓存的音频有上限
+pub struct SegmentAssembler {
+    config: VadConfig,
+    pending: Vec<SpeechSegment>,
+}
+
+impl SegmentAssembler {
+    pub fn new(config: &VadConfig) -> Self {
+        Self {
+            config: config.clone(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// 送入一个原始片段（按起点递增），返回已经确定的片段
+    pub fn push(&mut self, raw: SpeechSegment) -> Vec<SpeechSegment> {
+        let pad = self.config.pad_ms as f32 / 1000.0;
+        let merge_gap = self.config.merge_gap_ms as f32 / 1000.0;
+        let max_span = match self.config.max_segment_ms {
+            0 => MAX_PENDING_SECS,
+            ms => ms as f32 / 1000.0,
+        };
+        let ready = match (self.pending.first(), self.pending.last()) {
+            (Some(first), Some(last)) => {
+                let gap = (raw.start - pad).max(0.0) - (last.end + pad) > merge_gap;
+                let span = (last.end + pad) - (first.start - pad).max(0.0);
+                gap || span > max_span
+            }
+            _ => false,
+        };
+        let output = if ready {
+            let group = std::mem::take(&mut self.pending);
+            // 后面还有片段，说明这一组离音频结尾足够远，无需按总时长裁剪
+            postprocess_segments(group, f32::MAX, &self.config)
+        } else {
+            Vec::new()
+        };
+        self.pending.push(raw);
+        output
+    }
+
+    /// 尚未确定的片段外扩后的起点
+    pub fn pending_start(&self) -> Option<f32> {
+        let pad = self.config.pad_ms as f32 / 1000.0;
+        self.pending.first().map(|seg| (seg.start - pad).max(0.0))
+    }
+
+    pub fn finish(&mut self, total_duration: f32) -> Vec<SpeechSegment> {
+        postprocess_segments(
+            std::mem::take(&mut self.pending),
+            total_duration,
+            &self.config,
+        )
+    }
+}
+
+/// 按固定窗口切分整段音频，相邻窗口重叠 overlap 秒
+pub fn fixed_windows(total_duration: f32, window: f32, overlap: f32) -> Vec<SpeechSegment> {
+    let step = window - overlap;
+    if total_duration <= 0.0 || step <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut windows = Vec::new();
+    let mut start = 0.0_f32;
+    loop {
+        let end = (start + window).min(total_duration);
+        windows.push(SpeechSegment::new(start, end));
+        if end >= total_duration {
+            break;
+        }
+        start += step;
+    }
+    windows
+}
+
+fn validate_config(config: &VadConfig) -> Result<(), AudioError> {
+    if !(config.threshold > 0.0 && config.threshold < 1.0) {
+        return Err(AudioError::InvalidConfig(format!(
+            "VAD threshold must be in (0, 1), got {}",
+            config.threshold
+        )));
+    }
+    if config.num_threads == 0 {
+        return Err(AudioError::InvalidConfig(
+            "VAD num_threads must be at least 1".to_string(),
+        ));
+    }
+    if !(config.buffer_size_secs.is_finite() && config.buffer_size_secs > 0.0) {
+        return Err(AudioError::InvalidConfig(format!(
+            "VAD buffer_size_secs must be positive, got {}",
+            config.buffer_size_secs
+        )));
+    }
+    Ok(())
+}
+
 impl Drop for VadHandle {
     fn drop(&mut self) {
         info!("🗑️ VadHandle: releasing Silero VAD");
@@ -139,12 +332,140 @@ mod tests {
     #[test]
     fn test_extract_segment() {
         let samples: Vec<f32> = (0..16000).map(|i| i as f32 / 16000.0).collect();
-        let segment = SpeechSegment {
-            start: 0.5,
-            end: 1.0,
-        };
+        let segment = SpeechSegment::new(0.5, 1.0);
 
         let extracted = VadHandle::extract_segment(&samples, 16000, &segment);
         assert_eq!(extracted.len(), 8000);
     }
+
+    fn seg(start: f32, end: f32) -> SpeechSegment {
+        SpeechSegment::new(start, end)
+    }
+
+    fn bounds(segments: &[SpeechSegment]) -> Vec<(f32, f32)> {
+        segments
+            .iter()
+            .map(|s| ((s.start * 1000.0).round(), (s.end * 1000.0).round()))
+            .collect()
+    }
+
+    #[test]
+    fn test_postprocess_pads_and_merges() {
+        let config = VadConfig {
+            pad_ms: 100,
+            merge_gap_ms: 300,
+            max_segment_ms: 0,
+            ..Default::default()
+        };
+        let segments = vec![seg(2.0, 3.0), seg(0.05, 1.0), seg(3.4, 4.0), seg(6.0, 9.95)];
+
+        let result = postprocess_segments(segments, 10.0, &config);
+
+        // 首尾裁剪到音频范围内；外扩后 3.1 与 3.3 间隔 0.2s 被合并
+        assert_eq!(
+            bounds(&result),
+            vec![(0.0, 1100.0), (1900.0, 4100.0), (5900.0, 10000.0)]
+        );
+    }
+
+    #[test]
+    fn test_postprocess_splits_long_segments() {
+        let config = VadConfig {
+            pad_ms: 0,
+            merge_gap_ms: 0,
+            max_segment_ms: 20_000,
+            ..Default::default()
+        };
+
+        let result = postprocess_segments(vec![seg(10.0, 55.0)], 60.0, &config);
+
+        assert_eq!(
+            bounds(&result),
+            vec![(10000.0, 25000.0), (25000.0, 40000.0), (40000.0, 55000.0)]
+        );
+    }
+
+    #[test]
+    fn test_assembler_matches_batch_postprocess() {
+        // 每组在加入最后一个片段前都不超过 max_segment_ms，不会触发强制输出
+        let config = VadConfig {
+            pad_ms: 100,
+            merge_gap_ms: 300,
+            max_segment_ms: 4_000,
+            ..Default::default()
+        };
+        let raw = vec![
+            seg(0.05, 1.0),
+            seg(1.3, 1.5),
+            seg(2.0, 3.0),
+            seg(3.4, 7.0),
+            seg(9.0, 9.95),
+        ];
+
+        let mut assembler = SegmentAssembler::new(&config);
+        let mut streamed = Vec::new();
+        for segment in raw.clone() {
+            streamed.extend(assembler.push(segment));
+        }
+        assert_eq!(
+            assembler.pending_start().map(|s| (s * 1000.0).round()),
+            Some(8900.0)
+        );
+        streamed.extend(assembler.finish(10.0));
+
+        assert_eq!(
+            bounds(&streamed),
+            bounds(&postprocess_segments(raw, 10.0, &config))
+        );
+    }
+
+    #[test]
+    fn test_assembler_bounds_continuous_speech() {
+        let config = VadConfig {
+            pad_ms: 100,
+            merge_gap_ms: 300,
+            max_segment_ms: 2_000,
+            ..Default::default()
+        };
+        let mut assembler = SegmentAssembler::new(&config);
+
+        // 十分钟不停顿的语音，VAD 切成 0.5s 的小块，块间隔 0.1s 总能合并
+        let mut streamed = Vec::new();
+        let mut start = 0.0_f32;
+        while start < 600.0 {
+            streamed.extend(assembler.push(seg(start, start + 0.5)));
+            let pending = assembler.pending_start().unwrap();
+            assert!(start + 0.5 - pending <= 3.0, "pending since {}", pending);
+            start += 0.6;
+        }
+        streamed.extend(assembler.finish(601.0));
+
+        assert!(streamed.len() > 250);
+        assert!(streamed.iter().all(|s| s.end - s.start <= 2.0 + 1e-3));
+        assert!(streamed.windows(2).all(|w| w[0].start < w[1].start));
+        assert!(streamed.last().unwrap().end > 600.0);
+    }
+
+    #[test]
+    fn test_fixed_windows_overlap() {
+        assert_eq!(
+            bounds(&fixed_windows(35.0, 15.0, 1.0)),
+            vec![(0.0, 15000.0), (14000.0, 29000.0), (28000.0, 35000.0)]
+        );
+        assert_eq!(bounds(&fixed_windows(5.0, 15.0, 1.0)), vec![(0.0, 5000.0)]);
+        assert!(fixed_windows(5.0, 1.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_vad_config() {
+        let config = VadConfig {
+            threshold: 1.5,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            validate_config(&config),
+            Err(AudioError::InvalidConfig(_))
+        ));
+    }
 }
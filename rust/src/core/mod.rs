@@ -1,3 +1,5 @@
 pub mod audio;
+pub mod provider;
+pub mod recipe;
 pub mod video;
 pub mod xhs;
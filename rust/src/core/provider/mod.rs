@@ -0,0 +1,149 @@
+//! 内容平台抽象 - 不同平台的链接识别、抓取与解析
+
+use thiserror::Error;
+
+use crate::api::models::source::{Platform, SourceArticle};
+use crate::api::models::xhs::XhsClientConfig;
+use crate::core::xhs::{ParserError, XhsParser};
+
+mod xhs;
+mod xiachufang;
+
+pub use xiachufang::XiachufangProvider;
+
+#[derive(Error, Debug)]
+pub enum ProviderError {
+    #[error("HTTP 请求失败: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("不支持的链接: {0}")]
+    UnsupportedUrl(String),
+    #[error("解析页面失败: {0}")]
+    Parse(String),
+    #[error(transparent)]
+    Xhs(#[from] ParserError),
+}
+
+pub trait ContentProvider: Send + Sync {
+    fn platform(&self) -> Platform;
+
+    /// 从分享文本中找出本平台的链接，返回规范化后的 URL
+    fn find_url(&self, text: &str) -> Option<String>;
+
+    /// 是否能处理该链接
+    fn matches(&self, url: &str) -> bool {
+        self.find_url(url).is_some()
+    }
+
+    /// 抓取原始页面
+    fn fetch(&self, url: &str) -> Result<String, ProviderError>;
+
+    /// 从页面内容解析出平台无关的文章
+    fn parse(&self, html: &str) -> Result<SourceArticle, ProviderError>;
+
+    fn fetch_article(&self, url: &str) -> Result<SourceArticle, ProviderError> {
+        let html = self.fetch(url)?;
+        self.parse(&html)
+    }
+}
+
+/// 按注册顺序匹配链接的平台集合
+///
+/// 各平台使用阻塞客户端，不能在异步运行时中创建或调用，异步场景需放到 `spawn_blocking` 中
+pub struct ProviderRegistry {
+    providers: Vec<Box<dyn ContentProvider>>,
+}
+
+impl ProviderRegistry {
+    pub fn new() -> Self {
+        Self {
+            providers: Vec::new(),
+        }
+    }
+
+    /// 内置的全部平台
+    pub fn with_defaults() -> Result<Self, ProviderError> {
+        Self::with_config(&XhsClientConfig::default())
+    }
+
+    /// 内置的全部平台，小红书使用指定的网络与缓存配置
+    pub fn with_config(config: &XhsClientConfig) -> Result<Self, ProviderError> {
+        let mut registry = Self::new();
+        registry.register(Box::new(XhsParser::with_config(config)?));
+        registry.register(Box::new(XiachufangProvider::new(config)?));
+        Ok(registry)
+    }
+
+    pub fn register(&mut self, provider: Box<dyn ContentProvider>) {
+        self.providers.push(provider);
+    }
+
+    pub fn find(&self, url: &str) -> Option<&dyn ContentProvider> {
+        self.providers
+            .iter()
+            .find(|p| p.matches(url))
+            .map(|p| p.as_ref())
+    }
+
+    pub fn fetch_article(&self, url: &str) -> Result<SourceArticle, ProviderError> {
+        self.find(url)
+            .ok_or_else(|| ProviderError::UnsupportedUrl(url.to_string()))?
+            .fetch_article(url)
+    }
+
+    /// 从分享文本中找出第一个可处理的链接并抓取，返回规范化的链接与文章
+    pub fn fetch_from_text(&self, text: &str) -> Result<(String, SourceArticle), ProviderError> {
+        let (provider, url) = self
+            .providers
+            .iter()
+            .find_map(|p| p.find_url(text).map(|url| (p, url)))
+            .ok_or_else(|| ProviderError::UnsupportedUrl(text.to_string()))?;
+        let article = provider.fetch_article(&url)?;
+        Ok((url, article))
+    }
+}
+
+impl Default for ProviderRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_routes_by_url() {
+        let registry = ProviderRegistry::with_defaults().expect("创建失败");
+
+        let xhs = registry.find("http://xhslink.com/o/5ZMAfpDOokl").unwrap();
+        assert_eq!(xhs.platform(), Platform::Xiaohongshu);
+
+        let xcf = registry
+            .find("https://www.xiachufang.com/recipe/107138437/")
+            .unwrap();
+        assert_eq!(xcf.platform(), Platform::Xiachufang);
+
+        assert!(registry.find("https://www.example.com/recipe/1").is_none());
+    }
+
+    #[test]
+    fn test_find_url_in_share_text() {
+        let registry = ProviderRegistry::with_defaults().expect("创建失败");
+        let text = "番茄炒蛋 https://m.xiachufang.com/recipe/100123/?from=share 来自下厨房";
+
+        let provider = registry.find(text).unwrap();
+        assert_eq!(provider.platform(), Platform::Xiachufang);
+        assert_eq!(
+            provider.find_url(text).as_deref(),
+            Some("https://m.xiachufang.com/recipe/100123")
+        );
+    }
+
+    #[test]
+    fn test_unsupported_url_error() {
+        let registry = ProviderRegistry::new();
+        let result = registry.fetch_article("https://www.example.com/");
+        assert!(matches!(result, Err(ProviderError::UnsupportedUrl(_))));
+    }
+}
@@ -0,0 +1,26 @@
+use super::{ContentProvider, ProviderError};
+use crate::api::models::source::{Platform, SourceArticle};
+use crate::core::xhs::{classify_link, XhsParser};
+
+impl ContentProvider for XhsParser {
+    fn platform(&self) -> Platform {
+        Platform::Xiaohongshu
+    }
+
+    fn find_url(&self, text: &str) -> Option<String> {
+        classify_link(text).map(|link| link.url)
+    }
+
+    fn fetch(&self, url: &str) -> Result<String, ProviderError> {
+        Ok(self.fetch_html(url)?)
+    }
+
+    fn parse(&self, html: &str) -> Result<SourceArticle, ProviderError> {
+        Ok(self.parse_from_html(html)?.into())
+    }
+
+    /// 走 `parse_by_url`，复用短链解析与笔记缓存
+    fn fetch_article(&self, url: &str) -> Result<SourceArticle, ProviderError> {
+        Ok(self.parse_by_url(url)?.into())
+    }
+}
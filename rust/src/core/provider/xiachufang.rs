@@ -0,0 +1,279 @@
+//! 下厨房 - 基于页面内 schema.org Recipe (JSON-LD) 数据解析
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use reqwest::blocking::Client;
+use scraper::{Html, Selector};
+use serde_json::Value;
+
+use super::{ContentProvider, ProviderError};
+use crate::api::models::recipe::ParsedRecipe;
+use crate::api::models::source::{Platform, SourceArticle, SourceAuthor, SourceVideo};
+use crate::api::models::xhs::{NoteType, XhsClientConfig};
+use crate::core::recipe::parse_ingredient;
+use crate::core::xhs::client;
+
+static RECIPE_URL: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"https?://(?:www\.|m\.)?xiachufang\.com/recipe/\d+").unwrap());
+
+/// ISO 8601 时长，如 `PT1M5S`
+static ISO_DURATION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^PT(?:(\d+)H)?(?:(\d+)M)?(?:(\d+)S)?$").unwrap());
+
+pub struct XiachufangProvider {
+    client: Client,
+}
+
+impl XiachufangProvider {
+    /// 沿用小红书客户端的超时、重定向与代理设置
+    pub fn new(config: &XhsClientConfig) -> Result<Self, ProviderError> {
+        let client = client::blocking_builder(config)?.build()?;
+        Ok(Self { client })
+    }
+}
+
+impl ContentProvider for XiachufangProvider {
+    fn platform(&self) -> Platform {
+        Platform::Xiachufang
+    }
+
+    fn find_url(&self, text: &str) -> Option<String> {
+        RECIPE_URL.find(text).map(|m| m.as_str().to_string())
+    }
+
+    fn fetch(&self, url: &str) -> Result<String, ProviderError> {
+        let url = self
+            .find_url(url)
+            .ok_or_else(|| ProviderError::UnsupportedUrl(url.to_string()))?;
+        let resp = self.client.get(&url).send()?.error_for_status()?;
+        Ok(resp.text()?)
+    }
+
+    fn parse(&self, html: &str) -> Result<SourceArticle, ProviderError> {
+        let recipe = extract_recipe_json(html)?;
+        build_article(&recipe)
+    }
+}
+
+fn extract_recipe_json(html: &str) -> Result<Value, ProviderError> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse(r#"script[type="application/ld+json"]"#)
+        .map_err(|e| ProviderError::Parse(e.to_string()))?;
+
+    document
+        .select(&selector)
+        .filter_map(|element| serde_json::from_str::<Value>(&element.inner_html()).ok())
+        .find_map(find_recipe_node)
+        .ok_or_else(|| ProviderError::Parse("页面中没有 Recipe 数据".to_string()))
+}
+
+/// JSON-LD 可能是单个对象、数组或 `@graph`
+fn find_recipe_node(value: Value) -> Option<Value> {
+    match value {
+        Value::Array(items) => items.into_iter().find_map(find_recipe_node),
+        Value::Object(ref map) if map.contains_key("@graph") => {
+            find_recipe_node(map.get("@graph").cloned()?)
+        }
+        Value::Object(_) if is_recipe(&value) => Some(value),
+        _ => None,
+    }
+}
+
+fn is_recipe(value: &Value) -> bool {
+    match value.get("@type") {
+        Some(Value::String(t)) => t == "Recipe",
+        Some(Value::Array(types)) => types.iter().any(|t| t.as_str() == Some("Recipe")),
+        _ => false,
+    }
+}
+
+fn build_article(recipe: &Value) -> Result<SourceArticle, ProviderError> {
+    let title = str_field(recipe, "name")
+        .ok_or_else(|| ProviderError::Parse("Recipe 缺少 name".to_string()))?;
+    let desc = str_field(recipe, "description").unwrap_or_default();
+
+    let author = match recipe.get("author") {
+        Some(Value::String(name)) => SourceAuthor {
+            name: name.clone(),
+            ..Default::default()
+        },
+        Some(author) => SourceAuthor {
+            name: str_field(author, "name").unwrap_or_default(),
+            id: str_field(author, "url").unwrap_or_default(),
+            avatar: str_field(author, "image").unwrap_or_default(),
+        },
+        None => SourceAuthor::default(),
+    };
+
+    let images = recipe.get("image").map(urls).unwrap_or_default();
+    let video = recipe.get("video").and_then(extract_video);
+    let note_type = NoteType::from_media(video.is_some(), images.len());
+
+    let ingredients = recipe
+        .get("recipeIngredient")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(parse_ingredient)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut steps = Vec::new();
+    if let Some(instructions) = recipe.get("recipeInstructions") {
+        collect_steps(instructions, &mut steps);
+    }
+
+    Ok(SourceArticle {
+        platform: Platform::Xiachufang,
+        recipe: Some(ParsedRecipe {
+            title: title.clone(),
+            ingredients,
+            steps,
+            tips: Vec::new(),
+        }),
+        title,
+        desc,
+        author,
+        images,
+        video,
+        note_type,
+    })
+}
+
+/// 步骤可能是字符串、HowToStep 或嵌套的 HowToSection
+fn collect_steps(value: &Value, steps: &mut Vec<String>) {
+    match value {
+        Value::String(text) => {
+            let text = text.trim();
+            if !text.is_empty() {
+                steps.push(text.to_string());
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_steps(item, steps)),
+        Value::Object(map) => {
+            if let Some(items) = map.get("itemListElement") {
+                collect_steps(items, steps);
+            } else if let Some(text) = map.get("text") {
+                collect_steps(text, steps);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn extract_video(value: &Value) -> Option<SourceVideo> {
+    let play_url = str_field(value, "contentUrl")?;
+    let cover = value.get("thumbnailUrl").map(urls)?.into_iter().next()?;
+    let duration = str_field(value, "duration")
+        .and_then(|d| parse_iso_duration(&d))
+        .unwrap_or(0);
+
+    Some(SourceVideo {
+        duration,
+        cover,
+        play_url,
+    })
+}
+
+fn parse_iso_duration(text: &str) -> Option<i64> {
+    let caps = ISO_DURATION.captures(text)?;
+    let part = |i: usize| {
+        caps.get(i)
+            .and_then(|m| m.as_str().parse::<i64>().ok())
+            .unwrap_or(0)
+    };
+    Some(part(1) * 3600 + part(2) * 60 + part(3))
+}
+
+/// 图片字段可能是字符串、字符串数组或 ImageObject
+fn urls(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(url) => vec![url.clone()],
+        Value::Array(items) => items.iter().flat_map(urls).collect(),
+        Value::Object(_) => str_field(value, "url").into_iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn str_field(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(|s| s.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECIPE_HTML: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/xiachufang/recipe.html"
+    ));
+
+    fn provider() -> XiachufangProvider {
+        XiachufangProvider::new(&XhsClientConfig::default()).expect("创建失败")
+    }
+
+    #[test]
+    fn test_invalid_proxy_is_error_not_panic() {
+        let config = XhsClientConfig {
+            proxy: Some("not a proxy url".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            XiachufangProvider::new(&config),
+            Err(ProviderError::Http(_))
+        ));
+    }
+
+    #[test]
+    fn test_matches_recipe_urls() {
+        let provider = provider();
+        assert!(provider.matches("https://www.xiachufang.com/recipe/107138437/"));
+        assert!(provider.matches("分享一个菜谱 http://m.xiachufang.com/recipe/100123/ 来自下厨房"));
+        assert!(!provider.matches("https://www.xiachufang.com/category/40076/"));
+        assert!(!provider.matches("http://xhslink.com/o/5ZMAfpDOokl"));
+    }
+
+    #[test]
+    fn test_parse_recipe_fixture() {
+        let article = provider().parse(RECIPE_HTML).expect("解析失败");
+
+        assert_eq!(article.platform, Platform::Xiachufang);
+        assert_eq!(article.title, "番茄炒蛋");
+        assert!(article.desc.contains("下饭"));
+        assert_eq!(article.author.name, "厨房新手小王");
+        assert_eq!(article.images.len(), 1);
+        assert_eq!(article.note_type, NoteType::Video);
+
+        let video = article.video.expect("应该有视频");
+        assert_eq!(video.duration, 95);
+        assert!(video.play_url.ends_with(".mp4"));
+
+        let recipe = article.recipe.expect("应该有结构化菜谱");
+        assert_eq!(recipe.ingredients.len(), 4);
+        assert_eq!(recipe.ingredients[0].name, "番茄");
+        assert_eq!(recipe.ingredients[0].amount.as_deref(), Some("2个"));
+        assert_eq!(recipe.ingredients[3].amount.as_deref(), Some("适量"));
+        assert_eq!(recipe.steps.len(), 4);
+        assert!(recipe.steps[0].starts_with("番茄顶部划十字"));
+    }
+
+    #[test]
+    fn test_parse_page_without_recipe_data() {
+        let html = "<html><head><script type=\"application/ld+json\">{\"@type\":\"WebSite\"}</script></head></html>";
+        let result = provider().parse(html);
+        assert!(matches!(result, Err(ProviderError::Parse(_))));
+    }
+
+    #[test]
+    fn test_parse_iso_duration() {
+        assert_eq!(parse_iso_duration("PT1M35S"), Some(95));
+        assert_eq!(parse_iso_duration("PT1H"), Some(3600));
+        assert_eq!(parse_iso_duration("95"), None);
+    }
+}
@@ -0,0 +1,5 @@
+//! 菜谱结构化 - 从笔记正文与语音转写中提取食材、步骤
+
+mod parser;
+
+pub use parser::{parse_ingredient, parse_recipe};
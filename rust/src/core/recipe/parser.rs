@@ -0,0 +1,238 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::api::models::recipe::{ParsedRecipe, RecipeIngredient};
+
+/// 小红书话题标签，如 `#家常菜[话题]#`
+static TOPIC_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"#[^#\s]+?(\[话题\])?#").unwrap());
+
+/// 段落标题，如 `【食材】`、`做法：`
+static SECTION_HEADER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^[【\[]?\s*(食材|用料|材料|配料|原料|调料|主料|辅料|做法|步骤|制作方法|制作过程|小贴士|贴士|(?i:tips)|注意事项)\s*(?:[】\]]\s*[:：]?|[:：]|$)",
+    )
+    .unwrap()
+});
+
+/// 步骤序号前缀：`1.`、`2、`、`①`、`第3步`、`Step 4:`
+static STEP_PREFIX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:(?i:step)\s*\d+|第[一二三四五六七八九十\d]+步|\d{1,2}\s*[、.．:：)）]|[①-⑳])\s*[、.．:：)）]?\s*")
+        .unwrap()
+});
+
+const AMOUNT_PATTERN: &str = r"(?:\d+(?:\.\d+)?|[一二两三四五六七八九十半]+)\s*(?:克|g|kg|千克|斤|两|毫升|ml|升|勺|汤匙|茶匙|小勺|大勺|个|颗|只|片|根|块|把|瓣|杯|碗|段|张|条)|适量|少许|少量|一点|若干";
+
+/// 带用量的食材条目，如 `猪蹄 2只`、`盐适量`
+static INGREDIENT_ITEM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(&format!(
+        r"^(?P<name>.+?)\s*(?P<amount>{})$",
+        AMOUNT_PATTERN
+    ))
+    .unwrap()
+});
+
+/// 只有用量的片段，用于合并 `猪蹄 2只` 这种被空格拆开的条目
+static AMOUNT_ONLY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(&format!(r"^(?:{})$", AMOUNT_PATTERN)).unwrap());
+
+static ITEM_SEPARATOR: Lazy<Regex> = Lazy::new(|| Regex::new(r"[，,、；;/\s]+").unwrap());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    None,
+    Ingredients,
+    Steps,
+    Tips,
+}
+
+/// 从笔记标题、正文和（可选的）语音转写中解析菜谱结构
+///
+/// 正文中没有识别出步骤时，转写文本的每一行作为一个步骤。
+pub fn parse_recipe(title: &str, desc: &str, transcript: Option<&str>) -> ParsedRecipe {
+    let cleaned = TOPIC_TAG.replace_all(desc, "");
+    let mut recipe = ParsedRecipe {
+        title: title.trim().to_string(),
+        ..Default::default()
+    };
+    let mut section = Section::None;
+
+    for raw_line in cleaned.lines() {
+        let mut line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some((next, rest)) = detect_section(line) {
+            section = next;
+            line = rest.trim();
+            if line.is_empty() {
+                continue;
+            }
+        }
+
+        if let Some(step) = strip_step_prefix(line) {
+            if section == Section::Tips {
+                recipe.tips.push(step.to_string());
+            } else {
+                recipe.steps.push(step.to_string());
+            }
+            continue;
+        }
+
+        match section {
+            Section::Ingredients => recipe.ingredients.extend(parse_ingredients(line)),
+            Section::Steps => recipe.steps.push(line.to_string()),
+            Section::Tips => recipe.tips.push(line.to_string()),
+            Section::None => {
+                let items = parse_ingredients(line);
+                if !items.is_empty() && items.iter().all(|i| i.amount.is_some()) {
+                    recipe.ingredients.extend(items);
+                }
+            }
+        }
+    }
+
+    if recipe.steps.is_empty() {
+        if let Some(transcript) = transcript {
+            recipe.steps = transcript_sentences(transcript);
+        }
+    }
+
+    recipe
+}
+
+fn detect_section(line: &str) -> Option<(Section, &str)> {
+    let caps = SECTION_HEADER.captures(line)?;
+    let section = match caps.get(1)?.as_str() {
+        "做法" | "步骤" | "制作方法" | "制作过程" => Section::Steps,
+        "小贴士" | "贴士" | "注意事项" => Section::Tips,
+        keyword if keyword.eq_ignore_ascii_case("tips") => Section::Tips,
+        _ => Section::Ingredients,
+    };
+    Some((section, &line[caps.get(0)?.end()..]))
+}
+
+fn strip_step_prefix(line: &str) -> Option<&str> {
+    let m = STEP_PREFIX.find(line)?;
+    let rest = &line[m.end()..];
+    // `1.5勺盐` 是用量而不是步骤序号
+    if rest.is_empty() || rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(rest.trim())
+}
+
+fn parse_ingredients(line: &str) -> Vec<RecipeIngredient> {
+    let mut items: Vec<RecipeIngredient> = Vec::new();
+
+    for token in ITEM_SEPARATOR.split(line) {
+        let token = token.trim_matches(|c: char| !c.is_alphanumeric());
+        if token.is_empty() {
+            continue;
+        }
+
+        if AMOUNT_ONLY.is_match(token) {
+            if let Some(last) = items.last_mut().filter(|i| i.amount.is_none()) {
+                last.amount = Some(token.to_string());
+            }
+            continue;
+        }
+
+        items.push(parse_ingredient(token));
+    }
+
+    items
+}
+
+/// 解析单个食材条目，如 `猪蹄 2只` → (猪蹄, 2只)
+pub fn parse_ingredient(text: &str) -> RecipeIngredient {
+    let text = text.trim();
+    match INGREDIENT_ITEM.captures(text) {
+        Some(caps) => RecipeIngredient {
+            name: caps["name"].trim().to_string(),
+            amount: Some(caps["amount"].to_string()),
+        },
+        None => RecipeIngredient {
+            name: text.to_string(),
+            amount: None,
+        },
+    }
+}
+
+/// 转写结果的每一行形如 `00:00:01:20 - 00:00:04:50  --  文本`
+fn transcript_sentences(transcript: &str) -> Vec<String> {
+    transcript
+        .lines()
+        .map(|line| {
+            line.rsplit_once("  --  ")
+                .map_or(line, |(_, text)| text)
+                .trim()
+        })
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sectioned_desc() {
+        let desc = "慢炖两小时，汤都是奶白色的 #家常菜[话题]# #蹄花汤[话题]#\n\
+                    【食材】\n猪蹄 2只、白芸豆 200克，姜适量\n\
+                    【做法】\n1. 猪蹄冷水下锅焯水\n2、白芸豆提前泡发\n③ 小火慢炖两小时\n\
+                    小贴士：\n白芸豆一定要煮熟";
+        let recipe = parse_recipe(" 爱死蹄花汤了 ", desc, None);
+
+        assert_eq!(recipe.title, "爱死蹄花汤了");
+        assert_eq!(
+            recipe.ingredients,
+            vec![
+                RecipeIngredient {
+                    name: "猪蹄".into(),
+                    amount: Some("2只".into())
+                },
+                RecipeIngredient {
+                    name: "白芸豆".into(),
+                    amount: Some("200克".into())
+                },
+                RecipeIngredient {
+                    name: "姜".into(),
+                    amount: Some("适量".into())
+                },
+            ]
+        );
+        assert_eq!(
+            recipe.steps,
+            vec!["猪蹄冷水下锅焯水", "白芸豆提前泡发", "小火慢炖两小时"]
+        );
+        assert_eq!(recipe.tips, vec!["白芸豆一定要煮熟"]);
+    }
+
+    #[test]
+    fn test_parse_inline_header_and_unsectioned_ingredients() {
+        let desc = "面粉300g 鸡蛋1个\n今天的早餐好香\n做法：揉面醒发二十分钟";
+        let recipe = parse_recipe("早餐", desc, None);
+
+        assert_eq!(recipe.ingredients.len(), 2);
+        assert_eq!(recipe.ingredients[0].name, "面粉");
+        assert_eq!(recipe.ingredients[1].amount.as_deref(), Some("1个"));
+        assert_eq!(recipe.steps, vec!["揉面醒发二十分钟"]);
+    }
+
+    #[test]
+    fn test_decimal_amount_is_not_step() {
+        assert_eq!(strip_step_prefix("1.5勺盐"), None);
+        assert_eq!(strip_step_prefix("1.加盐"), Some("加盐"));
+        assert_eq!(strip_step_prefix("第二步 加盐"), Some("加盐"));
+    }
+
+    #[test]
+    fn test_transcript_fallback_for_steps() {
+        let transcript = "00:00:01:00 - 00:00:03:00  --  先把猪蹄焯水\n00:00:04:00 - 00:00:06:00  --  再加白芸豆";
+        let recipe = parse_recipe("蹄花汤", "好喝", Some(transcript));
+
+        assert_eq!(recipe.steps, vec!["先把猪蹄焯水", "再加白芸豆"]);
+    }
+}
@@ -0,0 +1,194 @@
+use std::path::Path;
+
+use reqwest::header::REFERER;
+use reqwest::Client;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+
+use super::cache::NoteCache;
+use super::{classify_link, client, page, parser, profile, ParserError};
+use crate::api::models::xhs::{
+    XhsArticle, XhsAuthorProfile, XhsClientConfig, XhsLink, XhsLinkKind,
+};
+
+/// 异步版解析器，供 FRB 的 async 接口在其 tokio 运行时中使用
+pub struct AsyncXhsParser {
+    client: Client,
+    cache: Option<NoteCache>,
+}
+
+impl AsyncXhsParser {
+    pub fn new() -> Result<Self, ParserError> {
+        Self::with_config(&XhsClientConfig::default())
+    }
+
+    pub fn with_config(config: &XhsClientConfig) -> Result<Self, ParserError> {
+        Ok(Self {
+            client: client::build_async_client(config)?,
+            cache: config.cache.as_ref().map(NoteCache::open).transpose()?,
+        })
+    }
+
+    /// 从小红书链接获取文章详情
+    ///
+    /// 配置了缓存时先按笔记 id 查缓存，短链使用记录过的跳转结果，未命中才联网抓取
+    pub async fn parse_by_url(&self, url: &str) -> Result<XhsArticle, ParserError> {
+        let (Some(cache), Some(link)) = (&self.cache, classify_link(url)) else {
+            let html = self.fetch_html(url).await?;
+            return self.parse_from_html(&html);
+        };
+
+        if let Some(article) = cache.note_id_for(&link).and_then(|id| cache.get(&id)) {
+            return Ok(article);
+        }
+
+        let short_code = (link.kind == XhsLinkKind::ShortLink).then(|| link.id.clone());
+        let link = self.resolve_link(&link).await?;
+        if let Some(short_code) = short_code {
+            cache.put_short_link(&short_code, &link.id);
+            if let Some(article) = cache.get(&link.id) {
+                return Ok(article);
+            }
+        }
+
+        let html = self.fetch_html(&link.url).await?;
+        let (state, article) = parser::parse_note_html_with_state(&html)?;
+        cache.put(&link.id, &state, &article);
+        Ok(article)
+    }
+
+    /// 跟随短链跳转，得到带笔记 id 的规范链接
+    pub async fn resolve_link(&self, link: &XhsLink) -> Result<XhsLink, ParserError> {
+        if link.kind != XhsLinkKind::ShortLink {
+            return Ok(link.clone());
+        }
+
+        let resp = self.client.get(&link.url).send().await?;
+        if let Some(e) = resp.error_for_status_ref().err() {
+            let status = resp.status().as_u16();
+            let html = resp.text().await.unwrap_or_default();
+            return Err(page::classify_blocked_status(status, &html).unwrap_or(e.into()));
+        }
+        let final_url = resp.url().as_str();
+        classify_link(final_url)
+            .filter(|resolved| resolved.note_id().is_some())
+            .ok_or_else(|| ParserError::InvalidLink(final_url.to_string()))
+    }
+
+    /// 从 HTML 内容直接解析
+    pub fn parse_from_html(&self, html: &str) -> Result<XhsArticle, ParserError> {
+        parser::parse_note_html(html)
+    }
+
+    /// 从作者主页链接获取作者信息与首屏笔记
+    pub async fn parse_profile_by_url(&self, url: &str) -> Result<XhsAuthorProfile, ParserError> {
+        let html = self.fetch_html(url).await?;
+        self.parse_profile_from_html(&html)
+    }
+
+    /// 从作者主页 HTML 直接解析
+    pub fn parse_profile_from_html(&self, html: &str) -> Result<XhsAuthorProfile, ParserError> {
+        profile::parse_profile_html(html)
+    }
+
+    /// 流式下载笔记视频到本地文件，返回写入的字节数
+    pub async fn download_video(&self, play_url: &str, dest: &Path) -> Result<u64, ParserError> {
+        let mut resp = self
+            .client
+            .get(play_url)
+            .header(REFERER, client::REFERER_URL)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let mut file = File::create(dest).await?;
+        let mut written = 0u64;
+        while let Some(chunk) = resp.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+        Ok(written)
+    }
+
+    /// 获取页面 HTML，验证码、限流等拦截状态按页面内容或状态码识别为对应错误
+    pub(crate) async fn fetch_html(&self, url: &str) -> Result<String, ParserError> {
+        let resp = self.client.get(url).send().await?;
+        if let Some(e) = resp.error_for_status_ref().err() {
+            let status = resp.status().as_u16();
+            let html = resp.text().await.unwrap_or_default();
+            return Err(page::classify_blocked_status(status, &html).unwrap_or(e.into()));
+        }
+        Ok(resp.text().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::xhs::{XhsApiError, XhsBlockReason};
+    use crate::core::xhs::tests::{serve_once, CAPTCHA, RATE_LIMITED};
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_captcha_status_is_classified() {
+        let url = serve_once("461 Unknown", b"<html>captcha</html>");
+        let parser = AsyncXhsParser::new().unwrap();
+
+        let error = block_on(parser.parse_by_url(&url)).unwrap_err();
+        assert!(matches!(
+            XhsApiError::from(error),
+            XhsApiError::Blocked {
+                reason: XhsBlockReason::CaptchaRequired
+            }
+        ));
+    }
+
+    #[test]
+    fn test_blocked_status_pages_are_classified() {
+        let parser = AsyncXhsParser::new().unwrap();
+
+        let url = serve_once("461 Unknown", CAPTCHA.as_bytes());
+        let error = block_on(parser.parse_by_url(&url)).unwrap_err();
+        assert!(matches!(error, ParserError::CaptchaRequired));
+
+        let url = serve_once("429 Too Many Requests", RATE_LIMITED.as_bytes());
+        let error = block_on(parser.parse_by_url(&url)).unwrap_err();
+        assert!(matches!(error, ParserError::RateLimited));
+
+        let url = serve_once("404 Not Found", b"<html></html>");
+        let error = block_on(parser.parse_by_url(&url)).unwrap_err();
+        assert!(matches!(error, ParserError::NoteDeleted));
+    }
+
+    #[test]
+    fn test_unclassified_error_status_is_not_parsed_as_html() {
+        let url = serve_once("500 Internal Server Error", b"<html>oops</html>");
+        let parser = AsyncXhsParser::new().unwrap();
+
+        let error = block_on(parser.parse_by_url(&url)).unwrap_err();
+        assert!(matches!(
+            XhsApiError::from(error),
+            XhsApiError::HttpStatus { status: 500, .. }
+        ));
+    }
+
+    #[test]
+    fn test_download_video_writes_file() {
+        let url = serve_once("200 OK", b"fake mp4 bytes");
+        let dest = std::env::temp_dir().join(format!("xhs_download_{}.mp4", std::process::id()));
+        let parser = AsyncXhsParser::new().unwrap();
+
+        let written = block_on(parser.download_video(&url, &dest)).unwrap();
+        assert_eq!(written, 14);
+        assert_eq!(std::fs::read(&dest).unwrap(), b"fake mp4 bytes");
+        std::fs::remove_file(&dest).unwrap();
+    }
+}
@@ -0,0 +1,295 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::ParserError;
+use crate::api::models::xhs::{NoteType, XhsArticle, XhsCacheConfig, XhsLink, XhsLinkKind};
+
+const ENTRY_EXTENSION: &str = "json";
+const SHORT_LINK_EXTENSION: &str = "link";
+
+/// 笔记磁盘缓存，每条笔记一个 `<note_id>.json` 文件
+///
+/// 短链另存一个 `<短链编码>.link` 文件记录对应的笔记 id，
+/// 命中缓存的短链无需联网跳转。缓存读写失败只记录日志，不影响正常抓取。
+pub struct NoteCache {
+    dir: PathBuf,
+    ttl_secs: u64,
+    max_size_bytes: u64,
+    bypass: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    note_id: String,
+    cached_at: u64,
+    /// 页面原始的初始状态，便于解析逻辑升级后重新提取
+    state: Value,
+    article: XhsArticle,
+}
+
+impl NoteCache {
+    pub fn open(config: &XhsCacheConfig) -> Result<Self, ParserError> {
+        let dir = PathBuf::from(&config.cache_dir);
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            ttl_secs: config.ttl_secs,
+            max_size_bytes: config.max_size_bytes,
+            bypass: config.bypass,
+        })
+    }
+
+    pub fn get(&self, note_id: &str) -> Option<XhsArticle> {
+        if self.bypass {
+            return None;
+        }
+        self.get_at(note_id, now_secs())
+    }
+
+    pub fn put(&self, note_id: &str, state: &Value, article: &XhsArticle) {
+        self.put_at(note_id, state, article, now_secs());
+    }
+
+    /// 链接对应的笔记 id，短链从已记录的跳转结果中查找
+    pub fn note_id_for(&self, link: &XhsLink) -> Option<String> {
+        if link.kind != XhsLinkKind::ShortLink {
+            return Some(link.id.clone());
+        }
+        let note_id = fs::read_to_string(self.short_link_path(&link.id)).ok()?;
+        let note_id = note_id.trim();
+        (!note_id.is_empty()).then(|| note_id.to_string())
+    }
+
+    /// 记录短链跳转后的笔记 id
+    pub fn put_short_link(&self, short_code: &str, note_id: &str) {
+        if let Err(e) = fs::write(self.short_link_path(short_code), note_id) {
+            warn!("⚠️ Failed to write XHS short link {}: {}", short_code, e);
+        }
+    }
+
+    fn get_at(&self, note_id: &str, now: u64) -> Option<XhsArticle> {
+        let path = self.entry_path(note_id);
+        let content = fs::read_to_string(&path).ok()?;
+        let entry: CacheEntry = match serde_json::from_str(&content) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!("⚠️ Corrupted XHS cache entry {:?}: {}", path, e);
+                let _ = fs::remove_file(&path);
+                return None;
+            }
+        };
+
+        if self.ttl_secs > 0 && now.saturating_sub(entry.cached_at) > self.ttl_secs {
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+
+        info!("💾 XHS cache hit: {}", note_id);
+        let mut article = entry.article;
+        article.note_type = NoteType::from_media(article.video.is_some(), article.images.len());
+        Some(article)
+    }
+
+    fn put_at(&self, note_id: &str, state: &Value, article: &XhsArticle, now: u64) {
+        let entry = CacheEntry {
+            note_id: note_id.to_string(),
+            cached_at: now,
+            state: state.clone(),
+            article: article.clone(),
+        };
+        if let Err(e) = self.write_entry(&entry) {
+            warn!("⚠️ Failed to write XHS cache entry {}: {}", note_id, e);
+            return;
+        }
+        if let Err(e) = self.evict() {
+            warn!("⚠️ Failed to evict XHS cache entries: {}", e);
+        }
+    }
+
+    fn write_entry(&self, entry: &CacheEntry) -> Result<(), ParserError> {
+        let path = self.entry_path(&entry.note_id);
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(entry)?)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+
+    /// 总大小超出上限时，按修改时间从旧到新删除条目
+    fn evict(&self) -> Result<(), ParserError> {
+        if self.max_size_bytes == 0 {
+            return Ok(());
+        }
+
+        let mut entries = Vec::new();
+        for item in fs::read_dir(&self.dir)? {
+            let path = item?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(ENTRY_EXTENSION) {
+                continue;
+            }
+            let meta = fs::metadata(&path)?;
+            let modified = meta.modified().unwrap_or(UNIX_EPOCH);
+            entries.push((modified, meta.len(), path));
+        }
+
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        entries.sort_by_key(|(modified, _, _)| *modified);
+        for (_, len, path) in entries {
+            if total <= self.max_size_bytes {
+                break;
+            }
+            fs::remove_file(&path)?;
+            total -= len;
+        }
+        Ok(())
+    }
+
+    fn entry_path(&self, note_id: &str) -> PathBuf {
+        self.dir.join(note_id).with_extension(ENTRY_EXTENSION)
+    }
+
+    fn short_link_path(&self, short_code: &str) -> PathBuf {
+        self.dir
+            .join(short_code)
+            .with_extension(SHORT_LINK_EXTENSION)
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::xhs::XhsAuthor;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+
+    fn temp_cache(name: &str, ttl_secs: u64, max_size_bytes: u64) -> NoteCache {
+        let dir = std::env::temp_dir().join(format!(
+            "cook_lib_xhs_cache_{}_{}_{}",
+            name,
+            std::process::id(),
+            NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        NoteCache::open(&XhsCacheConfig {
+            cache_dir: dir.to_string_lossy().to_string(),
+            ttl_secs,
+            max_size_bytes,
+            bypass: false,
+        })
+        .expect("创建缓存目录失败")
+    }
+
+    fn article(title: &str) -> XhsArticle {
+        XhsArticle {
+            title: title.to_string(),
+            desc: String::new(),
+            author: XhsAuthor {
+                nickname: "测试厨房".to_string(),
+                user_id: "000000000000000000000001".to_string(),
+                avatar: String::new(),
+            },
+            images: vec!["http://example.com/1.webp".to_string(); 3],
+            video: None,
+            note_type: NoteType::Images,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_restores_note_type() {
+        let cache = temp_cache("roundtrip", 0, 0);
+        cache.put("abc", &json!({ "note": {} }), &article("番茄炒蛋"));
+
+        let cached = cache.get("abc").expect("应该命中缓存");
+        assert_eq!(cached.title, "番茄炒蛋");
+        assert_eq!(cached.note_type, NoteType::Images);
+        assert!(cache.get("missing").is_none());
+
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn test_expired_entry_is_dropped() {
+        let cache = temp_cache("ttl", 60, 0);
+        cache.put_at("abc", &Value::Null, &article("蹄花汤"), 1_000);
+
+        assert!(cache.get_at("abc", 1_060).is_some());
+        assert!(cache.get_at("abc", 1_061).is_none());
+        assert!(!cache.entry_path("abc").exists());
+
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn test_bypass_skips_lookup() {
+        let mut cache = temp_cache("bypass", 0, 0);
+        cache.put("abc", &Value::Null, &article("馄饨"));
+        cache.bypass = true;
+
+        assert!(cache.get("abc").is_none());
+        assert!(cache.entry_path("abc").exists());
+
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn test_size_limit_evicts_oldest() {
+        let mut cache = temp_cache("evict", 0, 0);
+        cache.put("old", &Value::Null, &article("旧笔记"));
+        let old_path = cache.entry_path("old");
+        fs::File::options()
+            .write(true)
+            .open(&old_path)
+            .and_then(|f| f.set_modified(UNIX_EPOCH + std::time::Duration::from_secs(1_000)))
+            .expect("修改时间设置失败");
+
+        let entry_size = fs::metadata(&old_path).unwrap().len();
+        cache.max_size_bytes = entry_size * 3 / 2;
+        cache.put("new", &Value::Null, &article("新笔记"));
+
+        assert!(!old_path.exists());
+        assert!(cache.get("new").is_some());
+
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+
+    #[test]
+    fn test_short_link_maps_to_cached_note() {
+        let cache = temp_cache("short_link", 0, 0);
+        let short = XhsLink {
+            kind: XhsLinkKind::ShortLink,
+            id: "5ZMAfpDOokl".to_string(),
+            xsec_token: None,
+            xsec_source: None,
+            url: "http://xhslink.com/o/5ZMAfpDOokl".to_string(),
+        };
+        assert!(cache.note_id_for(&short).is_none());
+
+        cache.put("abc", &Value::Null, &article("馄饨"));
+        cache.put_short_link("5ZMAfpDOokl", "abc");
+        assert_eq!(cache.note_id_for(&short).as_deref(), Some("abc"));
+
+        let explore = XhsLink {
+            kind: XhsLinkKind::Explore,
+            id: "abc".to_string(),
+            xsec_token: None,
+            xsec_source: None,
+            url: "https://www.xiaohongshu.com/explore/abc".to_string(),
+        };
+        assert_eq!(cache.note_id_for(&explore).as_deref(), Some("abc"));
+
+        let _ = fs::remove_dir_all(&cache.dir);
+    }
+}
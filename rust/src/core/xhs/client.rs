@@ -0,0 +1,172 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::warn;
+use reqwest::cookie::Jar;
+use reqwest::redirect::Policy;
+use reqwest::{Proxy, Url};
+
+use super::ParserError;
+use crate::api::models::xhs::XhsClientConfig;
+
+const USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/125.0.0.0 Safari/537.36";
+
+pub(super) const REFERER_URL: &str = "https://www.xiaohongshu.com/";
+
+/// 未指定 Domain 的初始 Cookie 默认对所有子域生效（www、edith 等）
+const COOKIE_DOMAIN: &str = ".xiaohongshu.com";
+
+pub(super) fn build_blocking_client(
+    config: &XhsClientConfig,
+) -> Result<reqwest::blocking::Client, ParserError> {
+    let mut builder = blocking_builder(config).map_err(ParserError::ClientBuild)?;
+    if let Some(jar) = build_cookie_jar(config) {
+        builder = builder.cookie_provider(jar);
+    }
+    builder.build().map_err(ParserError::ClientBuild)
+}
+
+/// 带超时、重定向与代理设置的阻塞客户端构建器，不含小红书的 Cookie，供其他平台复用
+pub(crate) fn blocking_builder(
+    config: &XhsClientConfig,
+) -> Result<reqwest::blocking::ClientBuilder, reqwest::Error> {
+    let mut builder = reqwest::blocking::Client::builder()
+        .user_agent(USER_AGENT)
+        .connect_timeout(Duration::from_millis(config.connect_timeout_ms))
+        .timeout(Duration::from_millis(config.read_timeout_ms))
+        .redirect(redirect_policy(config));
+    if let Some(proxy) = build_proxy(config)? {
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder)
+}
+
+pub(super) fn build_async_client(config: &XhsClientConfig) -> Result<reqwest::Client, ParserError> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .connect_timeout(Duration::from_millis(config.connect_timeout_ms))
+        .read_timeout(Duration::from_millis(config.read_timeout_ms))
+        .redirect(redirect_policy(config));
+    if let Some(jar) = build_cookie_jar(config) {
+        builder = builder.cookie_provider(jar);
+    }
+    if let Some(proxy) = build_proxy(config).map_err(ParserError::ClientBuild)? {
+        builder = builder.proxy(proxy);
+    }
+    builder.build().map_err(ParserError::ClientBuild)
+}
+
+/// 载入初始 Cookie 的 Cookie 存储；未启用存储且没有初始 Cookie 时为 None
+fn build_cookie_jar(config: &XhsClientConfig) -> Option<Arc<Jar>> {
+    if !config.cookie_store && config.cookies.is_empty() {
+        return None;
+    }
+
+    let jar = Jar::default();
+    let url: Url = REFERER_URL.parse().expect("valid referer url");
+    for cookie in config.cookies.iter().map(|c| c.trim()) {
+        let pair = cookie.split(';').next().unwrap_or_default();
+        if !pair.contains('=') {
+            warn!("⚠️ Ignoring malformed cookie: {:?}", cookie);
+            continue;
+        }
+        let has_domain = cookie
+            .split(';')
+            .skip(1)
+            .any(|attr| attr.trim().to_ascii_lowercase().starts_with("domain="));
+        if has_domain {
+            jar.add_cookie_str(cookie, &url);
+        } else {
+            jar.add_cookie_str(&format!("{cookie}; Domain={COOKIE_DOMAIN}; Path=/"), &url);
+        }
+    }
+    Some(Arc::new(jar))
+}
+
+fn build_proxy(config: &XhsClientConfig) -> Result<Option<Proxy>, reqwest::Error> {
+    config
+        .proxy
+        .as_deref()
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| Proxy::all(p.trim()))
+        .transpose()
+}
+
+fn redirect_policy(config: &XhsClientConfig) -> Policy {
+    match config.max_redirects {
+        0 => Policy::none(),
+        n => Policy::limited(n as usize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_proxy_is_error_not_panic() {
+        let config = XhsClientConfig {
+            proxy: Some("not a proxy url".to_string()),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            build_async_client(&config),
+            Err(ParserError::ClientBuild(_))
+        ));
+        assert!(matches!(
+            build_blocking_client(&config),
+            Err(ParserError::ClientBuild(_))
+        ));
+    }
+
+    #[test]
+    fn test_initial_cookies_are_sent_to_all_subdomains() {
+        use reqwest::cookie::CookieStore;
+
+        let config = XhsClientConfig {
+            cookie_store: false,
+            cookies: vec![
+                "web_session=abc123".to_string(),
+                "a1=xyz; Domain=www.xiaohongshu.com".to_string(),
+                "not a cookie".to_string(),
+            ],
+            ..Default::default()
+        };
+        let jar = build_cookie_jar(&config).expect("有初始 Cookie 时应启用存储");
+
+        let cookies = |url: &str| {
+            jar.cookies(&url.parse().unwrap())
+                .map(|v| v.to_str().unwrap().to_string())
+                .unwrap_or_default()
+        };
+        let www = cookies("https://www.xiaohongshu.com/explore/1");
+        assert!(www.contains("web_session=abc123"));
+        assert!(www.contains("a1=xyz"));
+
+        let api = cookies("https://edith.xiaohongshu.com/api/sns/web/v1/user_posted");
+        assert!(api.contains("web_session=abc123"));
+        assert!(!api.contains("a1="));
+
+        assert!(cookies("https://www.example.com/").is_empty());
+    }
+
+    #[test]
+    fn test_cookie_store_disabled_without_cookies() {
+        let config = XhsClientConfig {
+            cookie_store: false,
+            ..Default::default()
+        };
+        assert!(build_cookie_jar(&config).is_none());
+    }
+
+    #[test]
+    fn test_blank_proxy_is_ignored() {
+        let config = XhsClientConfig {
+            proxy: Some("  ".to_string()),
+            ..Default::default()
+        };
+
+        assert!(build_async_client(&config).is_ok());
+    }
+}
@@ -0,0 +1,270 @@
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+
+use crate::api::models::xhs::{XhsLink, XhsLinkKind};
+
+/// 分享文本中的小红书链接
+///
+/// 查询串只匹配 ASCII 字符，包裹链接的全角标点（`（）【】，。`）不会被吞进来。
+static XHS_LINK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?x)
+        https?://
+        (?:
+            xhslink\.com/(?:[A-Za-z]/)?(?P<short>[A-Za-z0-9]+)
+          |
+            (?:www\.|m\.)?xiaohongshu\.com/
+            (?P<path>explore|discovery/item)/(?P<id>[0-9A-Za-z]+)
+            (?:\?(?P<query>[A-Za-z0-9_\-=&%.~+]*))?
+        )",
+    )
+    .unwrap()
+});
+
+/// 作者主页链接
+static PROFILE_LINK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"https?://(?:www\.|m\.)?xiaohongshu\.com/user/profile/(?P<id>[0-9A-Za-z]+)(?:\?(?P<query>[A-Za-z0-9_\-=&%.~+]*))?",
+    )
+    .unwrap()
+});
+
+const NOTE_URL_PREFIX: &str = "https://www.xiaohongshu.com/explore/";
+const PROFILE_URL_PREFIX: &str = "https://www.xiaohongshu.com/user/profile/";
+
+/// 从任意文本中识别第一个小红书链接
+pub fn classify_link(text: &str) -> Option<XhsLink> {
+    XHS_LINK
+        .captures(text)
+        .map(|caps| link_from_captures(&caps))
+}
+
+/// 笔记的规范链接，保留 `xsec_token`、`xsec_source`
+pub fn canonical_note_url(
+    note_id: &str,
+    xsec_token: Option<&str>,
+    xsec_source: Option<&str>,
+) -> String {
+    with_xsec_params(NOTE_URL_PREFIX, note_id, xsec_token, xsec_source)
+}
+
+/// 从文本中识别作者主页链接，返回用户 id
+pub fn profile_user_id(text: &str) -> Option<String> {
+    PROFILE_LINK
+        .captures(text)
+        .map(|caps| caps["id"].to_string())
+}
+
+/// 从文本中识别作者主页链接，返回保留 `xsec_token`、`xsec_source` 的规范链接
+///
+/// 分享出来的主页链接缺少这两个参数时，网页端通常只返回登录墙。
+pub fn profile_link(text: &str) -> Option<String> {
+    let caps = PROFILE_LINK.captures(text)?;
+    let query = caps.name("query").map(|q| q.as_str()).unwrap_or_default();
+    Some(profile_url(
+        &caps["id"],
+        query_param(query, "xsec_token"),
+        query_param(query, "xsec_source"),
+    ))
+}
+
+/// 作者主页的规范链接
+pub fn profile_url(user_id: &str, xsec_token: Option<&str>, xsec_source: Option<&str>) -> String {
+    with_xsec_params(PROFILE_URL_PREFIX, user_id, xsec_token, xsec_source)
+}
+
+fn with_xsec_params(
+    prefix: &str,
+    id: &str,
+    xsec_token: Option<&str>,
+    xsec_source: Option<&str>,
+) -> String {
+    let params: Vec<String> = [("xsec_token", xsec_token), ("xsec_source", xsec_source)]
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|v| format!("{}={}", key, v)))
+        .collect();
+    if params.is_empty() {
+        format!("{}{}", prefix, id)
+    } else {
+        format!("{}{}?{}", prefix, id, params.join("&"))
+    }
+}
+
+fn link_from_captures(caps: &Captures) -> XhsLink {
+    if let Some(short) = caps.name("short") {
+        return XhsLink {
+            kind: XhsLinkKind::ShortLink,
+            id: short.as_str().to_string(),
+            xsec_token: None,
+            xsec_source: None,
+            url: caps[0].to_string(),
+        };
+    }
+
+    let kind = match &caps["path"] {
+        "explore" => XhsLinkKind::Explore,
+        _ => XhsLinkKind::DiscoveryItem,
+    };
+    let id = caps["id"].to_string();
+    let query = caps.name("query").map(|q| q.as_str()).unwrap_or_default();
+    let xsec_token = query_param(query, "xsec_token").map(str::to_string);
+    let xsec_source = query_param(query, "xsec_source").map(str::to_string);
+
+    XhsLink {
+        kind,
+        url: canonical_note_url(&id, xsec_token.as_deref(), xsec_source.as_deref()),
+        id,
+        xsec_token,
+        xsec_source,
+    }
+}
+
+fn query_param<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, v)| *k == key && !v.is_empty())
+        .map(|(_, v)| v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE_ID: &str = "6751c0a7000000000800c5f3";
+
+    #[test]
+    fn test_classify_link_table() {
+        // (输入, 类型, id, xsec_token, xsec_source, 规范 url)
+        type Case = (
+            &'static str,
+            XhsLinkKind,
+            &'static str,
+            Option<&'static str>,
+            Option<&'static str>,
+            &'static str,
+        );
+        let cases: &[Case] = &[
+            (
+                "家庭版馄饨 http://xhslink.com/o/5ZMAfpDOokl 复制后打开【小红书】查看笔记！",
+                XhsLinkKind::ShortLink,
+                "5ZMAfpDOokl",
+                None,
+                None,
+                "http://xhslink.com/o/5ZMAfpDOokl",
+            ),
+            (
+                "快看 https://xhslink.com/a/abc123XYZ，超好吃",
+                XhsLinkKind::ShortLink,
+                "abc123XYZ",
+                None,
+                None,
+                "https://xhslink.com/a/abc123XYZ",
+            ),
+            (
+                "（http://xhslink.com/Qw3rTy）",
+                XhsLinkKind::ShortLink,
+                "Qw3rTy",
+                None,
+                None,
+                "http://xhslink.com/Qw3rTy",
+            ),
+            (
+                "https://www.xiaohongshu.com/explore/6751c0a7000000000800c5f3",
+                XhsLinkKind::Explore,
+                NOTE_ID,
+                None,
+                None,
+                "https://www.xiaohongshu.com/explore/6751c0a7000000000800c5f3",
+            ),
+            (
+                "【https://www.xiaohongshu.com/explore/6751c0a7000000000800c5f3?xsec_token=ABcd-12%3D&xsec_source=pc_share】",
+                XhsLinkKind::Explore,
+                NOTE_ID,
+                Some("ABcd-12%3D"),
+                Some("pc_share"),
+                "https://www.xiaohongshu.com/explore/6751c0a7000000000800c5f3?xsec_token=ABcd-12%3D&xsec_source=pc_share",
+            ),
+            (
+                "http://xiaohongshu.com/discovery/item/6751c0a7000000000800c5f3?app_platform=ios&xsec_token=tok，复制打开",
+                XhsLinkKind::DiscoveryItem,
+                NOTE_ID,
+                Some("tok"),
+                None,
+                "https://www.xiaohongshu.com/explore/6751c0a7000000000800c5f3?xsec_token=tok",
+            ),
+            (
+                "https://m.xiaohongshu.com/discovery/item/6751c0a7000000000800c5f3?xsec_token=",
+                XhsLinkKind::DiscoveryItem,
+                NOTE_ID,
+                None,
+                None,
+                "https://www.xiaohongshu.com/explore/6751c0a7000000000800c5f3",
+            ),
+        ];
+
+        for (input, kind, id, token, source, url) in cases {
+            let link = classify_link(input).unwrap_or_else(|| panic!("应该能识别: {}", input));
+            assert_eq!(link.kind, *kind, "input: {}", input);
+            assert_eq!(link.id, *id, "input: {}", input);
+            assert_eq!(link.xsec_token.as_deref(), *token, "input: {}", input);
+            assert_eq!(link.xsec_source.as_deref(), *source, "input: {}", input);
+            assert_eq!(link.url, *url, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_classify_link_rejects_unrelated_text() {
+        let cases = [
+            "这是一个没有链接的文本",
+            "https://www.example.com/explore/6751c0a7000000000800c5f3",
+            "https://www.xiaohongshu.com/user/profile/5c1fa2d500000000070373a3",
+        ];
+
+        for input in cases {
+            assert!(classify_link(input).is_none(), "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_profile_user_id() {
+        let text = "快来关注 https://www.xiaohongshu.com/user/profile/000000000000000000000001?xsec_token=abc 吧";
+        let user_id = profile_user_id(text).expect("应该能识别主页链接");
+
+        assert_eq!(user_id, "000000000000000000000001");
+        assert_eq!(
+            profile_url(&user_id, None, None),
+            "https://www.xiaohongshu.com/user/profile/000000000000000000000001"
+        );
+        assert!(profile_user_id("https://www.xiaohongshu.com/explore/abc123").is_none());
+    }
+
+    #[test]
+    fn test_profile_link_keeps_xsec_params() {
+        let text = "快来关注 https://www.xiaohongshu.com/user/profile/000000000000000000000001?xsec_token=AB-1%3D&xsec_source=app_share&type=normal，复制打开";
+        assert_eq!(
+            profile_link(text).as_deref(),
+            Some("https://www.xiaohongshu.com/user/profile/000000000000000000000001?xsec_token=AB-1%3D&xsec_source=app_share")
+        );
+
+        let bare = "https://www.xiaohongshu.com/user/profile/000000000000000000000001";
+        assert_eq!(profile_link(bare).as_deref(), Some(bare));
+        assert!(profile_link("https://www.xiaohongshu.com/explore/abc123").is_none());
+    }
+
+    #[test]
+    fn test_note_id_only_for_direct_links() {
+        let short = classify_link("http://xhslink.com/o/abc").unwrap();
+        assert_eq!(short.note_id(), None);
+
+        let note = classify_link("https://www.xiaohongshu.com/explore/abc123").unwrap();
+        assert_eq!(note.note_id(), Some("abc123"));
+
+        // 带 xsec 参数的链接也以笔记 id 作为缓存键
+        let shared = classify_link(
+            "https://www.xiaohongshu.com/explore/abc123?xsec_token=t&xsec_source=pc_share",
+        )
+        .unwrap();
+        assert_eq!(shared.note_id(), Some("abc123"));
+    }
+}
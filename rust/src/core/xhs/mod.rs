@@ -1,218 +1,337 @@
+use std::fs::File;
+use std::path::Path;
+
 use reqwest::blocking::Client;
+use reqwest::header::REFERER;
 use thiserror::Error;
 
-use crate::api::models::xhs::XhsArticle;
+use crate::api::models::xhs::{
+    XhsArticle, XhsAuthorProfile, XhsClientConfig, XhsLink, XhsLinkKind,
+};
 
+mod async_parser;
+mod cache;
+pub(crate) mod client;
+mod link;
+mod page;
 mod parser;
+mod profile;
+
+pub use async_parser::AsyncXhsParser;
+pub use link::{canonical_note_url, classify_link, profile_link, profile_url, profile_user_id};
+pub use profile::parse_note_page_json;
+
+use cache::NoteCache;
 
 #[derive(Error, Debug)]
 pub enum ParserError {
     #[error("HTTP 请求失败: {0}")]
     Http(#[from] reqwest::Error),
+    #[error("HTTP 客户端创建失败: {0}")]
+    ClientBuild(reqwest::Error),
     #[error("从页面中提取初始数据失败")]
     InitialStateMissing,
     #[error("JSON 数据解析失败: {0}")]
     Json(#[from] serde_json::Error),
     #[error("解析笔记数据失败: {0}")]
     ParseNote(String),
+    #[error("无法识别的笔记链接: {0}")]
+    InvalidLink(String),
+    #[error("笔记不存在或已被删除")]
+    NoteDeleted,
+    #[error("需要登录后才能查看")]
+    LoginRequired,
+    #[error("访问过于频繁，请稍后再试")]
+    RateLimited,
+    #[error("触发安全验证，需要完成验证码")]
+    CaptchaRequired,
+    #[error("笔记在当前地区不可见")]
+    RegionBlocked,
+    #[error("文件写入失败: {0}")]
+    Io(#[from] std::io::Error),
 }
 
+/// 阻塞版解析器，不能在异步运行时中创建或调用，异步场景使用 [`AsyncXhsParser`]
 pub struct XhsParser {
     client: Client,
+    cache: Option<NoteCache>,
 }
 
 impl XhsParser {
-    pub fn new() -> Self {
-        let client = Client::builder()
-            .user_agent("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/125.0.0.0 Safari/537.36")
-            .build()
-            .unwrap();
-        Self { client }
+    pub fn new() -> Result<Self, ParserError> {
+        Self::with_config(&XhsClientConfig::default())
+    }
+
+    pub fn with_config(config: &XhsClientConfig) -> Result<Self, ParserError> {
+        Ok(Self {
+            client: client::build_blocking_client(config)?,
+            cache: config.cache.as_ref().map(NoteCache::open).transpose()?,
+        })
     }
 
     /// 从小红书链接获取文章详情
+    ///
+    /// 配置了缓存时先按笔记 id 查缓存，短链使用记录过的跳转结果，未命中才联网抓取
     pub fn parse_by_url(&self, url: &str) -> Result<XhsArticle, ParserError> {
-        let html = self.fetch_html(url)?;
-        self.parse_from_html(&html)
+        let (Some(cache), Some(link)) = (&self.cache, classify_link(url)) else {
+            let html = self.fetch_html(url)?;
+            return self.parse_from_html(&html);
+        };
+
+        if let Some(article) = cache.note_id_for(&link).and_then(|id| cache.get(&id)) {
+            return Ok(article);
+        }
+
+        let short_code = (link.kind == XhsLinkKind::ShortLink).then(|| link.id.clone());
+        let link = self.resolve_link(&link)?;
+        if let Some(short_code) = short_code {
+            cache.put_short_link(&short_code, &link.id);
+            if let Some(article) = cache.get(&link.id) {
+                return Ok(article);
+            }
+        }
+
+        let html = self.fetch_html(&link.url)?;
+        let (state, article) = parser::parse_note_html_with_state(&html)?;
+        cache.put(&link.id, &state, &article);
+        Ok(article)
+    }
+
+    /// 跟随短链跳转，得到带笔记 id 的规范链接
+    pub fn resolve_link(&self, link: &XhsLink) -> Result<XhsLink, ParserError> {
+        if link.kind != XhsLinkKind::ShortLink {
+            return Ok(link.clone());
+        }
+
+        let resp = self.client.get(&link.url).send()?;
+        if let Some(e) = resp.error_for_status_ref().err() {
+            let status = resp.status().as_u16();
+            let html = resp.text().unwrap_or_default();
+            return Err(page::classify_blocked_status(status, &html).unwrap_or(e.into()));
+        }
+        let final_url = resp.url().as_str();
+        classify_link(final_url)
+            .filter(|resolved| resolved.note_id().is_some())
+            .ok_or_else(|| ParserError::InvalidLink(final_url.to_string()))
     }
 
     /// 从 HTML 内容直接解析
     pub fn parse_from_html(&self, html: &str) -> Result<XhsArticle, ParserError> {
-        let state = parser::extract_initial_state(html)?;
-        parser::build_article_from_state(state)
+        parser::parse_note_html(html)
+    }
+
+    /// 从作者主页链接获取作者信息与首屏笔记
+    pub fn parse_profile_by_url(&self, url: &str) -> Result<XhsAuthorProfile, ParserError> {
+        let html = self.fetch_html(url)?;
+        self.parse_profile_from_html(&html)
+    }
+
+    /// 从作者主页 HTML 直接解析
+    pub fn parse_profile_from_html(&self, html: &str) -> Result<XhsAuthorProfile, ParserError> {
+        profile::parse_profile_html(html)
+    }
+
+    /// 下载笔记视频到本地文件，返回写入的字节数
+    pub fn download_video(&self, play_url: &str, dest: &Path) -> Result<u64, ParserError> {
+        let mut resp = self
+            .client
+            .get(play_url)
+            .header(REFERER, client::REFERER_URL)
+            .send()?
+            .error_for_status()?;
+        let mut file = File::create(dest)?;
+        Ok(resp.copy_to(&mut file)?)
     }
 
-    fn fetch_html(&self, url: &str) -> Result<String, ParserError> {
+    pub(crate) fn fetch_html(&self, url: &str) -> Result<String, ParserError> {
         self.fetch_html_internal(url)
     }
 
+    /// 获取页面 HTML，验证码、限流等拦截状态按页面内容或状态码识别为对应错误
     fn fetch_html_internal(&self, url: &str) -> Result<String, ParserError> {
         let resp = self.client.get(url).send()?;
+        if let Some(e) = resp.error_for_status_ref().err() {
+            let status = resp.status().as_u16();
+            let html = resp.text().unwrap_or_default();
+            return Err(page::classify_blocked_status(status, &html).unwrap_or(e.into()));
+        }
         Ok(resp.text()?)
     }
 }
 
-impl Default for XhsParser {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
-    use std::path::Path;
-    use std::sync::OnceLock;
-
-    static CACHED_HTML: OnceLock<String> = OnceLock::new();
-
-    fn get_test_html() -> String {
-        CACHED_HTML
-            .get_or_init(|| {
-                let temp_path = "/tmp/xhs_test_cache.html";
-                if Path::new(temp_path).exists() {
-                    println!("从缓存读取小红书内容: {}", temp_path);
-                    fs::read_to_string(temp_path).expect("无法读取缓存 HTML")
-                } else {
-                    println!("从小红书实时获取内容...");
-                    let parser = XhsParser::new();
-                    let url = "http://xhslink.com/o/9vGyN9oI440";
-                    let content = parser
-                        .fetch_html_internal(url)
-                        .expect("无法从小红书获取内容");
-                    fs::write(temp_path, &content).expect("无法写入缓存");
-                    println!("缓存已保存到: {}", temp_path);
-                    content
-                }
-            })
-            .clone()
+    use crate::api::models::xhs::{NoteType, XhsCacheConfig};
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    macro_rules! fixture {
+        ($name:literal) => {
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/xhs/",
+                $name
+            ))
+        };
     }
 
-    #[test]
-    fn test_parse_video_note_from_sample_html() {
-        let html = get_test_html();
-
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
-
-        assert_eq!(article.title, "爱死蹄花汤了，有种喝肉的体验！");
-        assert!(!article.desc.is_empty());
-        assert_eq!(article.author.nickname, "开饭啦小志");
-        assert!(!article.author.avatar.is_empty());
-        assert!(!article.author.user_id.is_empty());
-        assert_eq!(article.images.len(), 1);
-        assert!(article.video.is_some());
-
-        let video = article.video.unwrap();
-        assert_eq!(video.duration, 65);
-        assert!(!video.cover.is_empty());
-        assert!(video.play_url.contains("sns-video") && video.play_url.contains("xhscdn.com"));
+    const VIDEO_NOTE: &str = fixture!("video_note.html");
+    const IMAGES_NOTE: &str = fixture!("images_note.html");
+    const MIXED_NOTE: &str = fixture!("mixed_note.html");
+    const TEXT_NOTE: &str = fixture!("text_note.html");
+    const DECOY_STATE: &str = fixture!("decoy_state.html");
+    const DELETED_NOTE: &str = fixture!("deleted_note.html");
+    const LOGIN_WALL: &str = fixture!("login_wall.html");
+    pub(super) const CAPTCHA: &str = fixture!("captcha.html");
+    pub(super) const RATE_LIMITED: &str = fixture!("rate_limited.html");
+    const REGION_BLOCKED: &str = fixture!("region_blocked.html");
+
+    /// 在本地起一个只应答一次的 HTTP 服务，返回其地址
+    pub(super) fn serve_once(status: &'static str, body: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 4096];
+            let _ = stream.read(&mut request);
+            let head = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                body.len()
+            );
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(body).unwrap();
+        });
+        format!("http://{}/explore/1", addr)
     }
 
-    #[test]
-    fn test_extract_author_info() {
-        let html = get_test_html();
-
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
-
-        assert_eq!(article.author.nickname, "开饭啦小志");
-        assert_eq!(article.author.user_id, "5c1fa2d500000000070373a3");
-        assert!(article.author.avatar.contains("xhscdn.com"));
+    fn parse(html: &str) -> Result<XhsArticle, ParserError> {
+        XhsParser::new()
+            .expect("创建解析器失败")
+            .parse_from_html(html)
     }
 
     #[test]
-    fn test_extract_title_and_desc() {
-        let html = get_test_html();
-
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
+    fn test_parse_video_note() {
+        let article = parse(VIDEO_NOTE).expect("解析失败");
 
         assert_eq!(article.title, "爱死蹄花汤了，有种喝肉的体验！");
         assert!(article.desc.contains("慢炖"));
         assert!(article.desc.contains("白芸豆"));
-    }
-
-    #[test]
-    fn test_extract_images() {
-        let html = get_test_html();
-
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
+        assert_eq!(article.note_type, NoteType::Video);
 
         assert_eq!(article.images.len(), 1);
         assert!(article.images[0].contains("sns-webpic-qc.xhscdn.com"));
         assert!(article.images[0].contains("spectrum"));
-    }
-
-    #[test]
-    fn test_extract_video_info() {
-        let html = get_test_html();
-
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
 
-        assert!(article.video.is_some());
-        let video = article.video.unwrap();
+        let video = article.video.expect("应该包含视频");
         assert_eq!(video.duration, 65);
         assert!(video.cover.contains("webp"));
         assert!(video.play_url.contains("mp4"));
         assert!(video.play_url.contains("sns-video") && video.play_url.contains("xhscdn.com"));
     }
 
-    fn get_pure_images_html() -> String {
-        let temp_path = "/tmp/xhs_pure_images.html";
-        if Path::new(temp_path).exists() {
-            println!("从缓存读取纯图片笔记: {}", temp_path);
-            fs::read_to_string(temp_path).expect("无法读取缓存 HTML")
-        } else {
-            println!("从小红书实时获取纯图片笔记...");
-            let parser = XhsParser::new();
-            let url = "http://xhslink.com/o/5ZMAfpDOokl";
-            let content = parser
-                .fetch_html_internal(url)
-                .expect("无法从小红书获取内容");
-            fs::write(temp_path, &content).expect("无法写入缓存");
-            println!("纯图片笔记缓存已保存到: {}", temp_path);
-            content
-        }
+    #[test]
+    fn test_extract_author_info() {
+        let article = parse(VIDEO_NOTE).expect("解析失败");
+
+        assert_eq!(article.author.nickname, "测试厨房1");
+        assert_eq!(article.author.user_id, "000000000000000000000001");
+        assert!(article.author.avatar.contains("xhscdn.com"));
     }
 
     #[test]
     fn test_parse_pure_images_note() {
-        let html = get_pure_images_html();
-
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
+        let article = parse(IMAGES_NOTE).expect("解析失败");
 
         assert_eq!(article.title, "家庭版馄饨｜早餐自制馄饨");
         assert!(!article.desc.is_empty());
-        assert!(!article.author.nickname.is_empty());
-        assert!(!article.author.avatar.is_empty());
-        assert!(!article.author.user_id.is_empty());
-
+        assert_eq!(article.author.nickname, "测试厨房2");
         assert_eq!(article.images.len(), 13);
         assert!(article.video.is_none());
+        assert_eq!(article.note_type, NoteType::Images);
+    }
+
+    #[test]
+    fn test_parse_mixed_note() {
+        let article = parse(MIXED_NOTE).expect("解析失败");
+
+        assert_eq!(article.title, "三分钟学会番茄炒蛋");
+        assert_eq!(article.images.len(), 4);
+        assert_eq!(article.video.map(|v| v.duration), Some(182));
+        assert_eq!(article.note_type, NoteType::Mixed);
+    }
+
+    #[test]
+    fn test_parse_text_note() {
+        let article = parse(TEXT_NOTE).expect("解析失败");
+
+        assert_eq!(article.title, "求推荐好用的铸铁锅");
+        assert!(article.images.is_empty());
+        assert!(article.video.is_none());
+        assert_eq!(article.note_type, NoteType::Text);
+    }
 
-        assert_eq!(article.note_type, crate::api::models::xhs::NoteType::Images);
+    #[test]
+    fn test_decoy_state_scripts_are_skipped() {
+        // 真实数据之前有 `==` 比较、非对象赋值以及无法解析的占位对象
+        let article = parse(DECOY_STATE).expect("应该跳过干扰脚本");
+
+        assert_eq!(article.title, "求推荐好用的铸铁锅");
+        assert_eq!(article.note_type, NoteType::Text);
     }
 
     #[test]
-    fn test_note_type_detection_video() {
-        let html = get_test_html();
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
+    fn test_cached_short_link_needs_no_network() {
+        let dir =
+            std::env::temp_dir().join(format!("cook_lib_xhs_short_link_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache_config = XhsCacheConfig::new(dir.to_string_lossy().to_string());
+        let (state, article) = parser::parse_note_html_with_state(TEXT_NOTE).expect("解析失败");
+        let cache = NoteCache::open(&cache_config).expect("创建缓存失败");
+        cache.put("6751c0a7000000000800c5f3", &state, &article);
+        cache.put_short_link("5ZMAfpDOokl", "6751c0a7000000000800c5f3");
+
+        // 代理指向不可用的端口，一旦联网就会失败
+        let parser = XhsParser::with_config(&XhsClientConfig {
+            proxy: Some("http://127.0.0.1:9".to_string()),
+            cache: Some(cache_config),
+            ..XhsClientConfig::default()
+        })
+        .expect("创建解析器失败");
+        let cached = parser
+            .parse_by_url(
+                "家庭版馄饨 http://xhslink.com/o/5ZMAfpDOokl 复制后打开【小红书】查看笔记！",
+            )
+            .expect("短链应该直接命中缓存");
+        assert_eq!(cached.title, "求推荐好用的铸铁锅");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 
-        assert_eq!(article.note_type, crate::api::models::xhs::NoteType::Video);
+    #[test]
+    fn test_failure_pages() {
+        assert!(matches!(parse(DELETED_NOTE), Err(ParserError::NoteDeleted)));
+        assert!(matches!(parse(LOGIN_WALL), Err(ParserError::LoginRequired)));
+        assert!(matches!(parse(CAPTCHA), Err(ParserError::CaptchaRequired)));
+        assert!(matches!(parse(RATE_LIMITED), Err(ParserError::RateLimited)));
+        assert!(matches!(
+            parse(REGION_BLOCKED),
+            Err(ParserError::RegionBlocked)
+        ));
     }
 
     #[test]
-    fn test_note_type_detection_pure_images() {
-        let html = get_pure_images_html();
-        let parser = XhsParser::new();
-        let article = parser.parse_from_html(&html).expect("解析失败");
+    fn test_blocked_status_pages_are_classified() {
+        let parser = XhsParser::new().expect("创建解析器失败");
+
+        let url = serve_once("461 Unknown", CAPTCHA.as_bytes());
+        let err = parser.parse_by_url(&url).expect_err("验证码页");
+        assert!(matches!(err, ParserError::CaptchaRequired), "{:?}", err);
 
-        assert_eq!(article.note_type, crate::api::models::xhs::NoteType::Images);
+        let url = serve_once("429 Too Many Requests", RATE_LIMITED.as_bytes());
+        let err = parser.parse_by_url(&url).expect_err("限流页");
+        assert!(matches!(err, ParserError::RateLimited), "{:?}", err);
     }
 }
@@ -0,0 +1,142 @@
+use serde_json::Value;
+
+use super::ParserError;
+
+/// 小红书服务端返回的笔记错误码
+const NOTE_NOT_FOUND_CODES: &[i64] = &[-510000, -510001];
+const RATE_LIMITED_CODES: &[i64] = &[300013];
+const REGION_BLOCKED_CODES: &[i64] = &[300012];
+
+const CAPTCHA_MARKERS: &[&str] = &["website-login/captcha", "red-captcha", "__CAPTCHA_CONFIG__"];
+const RATE_LIMITED_MARKERS: &[&str] = &["访问频次异常", "请求太频繁", "操作频繁"];
+const LOGIN_MARKERS: &[&str] = &["登录后查看", "website-login/error", "扫码登录"];
+const REGION_BLOCKED_MARKERS: &[&str] = &["当前地区不可见", "所在地区暂不支持", "该地区无法访问"];
+const NOTE_DELETED_MARKERS: &[&str] = &["笔记不存在", "暂时无法浏览", "笔记已被删除"];
+
+/// 小红书拦截请求时使用的 HTTP 状态码，461/471 为验证码拦截页
+const CAPTCHA_STATUSES: &[u16] = &[461, 471];
+const BLOCKED_STATUSES: &[u16] = &[403, 404, 429, 461, 471];
+
+/// 识别拿不到笔记数据的页面（验证码、限流、登录墙、地区限制、笔记已删除）
+///
+/// 只在正常解析失败后调用，避免正文里恰好出现"登录后查看"之类字样的笔记被误判。
+pub fn classify_blocked_page(html: &str, state: Option<&Value>) -> Option<ParserError> {
+    let server_code = state.and_then(server_error_code);
+    let code_in = |codes: &[i64]| server_code.is_some_and(|c| codes.contains(&c));
+    let has = |markers: &[&str]| markers.iter().any(|m| html.contains(m));
+
+    if has(CAPTCHA_MARKERS) {
+        Some(ParserError::CaptchaRequired)
+    } else if code_in(RATE_LIMITED_CODES) || has(RATE_LIMITED_MARKERS) {
+        Some(ParserError::RateLimited)
+    } else if state.is_some_and(login_requested) || has(LOGIN_MARKERS) {
+        Some(ParserError::LoginRequired)
+    } else if code_in(REGION_BLOCKED_CODES) || has(REGION_BLOCKED_MARKERS) {
+        Some(ParserError::RegionBlocked)
+    } else if code_in(NOTE_NOT_FOUND_CODES) || has(NOTE_DELETED_MARKERS) {
+        Some(ParserError::NoteDeleted)
+    } else {
+        None
+    }
+}
+
+/// 识别非 2xx 响应中的拦截页
+///
+/// 先按页面内容判断，内容无法判断时再按状态码本身：461/471 为验证码、429 为限流、404 为笔记已删除。
+/// 其余状态码（含无法判断内容的 403）返回 None，由调用方保留原始 HTTP 错误。
+pub fn classify_blocked_status(status: u16, html: &str) -> Option<ParserError> {
+    if !BLOCKED_STATUSES.contains(&status) {
+        return None;
+    }
+
+    classify_blocked_page(html, None).or_else(|| match status {
+        s if CAPTCHA_STATUSES.contains(&s) => Some(ParserError::CaptchaRequired),
+        429 => Some(ParserError::RateLimited),
+        404 => Some(ParserError::NoteDeleted),
+        _ => None,
+    })
+}
+
+fn server_error_code(state: &Value) -> Option<i64> {
+    let info = state.get("note")?.get("serverRequestInfo")?;
+    if info.get("state")?.as_str()? != "fail" {
+        return None;
+    }
+    info.get("errorCode")?.as_i64()
+}
+
+fn login_requested(state: &Value) -> bool {
+    state
+        .get("login")
+        .and_then(|l| l.get("showLogin"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn failed_state(code: i64) -> Value {
+        json!({
+            "note": {
+                "noteDetailMap": {},
+                "serverRequestInfo": { "state": "fail", "errorCode": code, "errorMsg": "" }
+            }
+        })
+    }
+
+    #[test]
+    fn test_classify_by_server_error_code() {
+        assert!(matches!(
+            classify_blocked_page("", Some(&failed_state(-510001))),
+            Some(ParserError::NoteDeleted)
+        ));
+        assert!(matches!(
+            classify_blocked_page("", Some(&failed_state(300013))),
+            Some(ParserError::RateLimited)
+        ));
+        assert!(matches!(
+            classify_blocked_page("", Some(&failed_state(300012))),
+            Some(ParserError::RegionBlocked)
+        ));
+        assert!(classify_blocked_page("", Some(&failed_state(1))).is_none());
+    }
+
+    #[test]
+    fn test_successful_request_is_not_classified() {
+        let state = json!({
+            "note": { "serverRequestInfo": { "state": "success", "errorCode": -510001 } }
+        });
+
+        assert!(classify_blocked_page("<html></html>", Some(&state)).is_none());
+    }
+
+    #[test]
+    fn test_classify_by_status() {
+        assert!(matches!(
+            classify_blocked_status(461, ""),
+            Some(ParserError::CaptchaRequired)
+        ));
+        assert!(matches!(
+            classify_blocked_status(471, ""),
+            Some(ParserError::CaptchaRequired)
+        ));
+        assert!(matches!(
+            classify_blocked_status(429, ""),
+            Some(ParserError::RateLimited)
+        ));
+        assert!(matches!(
+            classify_blocked_status(404, ""),
+            Some(ParserError::NoteDeleted)
+        ));
+        // 页面内容优先于状态码
+        assert!(matches!(
+            classify_blocked_status(403, "请先扫码登录"),
+            Some(ParserError::LoginRequired)
+        ));
+        assert!(classify_blocked_status(403, "<html></html>").is_none());
+        assert!(classify_blocked_status(500, "访问频次异常").is_none());
+    }
+}
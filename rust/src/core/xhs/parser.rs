@@ -1,27 +1,80 @@
+use log::debug;
+use once_cell::sync::Lazy;
+use regex::Regex;
 use scraper::{Html, Selector};
 use serde_json::Value;
 
+use crate::core::xhs::page::classify_blocked_page;
 use crate::core::xhs::ParserError;
 use crate::api::models::xhs::{NoteDetail, NoteType, XhsArticle, XhsVideo};
 
+/// 页面初始状态赋值语句，兼容 `window.X=`、`window.X = `、`window["X"]=` 以及不同的变量名
+///
+/// 只匹配单个 `=`，`==`、`===` 比较不算赋值；`value` 为右值的第一个字符
+static STATE_ASSIGNMENT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"window\s*(?:\.\s*(?:__INITIAL_STATE__|__INITIAL_SSR_STATE__|__INITIAL_DATA__)|\[\s*["'](?:__INITIAL_STATE__|__INITIAL_SSR_STATE__|__INITIAL_DATA__)["']\s*\])\s*=\s*(?P<value>[^=\s])"#,
+    )
+    .unwrap()
+});
+
+/// 按出现顺序尝试每一处赋值，跳过右值不是对象字面量或无法解析的候选
+///
+/// 所有候选都失败时，返回第一个解析错误；没有候选时返回 [`ParserError::InitialStateMissing`]
 pub fn extract_initial_state(html: &str) -> Result<Value, ParserError> {
     let document = Html::parse_document(html);
     let script_selector =
         Selector::parse("script").map_err(|_| ParserError::InitialStateMissing)?;
 
+    let mut first_error = None;
     for element in document.select(&script_selector) {
         let script_content = element.inner_html();
-        if let Some(stripped) = script_content.strip_prefix("window.__INITIAL_STATE__=") {
-            let cleaned = sanitize_json(stripped);
-            let value: Value = serde_json::from_str(&cleaned)?;
-            return Ok(value);
+        for caps in STATE_ASSIGNMENT.captures_iter(&script_content) {
+            let start = caps.name("value").map_or(0, |m| m.start());
+            let Some(literal) = sanitize_js_object(&script_content[start..]) else {
+                continue;
+            };
+            match parse_js_object(&literal) {
+                Ok(state) => return Ok(state),
+                Err(e) => {
+                    debug!("Skipping unparsable initial state candidate: {}", e);
+                    first_error.get_or_insert(e);
+                }
+            }
         }
     }
 
-    Err(ParserError::InitialStateMissing)
+    Err(first_error.unwrap_or(ParserError::InitialStateMissing))
+}
+
+/// 解析笔记页面；拿不到笔记数据时识别验证码、登录墙等页面并返回对应错误
+pub fn parse_note_html(html: &str) -> Result<XhsArticle, ParserError> {
+    parse_note_html_with_state(html).map(|(_, article)| article)
 }
 
-pub fn build_article_from_state(state: Value) -> Result<XhsArticle, ParserError> {
+/// 同 [`parse_note_html`]，额外返回页面的初始状态
+pub fn parse_note_html_with_state(html: &str) -> Result<(Value, XhsArticle), ParserError> {
+    let state = match extract_initial_state(html) {
+        Ok(state) => state,
+        Err(ParserError::InitialStateMissing) => {
+            return Err(
+                classify_blocked_page(html, None).unwrap_or(ParserError::InitialStateMissing)
+            )
+        }
+        Err(e) => return Err(e),
+    };
+
+    match build_article_from_state(&state) {
+        Ok(article) => Ok((state, article)),
+        Err(ParserError::InitialStateMissing) => {
+            Err(classify_blocked_page(html, Some(&state))
+                .unwrap_or(ParserError::InitialStateMissing))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+pub fn build_article_from_state(state: &Value) -> Result<XhsArticle, ParserError> {
     let note_map = state
         .get("note")
         .and_then(|n| n.get("noteDetailMap"))
@@ -65,12 +118,7 @@ fn convert_note_to_article(note: NoteDetail) -> Result<XhsArticle, ParserError>
 }
 
 fn determine_note_type(video: &Option<XhsVideo>, images: &[String]) -> NoteType {
-    match (video, images.len()) {
-        (Some(_), 0 | 1) => NoteType::Video,
-        (Some(_), _) => NoteType::Mixed,
-        (None, 0) => NoteType::Text,
-        (None, _) => NoteType::Images,
-    }
+    NoteType::from_media(video.is_some(), images.len())
 }
 
 fn extract_video_info(video_val: &Value) -> Option<XhsVideo> {
@@ -98,6 +146,163 @@ fn extract_video_info(video_val: &Value) -> Option<XhsVideo> {
     })
 }
 
-fn sanitize_json(raw: &str) -> String {
-    raw.replace("undefined", "null")
+/// 先按 JSON 解析，失败时再按 JSON5 解析（单引号、未加引号的键、尾逗号等）
+fn parse_js_object(literal: &str) -> Result<Value, ParserError> {
+    match serde_json::from_str(literal) {
+        Ok(value) => Ok(value),
+        Err(json_err) => json5::from_str(literal).map_err(|_| ParserError::Json(json_err)),
+    }
+}
+
+/// 截取 `src` 开头的 JS 对象字面量，并把字符串之外的裸标识符 `undefined` 改写为 `null`
+///
+/// 对象结束后的内容（`;`、后续语句）会被丢弃；对象不完整时返回 None。
+fn sanitize_js_object(src: &str) -> Option<String> {
+    let src = src.trim_start();
+    if !src.starts_with('{') {
+        return None;
+    }
+
+    let mut out = String::with_capacity(src.len());
+    let mut depth = 0usize;
+    let mut chars = src.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                out.push(c);
+                let mut escaped = false;
+                for (_, sc) in chars.by_ref() {
+                    out.push(sc);
+                    if escaped {
+                        escaped = false;
+                    } else if sc == '\\' {
+                        escaped = true;
+                    } else if sc == c {
+                        break;
+                    }
+                }
+            }
+            '{' | '[' => {
+                depth += 1;
+                out.push(c);
+            }
+            '}' | ']' => {
+                out.push(c);
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(out);
+                }
+            }
+            c if is_ident_char(c) => {
+                let mut end = i + c.len_utf8();
+                while let Some(&(j, nc)) = chars.peek() {
+                    if !is_ident_char(nc) {
+                        break;
+                    }
+                    end = j + nc.len_utf8();
+                    chars.next();
+                }
+                match &src[i..end] {
+                    "undefined" => out.push_str("null"),
+                    ident => out.push_str(ident),
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    None
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '$'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_html(script: &str) -> String {
+        format!(
+            "<html><head><script>var a = 1;</script><script>{}</script></head><body></body></html>",
+            script
+        )
+    }
+
+    #[test]
+    fn test_undefined_inside_strings_is_preserved() {
+        let raw = r#"{"title":"undefined 的做法","desc":'it\'s undefined',"video":undefined,"list":[undefined,1]};"#;
+        let sanitized = sanitize_js_object(raw).unwrap();
+        assert_eq!(
+            sanitized,
+            r#"{"title":"undefined 的做法","desc":'it\'s undefined',"video":null,"list":[null,1]}"#
+        );
+    }
+
+    #[test]
+    fn test_identifiers_containing_undefined_are_untouched() {
+        let sanitized =
+            sanitize_js_object("{isUndefined:true,undefinedCount:2,a:undefined}").unwrap();
+        assert_eq!(sanitized, "{isUndefined:true,undefinedCount:2,a:null}");
+    }
+
+    #[test]
+    fn test_incomplete_object_is_rejected() {
+        assert_eq!(sanitize_js_object(r#"{"a":{"b":1}"#), None);
+        assert_eq!(sanitize_js_object("null"), None);
+        assert_eq!(sanitize_js_object(r#"{"a":"}"#), None);
+    }
+
+    #[test]
+    fn test_extract_state_with_whitespace_and_trailing_code() {
+        let html = state_html(
+            r#"window.__INITIAL_STATE__ = {"note":{"title":"undefined"},"user":undefined} ; window.__LOADED__=true"#,
+        );
+        let state = extract_initial_state(&html).expect("应该能提取");
+        assert_eq!(state["note"]["title"], "undefined");
+        assert!(state["user"].is_null());
+    }
+
+    #[test]
+    fn test_extract_state_with_alternative_names_and_json5() {
+        let cases = [
+            "window.__INITIAL_SSR_STATE__={note:{title:'蹄花汤',},}",
+            r#"window["__INITIAL_DATA__"] = {"note":{"title":"蹄花汤"}};"#,
+            "  window . __INITIAL_STATE__={\"note\":{\"title\":\"蹄花汤\"}}",
+        ];
+
+        for script in cases {
+            let state = extract_initial_state(&state_html(script))
+                .unwrap_or_else(|e| panic!("{} 提取失败: {}", script, e));
+            assert_eq!(state["note"]["title"], "蹄花汤", "script: {}", script);
+        }
+    }
+
+    #[test]
+    fn test_comparison_is_not_an_assignment() {
+        let html = state_html(
+            r#"if (window.__INITIAL_STATE__ == null || window.__INITIAL_STATE__==={}) {} window.__INITIAL_STATE__ = {"note":{"title":"蹄花汤"}}"#,
+        );
+        let state = extract_initial_state(&html).expect("应该跳过比较语句");
+        assert_eq!(state["note"]["title"], "蹄花汤");
+    }
+
+    #[test]
+    fn test_unparsable_candidate_is_reported_when_nothing_else_matches() {
+        let html = state_html(r#"window.__INITIAL_STATE__={"note":}"#);
+        assert!(matches!(
+            extract_initial_state(&html),
+            Err(ParserError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_state() {
+        let html = state_html("window.__OTHER__={}");
+        assert!(matches!(
+            extract_initial_state(&html),
+            Err(ParserError::InitialStateMissing)
+        ));
+    }
 }
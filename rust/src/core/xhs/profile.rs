@@ -0,0 +1,279 @@
+use serde_json::Value;
+
+use super::page::classify_blocked_page;
+use super::parser::extract_initial_state;
+use super::ParserError;
+use crate::api::models::xhs::{NoteType, XhsAuthor, XhsAuthorProfile, XhsNoteCard, XhsNotePage};
+
+/// 解析作者主页；拿不到主页数据时识别验证码、登录墙等页面并返回对应错误
+pub fn parse_profile_html(html: &str) -> Result<XhsAuthorProfile, ParserError> {
+    let state = match extract_initial_state(html) {
+        Ok(state) => state,
+        Err(ParserError::InitialStateMissing) => {
+            return Err(
+                classify_blocked_page(html, None).unwrap_or(ParserError::InitialStateMissing)
+            )
+        }
+        Err(e) => return Err(e),
+    };
+
+    build_profile_from_state(&state).ok_or_else(|| {
+        classify_blocked_page(html, Some(&state)).unwrap_or(ParserError::InitialStateMissing)
+    })
+}
+
+/// 从主页初始状态中提取作者信息与首屏笔记
+pub fn build_profile_from_state(state: &Value) -> Option<XhsAuthorProfile> {
+    let user = state.get("user")?;
+    let page_data = user.get("userPageData")?;
+    let basic = page_data.get("basicInfo")?;
+
+    let user_id = user
+        .get("noteQueries")
+        .and_then(|q| q.get(0))
+        .and_then(|q| q.get("userId"))
+        .or_else(|| basic.get("userId"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+
+    let interaction = |kind: &str| {
+        page_data
+            .get("interactions")
+            .and_then(Value::as_array)
+            .and_then(|items| {
+                items
+                    .iter()
+                    .find(|i| i.get("type").and_then(Value::as_str) == Some(kind))
+            })
+            .and_then(|i| i.get("count"))
+            .map(parse_count)
+            .unwrap_or(0)
+    };
+
+    // notes 按主页 tab 分组（笔记、收藏、点赞），第一组是作者发布的笔记
+    let notes = user
+        .get("notes")
+        .and_then(|n| n.get(0))
+        .and_then(Value::as_array)
+        .map(|cards| cards.iter().filter_map(note_card_from_value).collect())
+        .unwrap_or_default();
+    let query = user.get("noteQueries").and_then(|q| q.get(0));
+
+    Some(XhsAuthorProfile {
+        author: XhsAuthor {
+            nickname: str_field(basic, "nickname")?,
+            user_id: user_id.to_string(),
+            avatar: str_field(basic, "images")
+                .or_else(|| str_field(basic, "imageb"))
+                .unwrap_or_default(),
+        },
+        red_id: str_field(basic, "redId").unwrap_or_default(),
+        bio: str_field(basic, "desc").unwrap_or_default(),
+        ip_location: str_field(basic, "ipLocation").filter(|s| !s.is_empty()),
+        follows: interaction("follows"),
+        fans: interaction("fans"),
+        liked_and_collected: interaction("interaction"),
+        notes: XhsNotePage {
+            notes,
+            cursor: query
+                .and_then(|q| str_field(q, "cursor"))
+                .unwrap_or_default(),
+            has_more: query
+                .and_then(|q| q.get("hasMore"))
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+        },
+    })
+}
+
+/// 解析作者笔记分页接口（`/api/sns/web/v1/user_posted`）的响应
+///
+/// 该接口需要签名请求，这里只负责解析，请求由调用方完成。
+pub fn parse_note_page_json(json: &str) -> Result<XhsNotePage, ParserError> {
+    let resp: Value = serde_json::from_str(json)?;
+    if resp.get("success").and_then(Value::as_bool) != Some(true) {
+        let msg = resp
+            .get("msg")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown error");
+        return Err(ParserError::ParseNote(format!(
+            "笔记列表接口返回失败: {}",
+            msg
+        )));
+    }
+
+    let data = resp
+        .get("data")
+        .ok_or_else(|| ParserError::ParseNote("笔记列表接口缺少 data".to_string()))?;
+    let notes = data
+        .get("notes")
+        .and_then(Value::as_array)
+        .map(|cards| cards.iter().filter_map(note_card_from_value).collect())
+        .unwrap_or_default();
+
+    Ok(XhsNotePage {
+        notes,
+        cursor: str_field(data, "cursor").unwrap_or_default(),
+        has_more: data
+            .get("has_more")
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+    })
+}
+
+/// 笔记卡片，兼容主页状态（驼峰、包在 noteCard 里）与分页接口（下划线）两种结构
+fn note_card_from_value(item: &Value) -> Option<XhsNoteCard> {
+    let card = item.get("noteCard").unwrap_or(item);
+    let field =
+        |camel: &str, snake: &str| str_field(card, camel).or_else(|| str_field(card, snake));
+
+    let note_id = field("noteId", "note_id")
+        .or_else(|| str_field(item, "id"))
+        .filter(|id| !id.is_empty())?;
+    let cover = card
+        .get("cover")
+        .and_then(|c| str_field(c, "urlDefault").or_else(|| str_field(c, "url_default")))
+        .unwrap_or_default();
+    let liked_count = card
+        .get("interactInfo")
+        .or_else(|| card.get("interact_info"))
+        .and_then(|i| i.get("likedCount").or_else(|| i.get("liked_count")))
+        .map(parse_count)
+        .unwrap_or(0);
+    let note_type = match field("type", "type").as_deref() {
+        Some("video") => NoteType::Video,
+        _ => NoteType::Images,
+    };
+    let xsec_token = str_field(item, "xsecToken")
+        .or_else(|| field("xsecToken", "xsec_token"))
+        .filter(|t| !t.is_empty());
+
+    Some(XhsNoteCard {
+        note_id,
+        title: field("displayTitle", "display_title").unwrap_or_default(),
+        cover,
+        note_type,
+        liked_count,
+        xsec_token,
+    })
+}
+
+fn str_field(value: &Value, key: &str) -> Option<String> {
+    value.get(key)?.as_str().map(str::to_string)
+}
+
+/// 解析页面上展示的计数："1024"、"1,024"、"1.2万"、"10万+"、"1.5亿"
+fn parse_count(value: &Value) -> u64 {
+    if let Some(n) = value.as_u64() {
+        return n;
+    }
+    let Some(text) = value.as_str() else {
+        return 0;
+    };
+
+    let text = text.trim().trim_end_matches('+').replace(',', "");
+    let (number, unit) = if let Some(n) = text.strip_suffix('万') {
+        (n, 10_000.0)
+    } else if let Some(n) = text.strip_suffix('亿') {
+        (n, 100_000_000.0)
+    } else {
+        (text.as_str(), 1.0)
+    };
+
+    number
+        .trim()
+        .parse::<f64>()
+        .map(|n| (n * unit).round() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const PROFILE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/xhs/profile.html"
+    ));
+    const USER_POSTED: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/xhs/user_posted.json"
+    ));
+
+    #[test]
+    fn test_parse_profile_page() {
+        let profile = parse_profile_html(PROFILE).expect("解析失败");
+
+        assert_eq!(profile.author.nickname, "测试厨房1");
+        assert_eq!(profile.author.user_id, "000000000000000000000001");
+        assert!(profile.author.avatar.contains("xhscdn.com"));
+        assert_eq!(profile.red_id, "100000001");
+        assert!(profile.bio.contains("家常菜"));
+        assert_eq!(profile.ip_location.as_deref(), Some("四川"));
+        assert_eq!(profile.follows, 128);
+        assert_eq!(profile.fans, 12_000);
+        assert_eq!(profile.liked_and_collected, 356_000);
+
+        let page = &profile.notes;
+        assert_eq!(page.notes.len(), 3);
+        assert!(page.has_more);
+        assert_eq!(page.cursor, "65a0000000000000000000b3");
+
+        let first = &page.notes[0];
+        assert_eq!(first.note_id, "65a0000000000000000000b1");
+        assert_eq!(first.title, "爱死蹄花汤了，有种喝肉的体验！");
+        assert_eq!(first.note_type, NoteType::Video);
+        assert_eq!(first.liked_count, 11_000);
+        assert!(first.cover.contains("sns-webpic-qc.xhscdn.com"));
+        assert_eq!(first.xsec_token.as_deref(), Some("fixture-token-b1"));
+
+        assert_eq!(page.notes[1].note_type, NoteType::Images);
+        assert_eq!(page.notes[1].liked_count, 1024);
+    }
+
+    #[test]
+    fn test_parse_next_note_page() {
+        let page = parse_note_page_json(USER_POSTED).expect("解析失败");
+
+        assert_eq!(page.notes.len(), 2);
+        assert!(!page.has_more);
+        assert!(page.cursor.is_empty());
+        assert_eq!(page.notes[0].note_id, "65a0000000000000000000b4");
+        assert_eq!(page.notes[0].title, "家庭版馄饨｜早餐自制馄饨");
+        assert_eq!(page.notes[0].liked_count, 2_300);
+        assert_eq!(page.notes[1].note_type, NoteType::Video);
+    }
+
+    #[test]
+    fn test_profile_failure_pages() {
+        let login_wall = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/xhs/login_wall.html"
+        ));
+
+        assert!(matches!(
+            parse_profile_html(login_wall),
+            Err(ParserError::LoginRequired)
+        ));
+        assert!(parse_note_page_json(r#"{"success":false,"msg":"登录已过期"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_count() {
+        let cases = [
+            (json!(42), 42),
+            (json!("1024"), 1024),
+            (json!("1,024"), 1024),
+            (json!("1.2万"), 12_000),
+            (json!("10万+"), 100_000),
+            (json!("1.5亿"), 150_000_000),
+            (json!(""), 0),
+            (json!(null), 0),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(parse_count(&input), expected, "input: {}", input);
+        }
+    }
+}
@@ -26,6 +26,7 @@
 // Section: imports
 
 use crate::api::audio::*;
+use crate::api::importer::*;
 use crate::api::video::*;
 use crate::core::audio::error::*;
 use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
@@ -40,7 +41,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -223892292;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -795597414;
 
 // Section: executor
 
@@ -71,12 +72,16 @@ fn wire__crate__api__audio__AudioRecognizer_create_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_models_dir = <String>::sse_decode(&mut deserializer);
+            let api_config = <Option<crate::api::models::asr::AudioRecognizerConfig>>::sse_decode(
+                &mut deserializer,
+            );
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, AudioError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::audio::AudioRecognizer::create(api_models_dir).await?;
+                            crate::api::audio::AudioRecognizer::create(api_models_dir, api_config)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -195,16 +200,17 @@ fn wire__crate__api__audio__AudioRecognizer_transcribe_audio_impl(
         },
     )
 }
-fn wire__crate__api__video__VideoFrameExtractor_create_impl(
+fn wire__crate__api__audio__AudioRecognizer_transcribe_audio_detailed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "VideoFrameExtractor_create",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioRecognizer_transcribe_audio_detailed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -216,24 +222,56 @@ fn wire__crate__api__video__VideoFrameExtractor_create_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>,
+            >>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_language = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::video::VideoFrameExtractor::create())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, AudioError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok =
+                            crate::api::audio::AudioRecognizer::transcribe_audio_detailed(
+                                &*api_that_guard,
+                                api_path,
+                                api_language,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__video__VideoFrameExtractor_process_batch_impl(
+fn wire__crate__api__audio__AudioRecognizer_transcribe_samples_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "VideoFrameExtractor_process_batch",
+            debug_name: "AudioRecognizer_transcribe_samples",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -248,49 +286,60 @@ fn wire__crate__api__video__VideoFrameExtractor_process_batch_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>,
             >>::sse_decode(&mut deserializer);
-            let api_frames =
-                <Vec<crate::core::video::manager::YFrameData>>::sse_decode(&mut deserializer);
+            let api_samples = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_channels = <u16>::sse_decode(&mut deserializer);
+            let api_language = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let mut api_that_guard = None;
-                    let decode_indices_ =
-                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                                &api_that, 0, false,
-                            ),
-                        ]);
-                    for i in decode_indices_ {
-                        match i {
-                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                            _ => unreachable!(),
+            move |context| async move {
+                transform_result_sse::<_, AudioError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
                         }
-                    }
-                    let api_that_guard = api_that_guard.unwrap();
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::video::VideoFrameExtractor::process_batch(
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::audio::AudioRecognizer::transcribe_samples(
                             &*api_that_guard,
-                            api_frames,
-                        ),
-                    )?;
-                    Ok(output_ok)
-                })())
+                            api_samples,
+                            api_sample_rate,
+                            api_channels,
+                            api_language,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__video__VideoFrameExtractor_reset_impl(
+fn wire__crate__api__audio__AudioRecognizer_transcribe_samples_i16_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "VideoFrameExtractor_reset",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "AudioRecognizer_transcribe_samples_i16",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -303,42 +352,60 @@ fn wire__crate__api__video__VideoFrameExtractor_reset_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>,
             >>::sse_decode(&mut deserializer);
+            let api_samples = <Vec<i16>>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_channels = <u16>::sse_decode(&mut deserializer);
+            let api_language = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::video::VideoFrameExtractor::reset(&*api_that_guard);
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, AudioError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::audio::AudioRecognizer::transcribe_samples_i16(
+                            &*api_that_guard,
+                            api_samples,
+                            api_sample_rate,
+                            api_channels,
+                            api_language,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__video__VideoFrameExtractor_stats_impl(
+fn wire__crate__api__importer__RecipeImporter_create_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "VideoFrameExtractor_stats",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "RecipeImporter_create",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -350,42 +417,37 @@ fn wire__crate__api__video__VideoFrameExtractor_stats_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <RustOpaqueMoi<
-                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
-            >>::sse_decode(&mut deserializer);
+            let api_models_dir = <String>::sse_decode(&mut deserializer);
+            let api_work_dir = <String>::sse_decode(&mut deserializer);
+            let api_client_config =
+                <Option<crate::api::models::xhs::XhsClientConfig>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let mut api_that_guard = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that_guard = api_that_guard.unwrap();
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::video::VideoFrameExtractor::stats(&*api_that_guard),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ImportError>(
+                    (move || async move {
+                        let output_ok = crate::api::importer::RecipeImporter::create(
+                            api_models_dir,
+                            api_work_dir,
+                            api_client_config,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__models__xhs__note_type_default_impl(
+fn wire__crate__api__importer__RecipeImporter_import_from_text_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "note_type_default",
+            debug_name: "RecipeImporter_import_from_text",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -399,25 +461,67 @@ fn wire__crate__api__models__xhs__note_type_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>,
+            >>::sse_decode(&mut deserializer);
+            let api_share_text = <String>::sse_decode(&mut deserializer);
+            let api_language = <Option<String>>::sse_decode(&mut deserializer);
+            let api_decode_audio = decode_DartFn_Inputs_String_Output_String_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            let api_extract_frames =
+                decode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            let api_on_progress = decode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::models::xhs::NoteType::default())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, ImportError>(
+                    (move || async move {
+                        let mut api_that_guard = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_guard =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that_guard = api_that_guard.unwrap();
+                        let output_ok = crate::api::importer::RecipeImporter::import_from_text(
+                            &*api_that_guard,
+                            api_share_text,
+                            api_language,
+                            api_decode_audio,
+                            api_extract_frames,
+                            api_on_progress,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__xhs__parse_xhs_from_text_impl(
+fn wire__crate__api__video__VideoFrameExtractor_create_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_xhs_from_text",
+            debug_name: "VideoFrameExtractor_create",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -431,23 +535,79 @@ fn wire__crate__api__xhs__parse_xhs_from_text_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, crate::api::xhs::XhsApiError>((move || {
-                let output_ok = crate::api::xhs::parse_xhs_from_text(api_text)?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::video::VideoFrameExtractor::create())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__xhs__parse_xhs_from_url_impl(
+fn wire__crate__api__video__VideoFrameExtractor_process_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "VideoFrameExtractor_process_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
+            >>::sse_decode(&mut deserializer);
+            let api_frames =
+                <Vec<crate::core::video::manager::YFrameData>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let mut api_that_guard = None;
+                    let decode_indices_ =
+                        flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                            flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                &api_that, 0, false,
+                            ),
+                        ]);
+                    for i in decode_indices_ {
+                        match i {
+                            0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                            _ => unreachable!(),
+                        }
+                    }
+                    let api_that_guard = api_that_guard.unwrap();
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::video::VideoFrameExtractor::process_batch(
+                            &*api_that_guard,
+                            api_frames,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__video__VideoFrameExtractor_reset_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_xhs_from_url",
+            debug_name: "VideoFrameExtractor_reset",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -461,814 +621,5398 @@ fn wire__crate__api__xhs__parse_xhs_from_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::video::VideoFrameExtractor::reset(&*api_that_guard);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__video__VideoFrameExtractor_stats_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "VideoFrameExtractor_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::video::VideoFrameExtractor::stats(&*api_that_guard),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__models__asr__asr_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "asr_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::AsrConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__asr_model_files_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "asr_model_files_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::AsrModelFiles::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__audio_recognizer_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "audio_recognizer_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::asr::AudioRecognizerConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__xhs__classify_xhs_link_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "classify_xhs_link",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, crate::api::xhs::XhsApiError>((move || {
-                let output_ok = crate::api::xhs::parse_xhs_from_url(api_url)?;
+                let output_ok = crate::api::xhs::classify_xhs_link(api_text)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>
+fn wire__crate__api__models__asr__diarization_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "diarization_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::DiarizationConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__endpoint_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "endpoint_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::EndpointConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__event_detection_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "event_detection_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::asr::EventDetectionConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__hotword_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hotword_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::HotwordConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__loudness_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "loudness_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::LoudnessConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__manifest__model_validation_report_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "model_validation_report_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::manifest::ModelValidationReport::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__manifest__model_validation_report_is_valid_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "model_validation_report_is_valid",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::models::manifest::ModelValidationReport>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::manifest::ModelValidationReport::is_valid(&api_that),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__noise_reduction_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "noise_reduction_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::asr::NoiseReductionConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__xhs__note_type_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "note_type_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::xhs::NoteType::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__xhs__parse_xhs_from_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_xhs_from_text",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::models::xhs::XhsClientConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::xhs::XhsApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::xhs::parse_xhs_from_text(api_text, api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__xhs__parse_xhs_from_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_xhs_from_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::models::xhs::XhsClientConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::xhs::XhsApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::xhs::parse_xhs_from_url(api_url, api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__xhs__parse_xhs_note_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_xhs_note_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::xhs::XhsApiError>((move || {
+                let output_ok = crate::api::xhs::parse_xhs_note_page(api_json)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhs__parse_xhs_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_xhs_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::models::xhs::XhsClientConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::xhs::XhsApiError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::xhs::parse_xhs_profile(api_text, api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__models__recipe__parsed_recipe_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parsed_recipe_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::recipe::ParsedRecipe::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__postprocess_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "postprocess_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::PostprocessConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__preprocess_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preprocess_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::PreprocessConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__recognizer_pool_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "recognizer_pool_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::asr::RecognizerPoolConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__resample_quality_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resample_quality_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::ResampleQuality::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__transcript__segmentation_path_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "segmentation_path_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::transcript::SegmentationPath::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__source__source_author_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "source_author_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::source::SourceAuthor::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__transcript__transcript_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "transcript_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::transcript::Transcript::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__vad_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vad_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::VadConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__asr__vad_fallback_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vad_fallback_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::asr::VadFallback::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__audio__validate_models_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_models",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_models_dir = <String>::sse_decode(&mut deserializer);
+            let api_verify_checksums = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, AudioError>(
+                    (move || async move {
+                        let output_ok = crate::api::audio::validate_models(
+                            api_models_dir,
+                            api_verify_checksums,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__xhs__xhs_api_error_code_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhs_api_error_code",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::xhs::XhsApiError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::xhs::XhsApiError::code(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__xhs__xhs_api_error_user_message_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhs_api_error_user_message",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::xhs::XhsApiError>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::xhs::XhsApiError::user_message(&api_that))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__models__xhs__xhs_cache_config_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhs_cache_config_new",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cache_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::models::xhs::XhsCacheConfig::new(api_cache_dir),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__xhs__xhs_client_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhs_client_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::xhs::XhsClientConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__models__xhs__xhs_note_page_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "xhs_note_page_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::models::xhs::XhsNotePage::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+fn decode_DartFn_Inputs_String_Output_String_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<String> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: String) -> String {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<String>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_String_Output_list_y_frame_data_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(String) -> flutter_rust_bridge::DartFnFuture<Vec<crate::core::video::manager::YFrameData>>
+{
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: String,
+    ) -> Vec<crate::core::video::manager::YFrameData> {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(
+                <Vec<crate::core::video::manager::YFrameData>>::sse_decode(&mut deserializer),
+            ),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: String| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_import_progress_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::api::models::recipe::ImportProgress) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::models::recipe::ImportProgress,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::models::recipe::ImportProgress| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>
 );
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>
 );
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>
+);
 flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
     flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>
 );
 
-// Section: dart2rust
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for AudioError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for AudioRecognizer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for ImportError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for RecipeImporter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for VideoFrameExtractor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::models::asr::AsrConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_decodingMethod =
+            <crate::api::models::asr::DecodingMethod>::sse_decode(deserializer);
+        let mut var_numActivePaths = <u32>::sse_decode(deserializer);
+        let mut var_numThreads = <u32>::sse_decode(deserializer);
+        let mut var_endpoint = <crate::api::models::asr::EndpointConfig>::sse_decode(deserializer);
+        let mut var_modelFiles = <crate::api::models::asr::AsrModelFiles>::sse_decode(deserializer);
+        return crate::api::models::asr::AsrConfig {
+            decoding_method: var_decodingMethod,
+            num_active_paths: var_numActivePaths,
+            num_threads: var_numThreads,
+            endpoint: var_endpoint,
+            model_files: var_modelFiles,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::AsrModelFiles {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_encoderParam = <String>::sse_decode(deserializer);
+        let mut var_encoderBin = <String>::sse_decode(deserializer);
+        let mut var_decoderParam = <String>::sse_decode(deserializer);
+        let mut var_decoderBin = <String>::sse_decode(deserializer);
+        let mut var_joinerParam = <String>::sse_decode(deserializer);
+        let mut var_joinerBin = <String>::sse_decode(deserializer);
+        let mut var_tokens = <String>::sse_decode(deserializer);
+        return crate::api::models::asr::AsrModelFiles {
+            encoder_param: var_encoderParam,
+            encoder_bin: var_encoderBin,
+            decoder_param: var_decoderParam,
+            decoder_bin: var_decoderBin,
+            joiner_param: var_joinerParam,
+            joiner_bin: var_joinerBin,
+            tokens: var_tokens,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::transcript::AudioEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind =
+            <crate::api::models::transcript::AudioEventKind>::sse_decode(deserializer);
+        let mut var_startMs = <u64>::sse_decode(deserializer);
+        let mut var_endMs = <u64>::sse_decode(deserializer);
+        let mut var_confidence = <f32>::sse_decode(deserializer);
+        return crate::api::models::transcript::AudioEvent {
+            kind: var_kind,
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            confidence: var_confidence,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::transcript::AudioEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::transcript::AudioEventKind::Sizzle,
+            1 => crate::api::models::transcript::AudioEventKind::Boiling,
+            2 => crate::api::models::transcript::AudioEventKind::TimerBeep,
+            3 => crate::api::models::transcript::AudioEventKind::Blender,
+            _ => unreachable!("Invalid variant for AudioEventKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::AudioRecognizerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hotwords =
+            <Option<crate::api::models::asr::HotwordConfig>>::sse_decode(deserializer);
+        let mut var_asr = <crate::api::models::asr::AsrConfig>::sse_decode(deserializer);
+        let mut var_modelPacks =
+            <Vec<crate::api::models::asr::ModelPackConfig>>::sse_decode(deserializer);
+        let mut var_pool =
+            <crate::api::models::asr::RecognizerPoolConfig>::sse_decode(deserializer);
+        let mut var_vad = <crate::api::models::asr::VadConfig>::sse_decode(deserializer);
+        let mut var_vadFallback = <crate::api::models::asr::VadFallback>::sse_decode(deserializer);
+        let mut var_diarization =
+            <Option<crate::api::models::asr::DiarizationConfig>>::sse_decode(deserializer);
+        let mut var_postprocess =
+            <crate::api::models::asr::PostprocessConfig>::sse_decode(deserializer);
+        let mut var_resampleQuality =
+            <crate::api::models::asr::ResampleQuality>::sse_decode(deserializer);
+        let mut var_preprocess =
+            <Option<crate::api::models::asr::PreprocessConfig>>::sse_decode(deserializer);
+        let mut var_events =
+            <Option<crate::api::models::asr::EventDetectionConfig>>::sse_decode(deserializer);
+        return crate::api::models::asr::AudioRecognizerConfig {
+            hotwords: var_hotwords,
+            asr: var_asr,
+            model_packs: var_modelPacks,
+            pool: var_pool,
+            vad: var_vad,
+            vad_fallback: var_vadFallback,
+            diarization: var_diarization,
+            postprocess: var_postprocess,
+            resample_quality: var_resampleQuality,
+            preprocess: var_preprocess,
+            events: var_events,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::models::asr::DecodingMethod {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::asr::DecodingMethod::GreedySearch,
+            1 => crate::api::models::asr::DecodingMethod::ModifiedBeamSearch,
+            _ => unreachable!("Invalid variant for DecodingMethod: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::DiarizationConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxSpeakers = <u32>::sse_decode(deserializer);
+        let mut var_threshold = <f32>::sse_decode(deserializer);
+        let mut var_minSegmentMs = <u32>::sse_decode(deserializer);
+        return crate::api::models::asr::DiarizationConfig {
+            max_speakers: var_maxSpeakers,
+            threshold: var_threshold,
+            min_segment_ms: var_minSegmentMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::EndpointConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enable = <bool>::sse_decode(deserializer);
+        let mut var_rule1MinTrailingSilence = <f32>::sse_decode(deserializer);
+        let mut var_rule2MinTrailingSilence = <f32>::sse_decode(deserializer);
+        let mut var_rule3MinUtteranceLength = <f32>::sse_decode(deserializer);
+        return crate::api::models::asr::EndpointConfig {
+            enable: var_enable,
+            rule1_min_trailing_silence: var_rule1MinTrailingSilence,
+            rule2_min_trailing_silence: var_rule2MinTrailingSilence,
+            rule3_min_utterance_length: var_rule3MinUtteranceLength,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::EventDetectionConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minEventMs = <u32>::sse_decode(deserializer);
+        let mut var_minConfidence = <f32>::sse_decode(deserializer);
+        return crate::api::models::asr::EventDetectionConfig {
+            min_event_ms: var_minEventMs,
+            min_confidence: var_minConfidence,
+        };
+    }
+}
+
+impl SseDecode for crate::core::video::manager::ExtractionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_processedFrames = <u64>::sse_decode(deserializer);
+        let mut var_extractedFrames = <u64>::sse_decode(deserializer);
+        return crate::core::video::manager::ExtractionStats {
+            processed_frames: var_processedFrames,
+            extracted_frames: var_extractedFrames,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::core::video::manager::FrameExtractedInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        let mut var_frameNumber = <u64>::sse_decode(deserializer);
+        let mut var_confidence = <f32>::sse_decode(deserializer);
+        let mut var_jpegData = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        return crate::core::video::manager::FrameExtractedInfo {
+            timestamp_ms: var_timestampMs,
+            frame_number: var_frameNumber,
+            confidence: var_confidence,
+            jpeg_data: var_jpegData,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::Hotword {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phrase = <String>::sse_decode(deserializer);
+        let mut var_score = <Option<f32>>::sse_decode(deserializer);
+        return crate::api::models::asr::Hotword {
+            phrase: var_phrase,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::HotwordConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hotwords = <Vec<crate::api::models::asr::Hotword>>::sse_decode(deserializer);
+        let mut var_useCookingLexicon = <bool>::sse_decode(deserializer);
+        let mut var_defaultScore = <f32>::sse_decode(deserializer);
+        let mut var_workDir = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::asr::HotwordConfig {
+            hotwords: var_hotwords,
+            use_cooking_lexicon: var_useCookingLexicon,
+            default_score: var_defaultScore,
+            work_dir: var_workDir,
+        };
+    }
+}
+
+impl SseDecode for i16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::models::recipe::ImportProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage = <crate::api::models::recipe::ImportStage>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::models::recipe::ImportProgress {
+            stage: var_stage,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::recipe::ImportStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::recipe::ImportStage::FetchingNote,
+            1 => crate::api::models::recipe::ImportStage::DownloadingVideo,
+            2 => crate::api::models::recipe::ImportStage::DecodingAudio,
+            3 => crate::api::models::recipe::ImportStage::Transcribing,
+            4 => crate::api::models::recipe::ImportStage::ExtractingFrames,
+            5 => crate::api::models::recipe::ImportStage::ParsingRecipe,
+            6 => crate::api::models::recipe::ImportStage::Complete,
+            _ => unreachable!("Invalid variant for ImportStage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::recipe::ImportedRecipe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceUrl = <String>::sse_decode(deserializer);
+        let mut var_article = <crate::api::models::source::SourceArticle>::sse_decode(deserializer);
+        let mut var_transcript = <Option<String>>::sse_decode(deserializer);
+        let mut var_keyframes =
+            <Vec<crate::core::video::manager::FrameExtractedInfo>>::sse_decode(deserializer);
+        let mut var_recipe = <crate::api::models::recipe::ParsedRecipe>::sse_decode(deserializer);
+        return crate::api::models::recipe::ImportedRecipe {
+            source_url: var_sourceUrl,
+            article: var_article,
+            transcript: var_transcript,
+            keyframes: var_keyframes,
+            recipe: var_recipe,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::transcript::AudioEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::transcript::AudioEvent>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::video::manager::FrameExtractedInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::video::manager::FrameExtractedInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::asr::Hotword> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::asr::Hotword>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::manifest::ModelFileIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::manifest::ModelFileIssue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::asr::ModelPackConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::asr::ModelPackConfig>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::recipe::RecipeIngredient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::recipe::RecipeIngredient>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::transcript::TranscriptSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::models::transcript::TranscriptSegment>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::transcript::TranscriptToken> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::transcript::TranscriptToken>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::models::xhs::XhsNoteCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::models::xhs::XhsNoteCard>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::core::video::manager::YFrameData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::core::video::manager::YFrameData>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::models::asr::LoudnessConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_measure = <crate::api::models::asr::LoudnessMeasure>::sse_decode(deserializer);
+        let mut var_targetDb = <f32>::sse_decode(deserializer);
+        let mut var_maxGainDb = <f32>::sse_decode(deserializer);
+        let mut var_windowMs = <u32>::sse_decode(deserializer);
+        return crate::api::models::asr::LoudnessConfig {
+            measure: var_measure,
+            target_db: var_targetDb,
+            max_gain_db: var_maxGainDb,
+            window_ms: var_windowMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::LoudnessMeasure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::asr::LoudnessMeasure::Rms,
+            1 => crate::api::models::asr::LoudnessMeasure::Lufs,
+            _ => unreachable!("Invalid variant for LoudnessMeasure: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::manifest::ModelFileIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::models::manifest::ModelIssueKind>::sse_decode(deserializer);
+        return crate::api::models::manifest::ModelFileIssue {
+            path: var_path,
+            kind: var_kind,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::manifest::ModelIssueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::models::manifest::ModelIssueKind::Missing;
+            }
+            1 => {
+                return crate::api::models::manifest::ModelIssueKind::InvalidPath;
+            }
+            2 => {
+                let mut var_expected = <u64>::sse_decode(deserializer);
+                let mut var_actual = <u64>::sse_decode(deserializer);
+                return crate::api::models::manifest::ModelIssueKind::SizeMismatch {
+                    expected: var_expected,
+                    actual: var_actual,
+                };
+            }
+            3 => {
+                let mut var_expected = <String>::sse_decode(deserializer);
+                let mut var_actual = <String>::sse_decode(deserializer);
+                return crate::api::models::manifest::ModelIssueKind::ChecksumMismatch {
+                    expected: var_expected,
+                    actual: var_actual,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::models::asr::ModelPackConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_dir = <String>::sse_decode(deserializer);
+        let mut var_languages = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::models::asr::ModelPackConfig {
+            dir: var_dir,
+            languages: var_languages,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::manifest::ModelValidationReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_checked = <u32>::sse_decode(deserializer);
+        let mut var_checksumsVerified = <bool>::sse_decode(deserializer);
+        let mut var_issues =
+            <Vec<crate::api::models::manifest::ModelFileIssue>>::sse_decode(deserializer);
+        return crate::api::models::manifest::ModelValidationReport {
+            checked: var_checked,
+            checksums_verified: var_checksumsVerified,
+            issues: var_issues,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::NoiseReductionConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_overSubtraction = <f32>::sse_decode(deserializer);
+        let mut var_floorDb = <f32>::sse_decode(deserializer);
+        return crate::api::models::asr::NoiseReductionConfig {
+            over_subtraction: var_overSubtraction,
+            floor_db: var_floorDb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::NoteType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::xhs::NoteType::Video,
+            1 => crate::api::models::xhs::NoteType::Images,
+            2 => crate::api::models::xhs::NoteType::Mixed,
+            3 => crate::api::models::xhs::NoteType::Text,
+            _ => unreachable!("Invalid variant for NoteType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::asr::AudioRecognizerConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::models::asr::AudioRecognizerConfig>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::asr::DiarizationConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::asr::DiarizationConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::asr::EventDetectionConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::asr::EventDetectionConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::asr::HotwordConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::asr::HotwordConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::asr::LoudnessConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::asr::LoudnessConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::asr::NoiseReductionConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::asr::NoiseReductionConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::recipe::ParsedRecipe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::recipe::ParsedRecipe>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::asr::PreprocessConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::asr::PreprocessConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::source::SourceVideo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::source::SourceVideo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::xhs::XhsCacheConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::xhs::XhsCacheConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::xhs::XhsClientConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::xhs::XhsClientConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::models::xhs::XhsVideo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::models::xhs::XhsVideo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::models::recipe::ParsedRecipe {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_ingredients =
+            <Vec<crate::api::models::recipe::RecipeIngredient>>::sse_decode(deserializer);
+        let mut var_steps = <Vec<String>>::sse_decode(deserializer);
+        let mut var_tips = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::models::recipe::ParsedRecipe {
+            title: var_title,
+            ingredients: var_ingredients,
+            steps: var_steps,
+            tips: var_tips,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::source::Platform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::source::Platform::Xiaohongshu,
+            1 => crate::api::models::source::Platform::Xiachufang,
+            _ => unreachable!("Invalid variant for Platform: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::PostprocessConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_punctuation =
+            <crate::api::models::asr::PunctuationMode>::sse_decode(deserializer);
+        let mut var_mixedEnglish = <bool>::sse_decode(deserializer);
+        let mut var_inverseTextNormalization = <bool>::sse_decode(deserializer);
+        return crate::api::models::asr::PostprocessConfig {
+            punctuation: var_punctuation,
+            mixed_english: var_mixedEnglish,
+            inverse_text_normalization: var_inverseTextNormalization,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::PreprocessConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_removeDc = <bool>::sse_decode(deserializer);
+        let mut var_highPassHz = <u32>::sse_decode(deserializer);
+        let mut var_noiseReduction =
+            <Option<crate::api::models::asr::NoiseReductionConfig>>::sse_decode(deserializer);
+        let mut var_loudness =
+            <Option<crate::api::models::asr::LoudnessConfig>>::sse_decode(deserializer);
+        return crate::api::models::asr::PreprocessConfig {
+            remove_dc: var_removeDc,
+            high_pass_hz: var_highPassHz,
+            noise_reduction: var_noiseReduction,
+            loudness: var_loudness,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::PunctuationMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::models::asr::PunctuationMode::Off;
+            }
+            1 => {
+                let mut var_pauseMs = <u32>::sse_decode(deserializer);
+                return crate::api::models::asr::PunctuationMode::Rules {
+                    pause_ms: var_pauseMs,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::models::recipe::RecipeIngredient {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_amount = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::recipe::RecipeIngredient {
+            name: var_name,
+            amount: var_amount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::RecognizerPoolConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxInstances = <u32>::sse_decode(deserializer);
+        let mut var_maxMemoryMb = <u32>::sse_decode(deserializer);
+        return crate::api::models::asr::RecognizerPoolConfig {
+            max_instances: var_maxInstances,
+            max_memory_mb: var_maxMemoryMb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::ResampleQuality {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::asr::ResampleQuality::Fast,
+            1 => crate::api::models::asr::ResampleQuality::Balanced,
+            2 => crate::api::models::asr::ResampleQuality::High,
+            _ => unreachable!("Invalid variant for ResampleQuality: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::transcript::SegmentationPath {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::transcript::SegmentationPath::Vad,
+            1 => crate::api::models::transcript::SegmentationPath::FixedWindow,
+            2 => crate::api::models::transcript::SegmentationPath::WholeFile,
+            _ => unreachable!("Invalid variant for SegmentationPath: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::source::SourceArticle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_platform = <crate::api::models::source::Platform>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_desc = <String>::sse_decode(deserializer);
+        let mut var_author = <crate::api::models::source::SourceAuthor>::sse_decode(deserializer);
+        let mut var_images = <Vec<String>>::sse_decode(deserializer);
+        let mut var_video =
+            <Option<crate::api::models::source::SourceVideo>>::sse_decode(deserializer);
+        let mut var_noteType = <crate::api::models::xhs::NoteType>::sse_decode(deserializer);
+        let mut var_recipe =
+            <Option<crate::api::models::recipe::ParsedRecipe>>::sse_decode(deserializer);
+        return crate::api::models::source::SourceArticle {
+            platform: var_platform,
+            title: var_title,
+            desc: var_desc,
+            author: var_author,
+            images: var_images,
+            video: var_video,
+            note_type: var_noteType,
+            recipe: var_recipe,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::source::SourceAuthor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_avatar = <String>::sse_decode(deserializer);
+        return crate::api::models::source::SourceAuthor {
+            name: var_name,
+            id: var_id,
+            avatar: var_avatar,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::source::SourceVideo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_duration = <i64>::sse_decode(deserializer);
+        let mut var_cover = <String>::sse_decode(deserializer);
+        let mut var_playUrl = <String>::sse_decode(deserializer);
+        return crate::api::models::source::SourceVideo {
+            duration: var_duration,
+            cover: var_cover,
+            play_url: var_playUrl,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::transcript::Transcript {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_segments =
+            <Vec<crate::api::models::transcript::TranscriptSegment>>::sse_decode(deserializer);
+        let mut var_segmentation =
+            <crate::api::models::transcript::SegmentationPath>::sse_decode(deserializer);
+        let mut var_fallbackReason = <Option<String>>::sse_decode(deserializer);
+        let mut var_modelPack = <String>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_languageDetected = <bool>::sse_decode(deserializer);
+        let mut var_events =
+            <Vec<crate::api::models::transcript::AudioEvent>>::sse_decode(deserializer);
+        return crate::api::models::transcript::Transcript {
+            segments: var_segments,
+            segmentation: var_segmentation,
+            fallback_reason: var_fallbackReason,
+            model_pack: var_modelPack,
+            language: var_language,
+            language_detected: var_languageDetected,
+            events: var_events,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::transcript::TranscriptSegment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startMs = <u64>::sse_decode(deserializer);
+        let mut var_endMs = <u64>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_tokens =
+            <Vec<crate::api::models::transcript::TranscriptToken>>::sse_decode(deserializer);
+        let mut var_speaker = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::models::transcript::TranscriptSegment {
+            start_ms: var_startMs,
+            end_ms: var_endMs,
+            text: var_text,
+            tokens: var_tokens,
+            speaker: var_speaker,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::transcript::TranscriptToken {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <String>::sse_decode(deserializer);
+        let mut var_startMs = <u64>::sse_decode(deserializer);
+        let mut var_confidence = <Option<f32>>::sse_decode(deserializer);
+        return crate::api::models::transcript::TranscriptToken {
+            token: var_token,
+            start_ms: var_startMs,
+            confidence: var_confidence,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::models::asr::VadConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_threshold = <f32>::sse_decode(deserializer);
+        let mut var_minSilenceDurationMs = <u32>::sse_decode(deserializer);
+        let mut var_minSpeechDurationMs = <u32>::sse_decode(deserializer);
+        let mut var_numThreads = <u32>::sse_decode(deserializer);
+        let mut var_bufferSizeSecs = <f32>::sse_decode(deserializer);
+        let mut var_padMs = <u32>::sse_decode(deserializer);
+        let mut var_mergeGapMs = <u32>::sse_decode(deserializer);
+        let mut var_maxSegmentMs = <u32>::sse_decode(deserializer);
+        return crate::api::models::asr::VadConfig {
+            threshold: var_threshold,
+            min_silence_duration_ms: var_minSilenceDurationMs,
+            min_speech_duration_ms: var_minSpeechDurationMs,
+            num_threads: var_numThreads,
+            buffer_size_secs: var_bufferSizeSecs,
+            pad_ms: var_padMs,
+            merge_gap_ms: var_mergeGapMs,
+            max_segment_ms: var_maxSegmentMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::asr::VadFallback {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::models::asr::VadFallback::Error;
+            }
+            1 => {
+                let mut var_windowMs = <u32>::sse_decode(deserializer);
+                let mut var_overlapMs = <u32>::sse_decode(deserializer);
+                return crate::api::models::asr::VadFallback::FixedWindow {
+                    window_ms: var_windowMs,
+                    overlap_ms: var_overlapMs,
+                };
+            }
+            2 => {
+                return crate::api::models::asr::VadFallback::WholeFile;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::xhs::XhsApiError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::xhs::XhsApiError::UrlNotFound;
+            }
+            1 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::api::xhs::XhsApiError::InvalidLink { url: var_url };
+            }
+            2 => {
+                let mut var_status = <u16>::sse_decode(deserializer);
+                let mut var_url = <Option<String>>::sse_decode(deserializer);
+                return crate::api::xhs::XhsApiError::HttpStatus {
+                    status: var_status,
+                    url: var_url,
+                };
+            }
+            3 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                let mut var_timeout = <bool>::sse_decode(deserializer);
+                return crate::api::xhs::XhsApiError::Network {
+                    message: var_message,
+                    timeout: var_timeout,
+                };
+            }
+            4 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::xhs::XhsApiError::ClientConfig {
+                    message: var_message,
+                };
+            }
+            5 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::xhs::XhsApiError::Parse {
+                    message: var_message,
+                };
+            }
+            6 => {
+                let mut var_reason = <crate::api::xhs::XhsBlockReason>::sse_decode(deserializer);
+                return crate::api::xhs::XhsApiError::Blocked { reason: var_reason };
+            }
+            7 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::api::xhs::XhsApiError::Io {
+                    message: var_message,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsArticle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_desc = <String>::sse_decode(deserializer);
+        let mut var_author = <crate::api::models::xhs::XhsAuthor>::sse_decode(deserializer);
+        let mut var_images = <Vec<String>>::sse_decode(deserializer);
+        let mut var_video = <Option<crate::api::models::xhs::XhsVideo>>::sse_decode(deserializer);
+        let mut var_noteType = <crate::api::models::xhs::NoteType>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsArticle {
+            title: var_title,
+            desc: var_desc,
+            author: var_author,
+            images: var_images,
+            video: var_video,
+            note_type: var_noteType,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsAuthor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nickname = <String>::sse_decode(deserializer);
+        let mut var_userId = <String>::sse_decode(deserializer);
+        let mut var_avatar = <String>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsAuthor {
+            nickname: var_nickname,
+            user_id: var_userId,
+            avatar: var_avatar,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsAuthorProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_author = <crate::api::models::xhs::XhsAuthor>::sse_decode(deserializer);
+        let mut var_redId = <String>::sse_decode(deserializer);
+        let mut var_bio = <String>::sse_decode(deserializer);
+        let mut var_ipLocation = <Option<String>>::sse_decode(deserializer);
+        let mut var_follows = <u64>::sse_decode(deserializer);
+        let mut var_fans = <u64>::sse_decode(deserializer);
+        let mut var_likedAndCollected = <u64>::sse_decode(deserializer);
+        let mut var_notes = <crate::api::models::xhs::XhsNotePage>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsAuthorProfile {
+            author: var_author,
+            red_id: var_redId,
+            bio: var_bio,
+            ip_location: var_ipLocation,
+            follows: var_follows,
+            fans: var_fans,
+            liked_and_collected: var_likedAndCollected,
+            notes: var_notes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::xhs::XhsBlockReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::xhs::XhsBlockReason::NoteDeleted,
+            1 => crate::api::xhs::XhsBlockReason::LoginRequired,
+            2 => crate::api::xhs::XhsBlockReason::RateLimited,
+            3 => crate::api::xhs::XhsBlockReason::CaptchaRequired,
+            4 => crate::api::xhs::XhsBlockReason::RegionBlocked,
+            _ => unreachable!("Invalid variant for XhsBlockReason: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsCacheConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cacheDir = <String>::sse_decode(deserializer);
+        let mut var_ttlSecs = <u64>::sse_decode(deserializer);
+        let mut var_maxSizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_bypass = <bool>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsCacheConfig {
+            cache_dir: var_cacheDir,
+            ttl_secs: var_ttlSecs,
+            max_size_bytes: var_maxSizeBytes,
+            bypass: var_bypass,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsClientConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_connectTimeoutMs = <u64>::sse_decode(deserializer);
+        let mut var_readTimeoutMs = <u64>::sse_decode(deserializer);
+        let mut var_proxy = <Option<String>>::sse_decode(deserializer);
+        let mut var_cookieStore = <bool>::sse_decode(deserializer);
+        let mut var_cookies = <Vec<String>>::sse_decode(deserializer);
+        let mut var_maxRedirects = <u32>::sse_decode(deserializer);
+        let mut var_cache =
+            <Option<crate::api::models::xhs::XhsCacheConfig>>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsClientConfig {
+            connect_timeout_ms: var_connectTimeoutMs,
+            read_timeout_ms: var_readTimeoutMs,
+            proxy: var_proxy,
+            cookie_store: var_cookieStore,
+            cookies: var_cookies,
+            max_redirects: var_maxRedirects,
+            cache: var_cache,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsLink {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::models::xhs::XhsLinkKind>::sse_decode(deserializer);
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_xsecToken = <Option<String>>::sse_decode(deserializer);
+        let mut var_xsecSource = <Option<String>>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsLink {
+            kind: var_kind,
+            id: var_id,
+            xsec_token: var_xsecToken,
+            xsec_source: var_xsecSource,
+            url: var_url,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsLinkKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::models::xhs::XhsLinkKind::ShortLink,
+            1 => crate::api::models::xhs::XhsLinkKind::Explore,
+            2 => crate::api::models::xhs::XhsLinkKind::DiscoveryItem,
+            _ => unreachable!("Invalid variant for XhsLinkKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsNoteCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_noteId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_cover = <String>::sse_decode(deserializer);
+        let mut var_noteType = <crate::api::models::xhs::NoteType>::sse_decode(deserializer);
+        let mut var_likedCount = <u64>::sse_decode(deserializer);
+        let mut var_xsecToken = <Option<String>>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsNoteCard {
+            note_id: var_noteId,
+            title: var_title,
+            cover: var_cover,
+            note_type: var_noteType,
+            liked_count: var_likedCount,
+            xsec_token: var_xsecToken,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsNotePage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_notes = <Vec<crate::api::models::xhs::XhsNoteCard>>::sse_decode(deserializer);
+        let mut var_cursor = <String>::sse_decode(deserializer);
+        let mut var_hasMore = <bool>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsNotePage {
+            notes: var_notes,
+            cursor: var_cursor,
+            has_more: var_hasMore,
+        };
+    }
+}
+
+impl SseDecode for crate::api::models::xhs::XhsVideo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_duration = <i64>::sse_decode(deserializer);
+        let mut var_cover = <String>::sse_decode(deserializer);
+        let mut var_playUrl = <String>::sse_decode(deserializer);
+        return crate::api::models::xhs::XhsVideo {
+            duration: var_duration,
+            cover: var_cover,
+            play_url: var_playUrl,
+        };
+    }
+}
+
+impl SseDecode for crate::core::video::manager::YFrameData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_yPlane = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        let mut var_frameNumber = <u64>::sse_decode(deserializer);
+        return crate::core::video::manager::YFrameData {
+            width: var_width,
+            height: var_height,
+            y_plane: var_yPlane,
+            timestamp_ms: var_timestampMs,
+            frame_number: var_frameNumber,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => {
+            wire__crate__api__audio__AudioRecognizer_create_impl(port, ptr, rust_vec_len, data_len)
+        }
+        3 => wire__crate__api__audio__AudioRecognizer_transcribe_audio_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__audio__AudioRecognizer_transcribe_audio_detailed_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__audio__AudioRecognizer_transcribe_samples_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__audio__AudioRecognizer_transcribe_samples_i16_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__importer__RecipeImporter_create_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__importer__RecipeImporter_import_from_text_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__video__VideoFrameExtractor_process_batch_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__models__asr__asr_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__models__asr__asr_model_files_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__models__asr__audio_recognizer_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__models__asr__diarization_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__models__asr__endpoint_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        19 => wire__crate__api__models__asr__event_detection_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__models__asr__hotword_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__models__asr__loudness_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__models__manifest__model_validation_report_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__models__manifest__model_validation_report_is_valid_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__models__asr__noise_reduction_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => {
+            wire__crate__api__models__xhs__note_type_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        26 => wire__crate__api__xhs__parse_xhs_from_text_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__xhs__parse_xhs_from_url_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__xhs__parse_xhs_profile_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__models__recipe__parsed_recipe_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__models__asr__postprocess_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__models__asr__preprocess_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__models__asr__recognizer_pool_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__models__asr__resample_quality_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__models__transcript__segmentation_path_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__models__source__source_author_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__models__transcript__transcript_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__models__asr__vad_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__models__asr__vad_fallback_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__audio__validate_models_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__models__xhs__xhs_cache_config_new_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__models__xhs__xhs_client_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__models__xhs__xhs_note_page_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire__crate__api__audio__AudioRecognizer_models_dir_impl(ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__video__VideoFrameExtractor_create_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__video__VideoFrameExtractor_reset_impl(ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__video__VideoFrameExtractor_stats_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__xhs__classify_xhs_link_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__xhs__parse_xhs_note_page_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__xhs__xhs_api_error_code_impl(ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__xhs__xhs_api_error_user_message_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<AudioError> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AudioError> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AudioError>> for AudioError {
+    fn into_into_dart(self) -> FrbWrapper<AudioError> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<AudioRecognizer> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AudioRecognizer> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AudioRecognizer>> for AudioRecognizer {
+    fn into_into_dart(self) -> FrbWrapper<AudioRecognizer> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<ImportError> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<ImportError> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<ImportError>> for ImportError {
+    fn into_into_dart(self) -> FrbWrapper<ImportError> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<RecipeImporter> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<RecipeImporter> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<RecipeImporter>> for RecipeImporter {
+    fn into_into_dart(self) -> FrbWrapper<RecipeImporter> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<VideoFrameExtractor> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for FrbWrapper<VideoFrameExtractor>
+{
+}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<VideoFrameExtractor>> for VideoFrameExtractor {
+    fn into_into_dart(self) -> FrbWrapper<VideoFrameExtractor> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::AsrConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.decoding_method.into_into_dart().into_dart(),
+            self.num_active_paths.into_into_dart().into_dart(),
+            self.num_threads.into_into_dart().into_dart(),
+            self.endpoint.into_into_dart().into_dart(),
+            self.model_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::AsrConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::AsrConfig>
+    for crate::api::models::asr::AsrConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::AsrConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::AsrModelFiles {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.encoder_param.into_into_dart().into_dart(),
+            self.encoder_bin.into_into_dart().into_dart(),
+            self.decoder_param.into_into_dart().into_dart(),
+            self.decoder_bin.into_into_dart().into_dart(),
+            self.joiner_param.into_into_dart().into_dart(),
+            self.joiner_bin.into_into_dart().into_dart(),
+            self.tokens.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::AsrModelFiles
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::AsrModelFiles>
+    for crate::api::models::asr::AsrModelFiles
+{
+    fn into_into_dart(self) -> crate::api::models::asr::AsrModelFiles {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::transcript::AudioEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.confidence.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::transcript::AudioEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::transcript::AudioEvent>
+    for crate::api::models::transcript::AudioEvent
+{
+    fn into_into_dart(self) -> crate::api::models::transcript::AudioEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::transcript::AudioEventKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Sizzle => 0.into_dart(),
+            Self::Boiling => 1.into_dart(),
+            Self::TimerBeep => 2.into_dart(),
+            Self::Blender => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::transcript::AudioEventKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::transcript::AudioEventKind>
+    for crate::api::models::transcript::AudioEventKind
+{
+    fn into_into_dart(self) -> crate::api::models::transcript::AudioEventKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::AudioRecognizerConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hotwords.into_into_dart().into_dart(),
+            self.asr.into_into_dart().into_dart(),
+            self.model_packs.into_into_dart().into_dart(),
+            self.pool.into_into_dart().into_dart(),
+            self.vad.into_into_dart().into_dart(),
+            self.vad_fallback.into_into_dart().into_dart(),
+            self.diarization.into_into_dart().into_dart(),
+            self.postprocess.into_into_dart().into_dart(),
+            self.resample_quality.into_into_dart().into_dart(),
+            self.preprocess.into_into_dart().into_dart(),
+            self.events.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::AudioRecognizerConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::AudioRecognizerConfig>
+    for crate::api::models::asr::AudioRecognizerConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::AudioRecognizerConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::DecodingMethod {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::GreedySearch => 0.into_dart(),
+            Self::ModifiedBeamSearch => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::DecodingMethod
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::DecodingMethod>
+    for crate::api::models::asr::DecodingMethod
+{
+    fn into_into_dart(self) -> crate::api::models::asr::DecodingMethod {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::DiarizationConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_speakers.into_into_dart().into_dart(),
+            self.threshold.into_into_dart().into_dart(),
+            self.min_segment_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::DiarizationConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::DiarizationConfig>
+    for crate::api::models::asr::DiarizationConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::DiarizationConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::EndpointConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enable.into_into_dart().into_dart(),
+            self.rule1_min_trailing_silence.into_into_dart().into_dart(),
+            self.rule2_min_trailing_silence.into_into_dart().into_dart(),
+            self.rule3_min_utterance_length.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::EndpointConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::EndpointConfig>
+    for crate::api::models::asr::EndpointConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::EndpointConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::EventDetectionConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.min_event_ms.into_into_dart().into_dart(),
+            self.min_confidence.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::EventDetectionConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::EventDetectionConfig>
+    for crate::api::models::asr::EventDetectionConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::EventDetectionConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::video::manager::ExtractionStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.processed_frames.into_into_dart().into_dart(),
+            self.extracted_frames.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::video::manager::ExtractionStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::video::manager::ExtractionStats>
+    for crate::core::video::manager::ExtractionStats
+{
+    fn into_into_dart(self) -> crate::core::video::manager::ExtractionStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::video::manager::FrameExtractedInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.timestamp_ms.into_into_dart().into_dart(),
+            self.frame_number.into_into_dart().into_dart(),
+            self.confidence.into_into_dart().into_dart(),
+            self.jpeg_data.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::video::manager::FrameExtractedInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::video::manager::FrameExtractedInfo>
+    for crate::core::video::manager::FrameExtractedInfo
+{
+    fn into_into_dart(self) -> crate::core::video::manager::FrameExtractedInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::Hotword {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phrase.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::Hotword
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::Hotword>
+    for crate::api::models::asr::Hotword
+{
+    fn into_into_dart(self) -> crate::api::models::asr::Hotword {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::HotwordConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hotwords.into_into_dart().into_dart(),
+            self.use_cooking_lexicon.into_into_dart().into_dart(),
+            self.default_score.into_into_dart().into_dart(),
+            self.work_dir.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::HotwordConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::HotwordConfig>
+    for crate::api::models::asr::HotwordConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::HotwordConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::recipe::ImportProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stage.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::recipe::ImportProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::recipe::ImportProgress>
+    for crate::api::models::recipe::ImportProgress
+{
+    fn into_into_dart(self) -> crate::api::models::recipe::ImportProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::recipe::ImportStage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::FetchingNote => 0.into_dart(),
+            Self::DownloadingVideo => 1.into_dart(),
+            Self::DecodingAudio => 2.into_dart(),
+            Self::Transcribing => 3.into_dart(),
+            Self::ExtractingFrames => 4.into_dart(),
+            Self::ParsingRecipe => 5.into_dart(),
+            Self::Complete => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::recipe::ImportStage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::recipe::ImportStage>
+    for crate::api::models::recipe::ImportStage
+{
+    fn into_into_dart(self) -> crate::api::models::recipe::ImportStage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::recipe::ImportedRecipe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_url.into_into_dart().into_dart(),
+            self.article.into_into_dart().into_dart(),
+            self.transcript.into_into_dart().into_dart(),
+            self.keyframes.into_into_dart().into_dart(),
+            self.recipe.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::recipe::ImportedRecipe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::recipe::ImportedRecipe>
+    for crate::api::models::recipe::ImportedRecipe
+{
+    fn into_into_dart(self) -> crate::api::models::recipe::ImportedRecipe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::LoudnessConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.measure.into_into_dart().into_dart(),
+            self.target_db.into_into_dart().into_dart(),
+            self.max_gain_db.into_into_dart().into_dart(),
+            self.window_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::LoudnessConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::LoudnessConfig>
+    for crate::api::models::asr::LoudnessConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::LoudnessConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::LoudnessMeasure {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Rms => 0.into_dart(),
+            Self::Lufs => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::LoudnessMeasure
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::LoudnessMeasure>
+    for crate::api::models::asr::LoudnessMeasure
+{
+    fn into_into_dart(self) -> crate::api::models::asr::LoudnessMeasure {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::manifest::ModelFileIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::manifest::ModelFileIssue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::manifest::ModelFileIssue>
+    for crate::api::models::manifest::ModelFileIssue
+{
+    fn into_into_dart(self) -> crate::api::models::manifest::ModelFileIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::manifest::ModelIssueKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::models::manifest::ModelIssueKind::Missing => [0.into_dart()].into_dart(),
+            crate::api::models::manifest::ModelIssueKind::InvalidPath => {
+                [1.into_dart()].into_dart()
+            }
+            crate::api::models::manifest::ModelIssueKind::SizeMismatch { expected, actual } => [
+                2.into_dart(),
+                expected.into_into_dart().into_dart(),
+                actual.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::models::manifest::ModelIssueKind::ChecksumMismatch { expected, actual } => {
+                [
+                    3.into_dart(),
+                    expected.into_into_dart().into_dart(),
+                    actual.into_into_dart().into_dart(),
+                ]
+                .into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::manifest::ModelIssueKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::manifest::ModelIssueKind>
+    for crate::api::models::manifest::ModelIssueKind
+{
+    fn into_into_dart(self) -> crate::api::models::manifest::ModelIssueKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::ModelPackConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.dir.into_into_dart().into_dart(),
+            self.languages.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::ModelPackConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::ModelPackConfig>
+    for crate::api::models::asr::ModelPackConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::ModelPackConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::manifest::ModelValidationReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.checked.into_into_dart().into_dart(),
+            self.checksums_verified.into_into_dart().into_dart(),
+            self.issues.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::manifest::ModelValidationReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::manifest::ModelValidationReport>
+    for crate::api::models::manifest::ModelValidationReport
+{
+    fn into_into_dart(self) -> crate::api::models::manifest::ModelValidationReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::NoiseReductionConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.over_subtraction.into_into_dart().into_dart(),
+            self.floor_db.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::NoiseReductionConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::NoiseReductionConfig>
+    for crate::api::models::asr::NoiseReductionConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::NoiseReductionConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::NoteType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Video => 0.into_dart(),
+            Self::Images => 1.into_dart(),
+            Self::Mixed => 2.into_dart(),
+            Self::Text => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::NoteType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::NoteType>
+    for crate::api::models::xhs::NoteType
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::NoteType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::recipe::ParsedRecipe {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.ingredients.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.tips.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::recipe::ParsedRecipe
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::recipe::ParsedRecipe>
+    for crate::api::models::recipe::ParsedRecipe
+{
+    fn into_into_dart(self) -> crate::api::models::recipe::ParsedRecipe {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::source::Platform {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Xiaohongshu => 0.into_dart(),
+            Self::Xiachufang => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::source::Platform
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::source::Platform>
+    for crate::api::models::source::Platform
+{
+    fn into_into_dart(self) -> crate::api::models::source::Platform {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::PostprocessConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.punctuation.into_into_dart().into_dart(),
+            self.mixed_english.into_into_dart().into_dart(),
+            self.inverse_text_normalization.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::PostprocessConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::PostprocessConfig>
+    for crate::api::models::asr::PostprocessConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::PostprocessConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::PreprocessConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.remove_dc.into_into_dart().into_dart(),
+            self.high_pass_hz.into_into_dart().into_dart(),
+            self.noise_reduction.into_into_dart().into_dart(),
+            self.loudness.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::PreprocessConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::PreprocessConfig>
+    for crate::api::models::asr::PreprocessConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::PreprocessConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::PunctuationMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::models::asr::PunctuationMode::Off => [0.into_dart()].into_dart(),
+            crate::api::models::asr::PunctuationMode::Rules { pause_ms } => {
+                [1.into_dart(), pause_ms.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::PunctuationMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::PunctuationMode>
+    for crate::api::models::asr::PunctuationMode
+{
+    fn into_into_dart(self) -> crate::api::models::asr::PunctuationMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::recipe::RecipeIngredient {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.amount.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::recipe::RecipeIngredient
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::recipe::RecipeIngredient>
+    for crate::api::models::recipe::RecipeIngredient
+{
+    fn into_into_dart(self) -> crate::api::models::recipe::RecipeIngredient {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::RecognizerPoolConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_instances.into_into_dart().into_dart(),
+            self.max_memory_mb.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::RecognizerPoolConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::RecognizerPoolConfig>
+    for crate::api::models::asr::RecognizerPoolConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::RecognizerPoolConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::ResampleQuality {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fast => 0.into_dart(),
+            Self::Balanced => 1.into_dart(),
+            Self::High => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::ResampleQuality
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::ResampleQuality>
+    for crate::api::models::asr::ResampleQuality
+{
+    fn into_into_dart(self) -> crate::api::models::asr::ResampleQuality {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::transcript::SegmentationPath {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Vad => 0.into_dart(),
+            Self::FixedWindow => 1.into_dart(),
+            Self::WholeFile => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::transcript::SegmentationPath
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::transcript::SegmentationPath>
+    for crate::api::models::transcript::SegmentationPath
+{
+    fn into_into_dart(self) -> crate::api::models::transcript::SegmentationPath {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::source::SourceArticle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.platform.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.desc.into_into_dart().into_dart(),
+            self.author.into_into_dart().into_dart(),
+            self.images.into_into_dart().into_dart(),
+            self.video.into_into_dart().into_dart(),
+            self.note_type.into_into_dart().into_dart(),
+            self.recipe.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::source::SourceArticle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::source::SourceArticle>
+    for crate::api::models::source::SourceArticle
+{
+    fn into_into_dart(self) -> crate::api::models::source::SourceArticle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::source::SourceAuthor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.avatar.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::source::SourceAuthor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::source::SourceAuthor>
+    for crate::api::models::source::SourceAuthor
+{
+    fn into_into_dart(self) -> crate::api::models::source::SourceAuthor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::source::SourceVideo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.duration.into_into_dart().into_dart(),
+            self.cover.into_into_dart().into_dart(),
+            self.play_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::source::SourceVideo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::source::SourceVideo>
+    for crate::api::models::source::SourceVideo
+{
+    fn into_into_dart(self) -> crate::api::models::source::SourceVideo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::transcript::Transcript {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.segments.into_into_dart().into_dart(),
+            self.segmentation.into_into_dart().into_dart(),
+            self.fallback_reason.into_into_dart().into_dart(),
+            self.model_pack.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.language_detected.into_into_dart().into_dart(),
+            self.events.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::transcript::Transcript
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::transcript::Transcript>
+    for crate::api::models::transcript::Transcript
+{
+    fn into_into_dart(self) -> crate::api::models::transcript::Transcript {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::transcript::TranscriptSegment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_ms.into_into_dart().into_dart(),
+            self.end_ms.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.tokens.into_into_dart().into_dart(),
+            self.speaker.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::transcript::TranscriptSegment
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::transcript::TranscriptSegment>
+    for crate::api::models::transcript::TranscriptSegment
+{
+    fn into_into_dart(self) -> crate::api::models::transcript::TranscriptSegment {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::transcript::TranscriptToken {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.token.into_into_dart().into_dart(),
+            self.start_ms.into_into_dart().into_dart(),
+            self.confidence.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::transcript::TranscriptToken
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::transcript::TranscriptToken>
+    for crate::api::models::transcript::TranscriptToken
+{
+    fn into_into_dart(self) -> crate::api::models::transcript::TranscriptToken {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::VadConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.threshold.into_into_dart().into_dart(),
+            self.min_silence_duration_ms.into_into_dart().into_dart(),
+            self.min_speech_duration_ms.into_into_dart().into_dart(),
+            self.num_threads.into_into_dart().into_dart(),
+            self.buffer_size_secs.into_into_dart().into_dart(),
+            self.pad_ms.into_into_dart().into_dart(),
+            self.merge_gap_ms.into_into_dart().into_dart(),
+            self.max_segment_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::VadConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::VadConfig>
+    for crate::api::models::asr::VadConfig
+{
+    fn into_into_dart(self) -> crate::api::models::asr::VadConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::asr::VadFallback {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::models::asr::VadFallback::Error => [0.into_dart()].into_dart(),
+            crate::api::models::asr::VadFallback::FixedWindow {
+                window_ms,
+                overlap_ms,
+            } => [
+                1.into_dart(),
+                window_ms.into_into_dart().into_dart(),
+                overlap_ms.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::models::asr::VadFallback::WholeFile => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::asr::VadFallback
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::asr::VadFallback>
+    for crate::api::models::asr::VadFallback
+{
+    fn into_into_dart(self) -> crate::api::models::asr::VadFallback {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::xhs::XhsApiError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::xhs::XhsApiError::UrlNotFound => [0.into_dart()].into_dart(),
+            crate::api::xhs::XhsApiError::InvalidLink { url } => {
+                [1.into_dart(), url.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::xhs::XhsApiError::HttpStatus { status, url } => [
+                2.into_dart(),
+                status.into_into_dart().into_dart(),
+                url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::xhs::XhsApiError::Network { message, timeout } => [
+                3.into_dart(),
+                message.into_into_dart().into_dart(),
+                timeout.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::xhs::XhsApiError::ClientConfig { message } => {
+                [4.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::xhs::XhsApiError::Parse { message } => {
+                [5.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::xhs::XhsApiError::Blocked { reason } => {
+                [6.into_dart(), reason.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::xhs::XhsApiError::Io { message } => {
+                [7.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::xhs::XhsApiError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::xhs::XhsApiError>
+    for crate::api::xhs::XhsApiError
+{
+    fn into_into_dart(self) -> crate::api::xhs::XhsApiError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsArticle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.desc.into_into_dart().into_dart(),
+            self.author.into_into_dart().into_dart(),
+            self.images.into_into_dart().into_dart(),
+            self.video.into_into_dart().into_dart(),
+            self.note_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsArticle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsArticle>
+    for crate::api::models::xhs::XhsArticle
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsArticle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsAuthor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.nickname.into_into_dart().into_dart(),
+            self.user_id.into_into_dart().into_dart(),
+            self.avatar.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsAuthor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsAuthor>
+    for crate::api::models::xhs::XhsAuthor
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsAuthor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsAuthorProfile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.author.into_into_dart().into_dart(),
+            self.red_id.into_into_dart().into_dart(),
+            self.bio.into_into_dart().into_dart(),
+            self.ip_location.into_into_dart().into_dart(),
+            self.follows.into_into_dart().into_dart(),
+            self.fans.into_into_dart().into_dart(),
+            self.liked_and_collected.into_into_dart().into_dart(),
+            self.notes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsAuthorProfile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsAuthorProfile>
+    for crate::api::models::xhs::XhsAuthorProfile
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsAuthorProfile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::xhs::XhsBlockReason {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NoteDeleted => 0.into_dart(),
+            Self::LoginRequired => 1.into_dart(),
+            Self::RateLimited => 2.into_dart(),
+            Self::CaptchaRequired => 3.into_dart(),
+            Self::RegionBlocked => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::xhs::XhsBlockReason
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::xhs::XhsBlockReason>
+    for crate::api::xhs::XhsBlockReason
+{
+    fn into_into_dart(self) -> crate::api::xhs::XhsBlockReason {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsCacheConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cache_dir.into_into_dart().into_dart(),
+            self.ttl_secs.into_into_dart().into_dart(),
+            self.max_size_bytes.into_into_dart().into_dart(),
+            self.bypass.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsCacheConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsCacheConfig>
+    for crate::api::models::xhs::XhsCacheConfig
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsCacheConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsClientConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.connect_timeout_ms.into_into_dart().into_dart(),
+            self.read_timeout_ms.into_into_dart().into_dart(),
+            self.proxy.into_into_dart().into_dart(),
+            self.cookie_store.into_into_dart().into_dart(),
+            self.cookies.into_into_dart().into_dart(),
+            self.max_redirects.into_into_dart().into_dart(),
+            self.cache.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsClientConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsClientConfig>
+    for crate::api::models::xhs::XhsClientConfig
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsClientConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsLink {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.xsec_token.into_into_dart().into_dart(),
+            self.xsec_source.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsLink
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsLink>
+    for crate::api::models::xhs::XhsLink
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsLink {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsLinkKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ShortLink => 0.into_dart(),
+            Self::Explore => 1.into_dart(),
+            Self::DiscoveryItem => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsLinkKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsLinkKind>
+    for crate::api::models::xhs::XhsLinkKind
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsLinkKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsNoteCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.note_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.cover.into_into_dart().into_dart(),
+            self.note_type.into_into_dart().into_dart(),
+            self.liked_count.into_into_dart().into_dart(),
+            self.xsec_token.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsNoteCard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsNoteCard>
+    for crate::api::models::xhs::XhsNoteCard
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsNoteCard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsNotePage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.notes.into_into_dart().into_dart(),
+            self.cursor.into_into_dart().into_dart(),
+            self.has_more.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsNotePage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsNotePage>
+    for crate::api::models::xhs::XhsNotePage
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsNotePage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsVideo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.duration.into_into_dart().into_dart(),
+            self.cover.into_into_dart().into_dart(),
+            self.play_url.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::models::xhs::XhsVideo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsVideo>
+    for crate::api::models::xhs::XhsVideo
+{
+    fn into_into_dart(self) -> crate::api::models::xhs::XhsVideo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::core::video::manager::YFrameData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.y_plane.into_into_dart().into_dart(),
+            self.timestamp_ms.into_into_dart().into_dart(),
+            self.frame_number.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::core::video::manager::YFrameData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::core::video::manager::YFrameData>
+    for crate::core::video::manager::YFrameData
+{
+    fn into_into_dart(self) -> crate::core::video::manager::YFrameData {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for AudioError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for AudioRecognizer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
 
-impl SseDecode for AudioError {
+impl SseEncode for ImportError {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for AudioRecognizer {
+impl SseEncode for RecipeImporter {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode for VideoFrameExtractor {
+impl SseEncode for VideoFrameExtractor {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
     }
 }
 
-impl SseDecode
+impl SseEncode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.encode(), serializer);
+    }
+}
+
+impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode
+impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
     for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
     }
 }
 
-impl SseDecode for String {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for crate::core::video::manager::ExtractionStats {
+impl SseEncode for crate::api::models::asr::AsrConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_processedFrames = <u64>::sse_decode(deserializer);
-        let mut var_extractedFrames = <u64>::sse_decode(deserializer);
-        return crate::core::video::manager::ExtractionStats {
-            processed_frames: var_processedFrames,
-            extracted_frames: var_extractedFrames,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::models::asr::DecodingMethod>::sse_encode(self.decoding_method, serializer);
+        <u32>::sse_encode(self.num_active_paths, serializer);
+        <u32>::sse_encode(self.num_threads, serializer);
+        <crate::api::models::asr::EndpointConfig>::sse_encode(self.endpoint, serializer);
+        <crate::api::models::asr::AsrModelFiles>::sse_encode(self.model_files, serializer);
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode for crate::api::models::asr::AsrModelFiles {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.encoder_param, serializer);
+        <String>::sse_encode(self.encoder_bin, serializer);
+        <String>::sse_encode(self.decoder_param, serializer);
+        <String>::sse_encode(self.decoder_bin, serializer);
+        <String>::sse_encode(self.joiner_param, serializer);
+        <String>::sse_encode(self.joiner_bin, serializer);
+        <String>::sse_encode(self.tokens, serializer);
     }
 }
 
-impl SseDecode for crate::core::video::manager::FrameExtractedInfo {
+impl SseEncode for crate::api::models::transcript::AudioEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_timestampMs = <u64>::sse_decode(deserializer);
-        let mut var_frameNumber = <u64>::sse_decode(deserializer);
-        let mut var_confidence = <f32>::sse_decode(deserializer);
-        let mut var_jpegData = <Vec<u8>>::sse_decode(deserializer);
-        let mut var_width = <u32>::sse_decode(deserializer);
-        let mut var_height = <u32>::sse_decode(deserializer);
-        return crate::core::video::manager::FrameExtractedInfo {
-            timestamp_ms: var_timestampMs,
-            frame_number: var_frameNumber,
-            confidence: var_confidence,
-            jpeg_data: var_jpegData,
-            width: var_width,
-            height: var_height,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::models::transcript::AudioEventKind>::sse_encode(self.kind, serializer);
+        <u64>::sse_encode(self.start_ms, serializer);
+        <u64>::sse_encode(self.end_ms, serializer);
+        <f32>::sse_encode(self.confidence, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for crate::api::models::transcript::AudioEventKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::transcript::AudioEventKind::Sizzle => 0,
+                crate::api::models::transcript::AudioEventKind::Boiling => 1,
+                crate::api::models::transcript::AudioEventKind::TimerBeep => 2,
+                crate::api::models::transcript::AudioEventKind::Blender => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for crate::api::models::asr::AudioRecognizerConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::models::asr::HotwordConfig>>::sse_encode(self.hotwords, serializer);
+        <crate::api::models::asr::AsrConfig>::sse_encode(self.asr, serializer);
+        <Vec<crate::api::models::asr::ModelPackConfig>>::sse_encode(self.model_packs, serializer);
+        <crate::api::models::asr::RecognizerPoolConfig>::sse_encode(self.pool, serializer);
+        <crate::api::models::asr::VadConfig>::sse_encode(self.vad, serializer);
+        <crate::api::models::asr::VadFallback>::sse_encode(self.vad_fallback, serializer);
+        <Option<crate::api::models::asr::DiarizationConfig>>::sse_encode(
+            self.diarization,
+            serializer,
+        );
+        <crate::api::models::asr::PostprocessConfig>::sse_encode(self.postprocess, serializer);
+        <crate::api::models::asr::ResampleQuality>::sse_encode(self.resample_quality, serializer);
+        <Option<crate::api::models::asr::PreprocessConfig>>::sse_encode(
+            self.preprocess,
+            serializer,
+        );
+        <Option<crate::api::models::asr::EventDetectionConfig>>::sse_encode(
+            self.events,
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseDecode for Vec<crate::core::video::manager::FrameExtractedInfo> {
+impl SseEncode for crate::api::models::asr::DecodingMethod {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::core::video::manager::FrameExtractedInfo>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::asr::DecodingMethod::GreedySearch => 0,
+                crate::api::models::asr::DecodingMethod::ModifiedBeamSearch => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::api::models::asr::DiarizationConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.max_speakers, serializer);
+        <f32>::sse_encode(self.threshold, serializer);
+        <u32>::sse_encode(self.min_segment_ms, serializer);
     }
 }
 
-impl SseDecode for Vec<crate::core::video::manager::YFrameData> {
+impl SseEncode for crate::api::models::asr::EndpointConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::core::video::manager::YFrameData>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enable, serializer);
+        <f32>::sse_encode(self.rule1_min_trailing_silence, serializer);
+        <f32>::sse_encode(self.rule2_min_trailing_silence, serializer);
+        <f32>::sse_encode(self.rule3_min_utterance_length, serializer);
     }
 }
 
-impl SseDecode for crate::api::models::xhs::NoteType {
+impl SseEncode for crate::api::models::asr::EventDetectionConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::models::xhs::NoteType::Video,
-            1 => crate::api::models::xhs::NoteType::Images,
-            2 => crate::api::models::xhs::NoteType::Mixed,
-            3 => crate::api::models::xhs::NoteType::Text,
-            _ => unreachable!("Invalid variant for NoteType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.min_event_ms, serializer);
+        <f32>::sse_encode(self.min_confidence, serializer);
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for crate::core::video::manager::ExtractionStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.processed_frames, serializer);
+        <u64>::sse_encode(self.extracted_frames, serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::models::xhs::XhsVideo> {
+impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::models::xhs::XhsVideo>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for crate::core::video::manager::FrameExtractedInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.timestamp_ms, serializer);
+        <u64>::sse_encode(self.frame_number, serializer);
+        <f32>::sse_encode(self.confidence, serializer);
+        <Vec<u8>>::sse_encode(self.jpeg_data, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for crate::api::models::asr::Hotword {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.phrase, serializer);
+        <Option<f32>>::sse_encode(self.score, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::models::asr::HotwordConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::models::asr::Hotword>>::sse_encode(self.hotwords, serializer);
+        <bool>::sse_encode(self.use_cooking_lexicon, serializer);
+        <f32>::sse_encode(self.default_score, serializer);
+        <Option<String>>::sse_encode(self.work_dir, serializer);
+    }
+}
+
+impl SseEncode for i16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i16::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::models::recipe::ImportProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::models::recipe::ImportStage>::sse_encode(self.stage, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
 
-impl SseDecode for crate::api::xhs::XhsApiError {
+impl SseEncode for crate::api::models::recipe::ImportStage {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_errorType = <String>::sse_decode(deserializer);
-        let mut var_message = <String>::sse_decode(deserializer);
-        return crate::api::xhs::XhsApiError {
-            error_type: var_errorType,
-            message: var_message,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::recipe::ImportStage::FetchingNote => 0,
+                crate::api::models::recipe::ImportStage::DownloadingVideo => 1,
+                crate::api::models::recipe::ImportStage::DecodingAudio => 2,
+                crate::api::models::recipe::ImportStage::Transcribing => 3,
+                crate::api::models::recipe::ImportStage::ExtractingFrames => 4,
+                crate::api::models::recipe::ImportStage::ParsingRecipe => 5,
+                crate::api::models::recipe::ImportStage::Complete => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::api::models::xhs::XhsArticle {
+impl SseEncode for crate::api::models::recipe::ImportedRecipe {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_desc = <String>::sse_decode(deserializer);
-        let mut var_author = <crate::api::models::xhs::XhsAuthor>::sse_decode(deserializer);
-        let mut var_images = <Vec<String>>::sse_decode(deserializer);
-        let mut var_video = <Option<crate::api::models::xhs::XhsVideo>>::sse_decode(deserializer);
-        let mut var_noteType = <crate::api::models::xhs::NoteType>::sse_decode(deserializer);
-        return crate::api::models::xhs::XhsArticle {
-            title: var_title,
-            desc: var_desc,
-            author: var_author,
-            images: var_images,
-            video: var_video,
-            note_type: var_noteType,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source_url, serializer);
+        <crate::api::models::source::SourceArticle>::sse_encode(self.article, serializer);
+        <Option<String>>::sse_encode(self.transcript, serializer);
+        <Vec<crate::core::video::manager::FrameExtractedInfo>>::sse_encode(
+            self.keyframes,
+            serializer,
+        );
+        <crate::api::models::recipe::ParsedRecipe>::sse_encode(self.recipe, serializer);
     }
 }
 
-impl SseDecode for crate::api::models::xhs::XhsAuthor {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nickname = <String>::sse_decode(deserializer);
-        let mut var_userId = <String>::sse_decode(deserializer);
-        let mut var_avatar = <String>::sse_decode(deserializer);
-        return crate::api::models::xhs::XhsAuthor {
-            nickname: var_nickname,
-            user_id: var_userId,
-            avatar: var_avatar,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::models::xhs::XhsVideo {
+impl SseEncode for Vec<crate::api::models::transcript::AudioEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_duration = <i64>::sse_decode(deserializer);
-        let mut var_cover = <String>::sse_decode(deserializer);
-        let mut var_playUrl = <String>::sse_decode(deserializer);
-        return crate::api::models::xhs::XhsVideo {
-            duration: var_duration,
-            cover: var_cover,
-            play_url: var_playUrl,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::transcript::AudioEvent>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::core::video::manager::YFrameData {
+impl SseEncode for Vec<crate::core::video::manager::FrameExtractedInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_width = <u32>::sse_decode(deserializer);
-        let mut var_height = <u32>::sse_decode(deserializer);
-        let mut var_yPlane = <Vec<u8>>::sse_decode(deserializer);
-        let mut var_timestampMs = <u64>::sse_decode(deserializer);
-        let mut var_frameNumber = <u64>::sse_decode(deserializer);
-        return crate::core::video::manager::YFrameData {
-            width: var_width,
-            height: var_height,
-            y_plane: var_yPlane,
-            timestamp_ms: var_timestampMs,
-            frame_number: var_frameNumber,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::video::manager::FrameExtractedInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for Vec<crate::api::models::asr::Hotword> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::asr::Hotword>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => {
-            wire__crate__api__audio__AudioRecognizer_create_impl(port, ptr, rust_vec_len, data_len)
-        }
-        3 => wire__crate__api__audio__AudioRecognizer_transcribe_audio_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__video__VideoFrameExtractor_process_batch_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => {
-            wire__crate__api__models__xhs__note_type_default_impl(port, ptr, rust_vec_len, data_len)
+impl SseEncode for Vec<crate::api::models::manifest::ModelFileIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::manifest::ModelFileIssue>::sse_encode(item, serializer);
         }
-        _ => unreachable!(),
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        2 => wire__crate__api__audio__AudioRecognizer_models_dir_impl(ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__video__VideoFrameExtractor_create_impl(ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__video__VideoFrameExtractor_reset_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__video__VideoFrameExtractor_stats_impl(ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__xhs__parse_xhs_from_text_impl(ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__xhs__parse_xhs_from_url_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::models::asr::ModelPackConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::asr::ModelPackConfig>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<AudioError> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AudioError> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AudioError>> for AudioError {
-    fn into_into_dart(self) -> FrbWrapper<AudioError> {
-        self.into()
+impl SseEncode for Vec<i16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i16>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<AudioRecognizer> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<AudioRecognizer> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<AudioRecognizer>> for AudioRecognizer {
-    fn into_into_dart(self) -> FrbWrapper<AudioRecognizer> {
-        self.into()
+impl SseEncode for Vec<crate::api::models::recipe::RecipeIngredient> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::recipe::RecipeIngredient>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<VideoFrameExtractor> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Vec<crate::api::models::transcript::TranscriptSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::transcript::TranscriptSegment>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for FrbWrapper<VideoFrameExtractor>
-{
-}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<VideoFrameExtractor>> for VideoFrameExtractor {
-    fn into_into_dart(self) -> FrbWrapper<VideoFrameExtractor> {
-        self.into()
+impl SseEncode for Vec<crate::api::models::transcript::TranscriptToken> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::transcript::TranscriptToken>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::video::manager::ExtractionStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.processed_frames.into_into_dart().into_dart(),
-            self.extracted_frames.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Vec<crate::api::models::xhs::XhsNoteCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::models::xhs::XhsNoteCard>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::video::manager::ExtractionStats
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::core::video::manager::ExtractionStats>
-    for crate::core::video::manager::ExtractionStats
-{
-    fn into_into_dart(self) -> crate::core::video::manager::ExtractionStats {
-        self
+
+impl SseEncode for Vec<crate::core::video::manager::YFrameData> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::core::video::manager::YFrameData>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::video::manager::FrameExtractedInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.timestamp_ms.into_into_dart().into_dart(),
-            self.frame_number.into_into_dart().into_dart(),
-            self.confidence.into_into_dart().into_dart(),
-            self.jpeg_data.into_into_dart().into_dart(),
-            self.width.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::models::asr::LoudnessConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::models::asr::LoudnessMeasure>::sse_encode(self.measure, serializer);
+        <f32>::sse_encode(self.target_db, serializer);
+        <f32>::sse_encode(self.max_gain_db, serializer);
+        <u32>::sse_encode(self.window_ms, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::video::manager::FrameExtractedInfo
-{
+
+impl SseEncode for crate::api::models::asr::LoudnessMeasure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::asr::LoudnessMeasure::Rms => 0,
+                crate::api::models::asr::LoudnessMeasure::Lufs => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::core::video::manager::FrameExtractedInfo>
-    for crate::core::video::manager::FrameExtractedInfo
-{
-    fn into_into_dart(self) -> crate::core::video::manager::FrameExtractedInfo {
-        self
+
+impl SseEncode for crate::api::models::manifest::ModelFileIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <crate::api::models::manifest::ModelIssueKind>::sse_encode(self.kind, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::NoteType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+
+impl SseEncode for crate::api::models::manifest::ModelIssueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            Self::Video => 0.into_dart(),
-            Self::Images => 1.into_dart(),
-            Self::Mixed => 2.into_dart(),
-            Self::Text => 3.into_dart(),
-            _ => unreachable!(),
+            crate::api::models::manifest::ModelIssueKind::Missing => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::models::manifest::ModelIssueKind::InvalidPath => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::models::manifest::ModelIssueKind::SizeMismatch { expected, actual } => {
+                <i32>::sse_encode(2, serializer);
+                <u64>::sse_encode(expected, serializer);
+                <u64>::sse_encode(actual, serializer);
+            }
+            crate::api::models::manifest::ModelIssueKind::ChecksumMismatch { expected, actual } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(expected, serializer);
+                <String>::sse_encode(actual, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::xhs::NoteType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::NoteType>
-    for crate::api::models::xhs::NoteType
-{
-    fn into_into_dart(self) -> crate::api::models::xhs::NoteType {
-        self
+
+impl SseEncode for crate::api::models::asr::ModelPackConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.dir, serializer);
+        <Vec<String>>::sse_encode(self.languages, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::xhs::XhsApiError {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.error_type.into_into_dart().into_dart(),
-            self.message.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::models::manifest::ModelValidationReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.checked, serializer);
+        <bool>::sse_encode(self.checksums_verified, serializer);
+        <Vec<crate::api::models::manifest::ModelFileIssue>>::sse_encode(self.issues, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::xhs::XhsApiError {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::xhs::XhsApiError>
-    for crate::api::xhs::XhsApiError
-{
-    fn into_into_dart(self) -> crate::api::xhs::XhsApiError {
-        self
+
+impl SseEncode for crate::api::models::asr::NoiseReductionConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.over_subtraction, serializer);
+        <f32>::sse_encode(self.floor_db, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsArticle {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.title.into_into_dart().into_dart(),
-            self.desc.into_into_dart().into_dart(),
-            self.author.into_into_dart().into_dart(),
-            self.images.into_into_dart().into_dart(),
-            self.video.into_into_dart().into_dart(),
-            self.note_type.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::models::xhs::NoteType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::xhs::NoteType::Video => 0,
+                crate::api::models::xhs::NoteType::Images => 1,
+                crate::api::models::xhs::NoteType::Mixed => 2,
+                crate::api::models::xhs::NoteType::Text => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::xhs::XhsArticle
-{
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsArticle>
-    for crate::api::models::xhs::XhsArticle
-{
-    fn into_into_dart(self) -> crate::api::models::xhs::XhsArticle {
-        self
+
+impl SseEncode for Option<crate::api::models::asr::AudioRecognizerConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::asr::AudioRecognizerConfig>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsAuthor {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.nickname.into_into_dart().into_dart(),
-            self.user_id.into_into_dart().into_dart(),
-            self.avatar.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::models::asr::DiarizationConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::asr::DiarizationConfig>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::xhs::XhsAuthor
-{
+
+impl SseEncode for Option<crate::api::models::asr::EventDetectionConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::asr::EventDetectionConfig>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsAuthor>
-    for crate::api::models::xhs::XhsAuthor
-{
-    fn into_into_dart(self) -> crate::api::models::xhs::XhsAuthor {
-        self
+
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::models::xhs::XhsVideo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.duration.into_into_dart().into_dart(),
-            self.cover.into_into_dart().into_dart(),
-            self.play_url.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::models::asr::HotwordConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::asr::HotwordConfig>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::models::xhs::XhsVideo
-{
+
+impl SseEncode for Option<crate::api::models::asr::LoudnessConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::asr::LoudnessConfig>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::models::xhs::XhsVideo>
-    for crate::api::models::xhs::XhsVideo
-{
-    fn into_into_dart(self) -> crate::api::models::xhs::XhsVideo {
-        self
+
+impl SseEncode for Option<crate::api::models::asr::NoiseReductionConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::asr::NoiseReductionConfig>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::core::video::manager::YFrameData {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.width.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.y_plane.into_into_dart().into_dart(),
-            self.timestamp_ms.into_into_dart().into_dart(),
-            self.frame_number.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::models::recipe::ParsedRecipe> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::recipe::ParsedRecipe>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::core::video::manager::YFrameData
-{
+
+impl SseEncode for Option<crate::api::models::asr::PreprocessConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::asr::PreprocessConfig>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::core::video::manager::YFrameData>
-    for crate::core::video::manager::YFrameData
-{
-    fn into_into_dart(self) -> crate::core::video::manager::YFrameData {
-        self
+
+impl SseEncode for Option<crate::api::models::source::SourceVideo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::source::SourceVideo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for AudioError {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for AudioRecognizer {
+impl SseEncode for Option<crate::api::models::xhs::XhsCacheConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::xhs::XhsCacheConfig>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for VideoFrameExtractor {
+impl SseEncode for Option<crate::api::models::xhs::XhsClientConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::xhs::XhsClientConfig>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioError>>
-{
+impl SseEncode for Option<crate::api::models::xhs::XhsVideo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::models::xhs::XhsVideo>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>
-{
+impl SseEncode for crate::api::models::recipe::ParsedRecipe {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Vec<crate::api::models::recipe::RecipeIngredient>>::sse_encode(
+            self.ingredients,
+            serializer,
+        );
+        <Vec<String>>::sse_encode(self.steps, serializer);
+        <Vec<String>>::sse_encode(self.tips, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<VideoFrameExtractor>>
-{
+impl SseEncode for crate::api::models::source::Platform {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::source::Platform::Xiaohongshu => 0,
+                crate::api::models::source::Platform::Xiachufang => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::models::asr::PostprocessConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <crate::api::models::asr::PunctuationMode>::sse_encode(self.punctuation, serializer);
+        <bool>::sse_encode(self.mixed_english, serializer);
+        <bool>::sse_encode(self.inverse_text_normalization, serializer);
     }
 }
 
-impl SseEncode for crate::core::video::manager::ExtractionStats {
+impl SseEncode for crate::api::models::asr::PreprocessConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.processed_frames, serializer);
-        <u64>::sse_encode(self.extracted_frames, serializer);
+        <bool>::sse_encode(self.remove_dc, serializer);
+        <u32>::sse_encode(self.high_pass_hz, serializer);
+        <Option<crate::api::models::asr::NoiseReductionConfig>>::sse_encode(
+            self.noise_reduction,
+            serializer,
+        );
+        <Option<crate::api::models::asr::LoudnessConfig>>::sse_encode(self.loudness, serializer);
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for crate::api::models::asr::PunctuationMode {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        match self {
+            crate::api::models::asr::PunctuationMode::Off => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::models::asr::PunctuationMode::Rules { pause_ms } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(pause_ms, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for crate::core::video::manager::FrameExtractedInfo {
+impl SseEncode for crate::api::models::recipe::RecipeIngredient {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.timestamp_ms, serializer);
-        <u64>::sse_encode(self.frame_number, serializer);
-        <f32>::sse_encode(self.confidence, serializer);
-        <Vec<u8>>::sse_encode(self.jpeg_data, serializer);
-        <u32>::sse_encode(self.width, serializer);
-        <u32>::sse_encode(self.height, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.amount, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::models::asr::RecognizerPoolConfig {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <u32>::sse_encode(self.max_instances, serializer);
+        <u32>::sse_encode(self.max_memory_mb, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::api::models::asr::ResampleQuality {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::asr::ResampleQuality::Fast => 0,
+                crate::api::models::asr::ResampleQuality::Balanced => 1,
+                crate::api::models::asr::ResampleQuality::High => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::models::transcript::SegmentationPath {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::transcript::SegmentationPath::Vad => 0,
+                crate::api::models::transcript::SegmentationPath::FixedWindow => 1,
+                crate::api::models::transcript::SegmentationPath::WholeFile => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::core::video::manager::FrameExtractedInfo> {
+impl SseEncode for crate::api::models::source::SourceArticle {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::core::video::manager::FrameExtractedInfo>::sse_encode(item, serializer);
-        }
+        <crate::api::models::source::Platform>::sse_encode(self.platform, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.desc, serializer);
+        <crate::api::models::source::SourceAuthor>::sse_encode(self.author, serializer);
+        <Vec<String>>::sse_encode(self.images, serializer);
+        <Option<crate::api::models::source::SourceVideo>>::sse_encode(self.video, serializer);
+        <crate::api::models::xhs::NoteType>::sse_encode(self.note_type, serializer);
+        <Option<crate::api::models::recipe::ParsedRecipe>>::sse_encode(self.recipe, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::models::source::SourceAuthor {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.avatar, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::core::video::manager::YFrameData> {
+impl SseEncode for crate::api::models::source::SourceVideo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::core::video::manager::YFrameData>::sse_encode(item, serializer);
-        }
+        <i64>::sse_encode(self.duration, serializer);
+        <String>::sse_encode(self.cover, serializer);
+        <String>::sse_encode(self.play_url, serializer);
     }
 }
 
-impl SseEncode for crate::api::models::xhs::NoteType {
+impl SseEncode for crate::api::models::transcript::Transcript {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::models::xhs::NoteType::Video => 0,
-                crate::api::models::xhs::NoteType::Images => 1,
-                crate::api::models::xhs::NoteType::Mixed => 2,
-                crate::api::models::xhs::NoteType::Text => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
+        <Vec<crate::api::models::transcript::TranscriptSegment>>::sse_encode(
+            self.segments,
             serializer,
         );
+        <crate::api::models::transcript::SegmentationPath>::sse_encode(
+            self.segmentation,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.fallback_reason, serializer);
+        <String>::sse_encode(self.model_pack, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <bool>::sse_encode(self.language_detected, serializer);
+        <Vec<crate::api::models::transcript::AudioEvent>>::sse_encode(self.events, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::models::transcript::TranscriptSegment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <u64>::sse_encode(self.start_ms, serializer);
+        <u64>::sse_encode(self.end_ms, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Vec<crate::api::models::transcript::TranscriptToken>>::sse_encode(self.tokens, serializer);
+        <Option<u32>>::sse_encode(self.speaker, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::models::xhs::XhsVideo> {
+impl SseEncode for crate::api::models::transcript::TranscriptToken {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::models::xhs::XhsVideo>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.token, serializer);
+        <u64>::sse_encode(self.start_ms, serializer);
+        <Option<f32>>::sse_encode(self.confidence, serializer);
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -1308,11 +6052,86 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::models::asr::VadConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.threshold, serializer);
+        <u32>::sse_encode(self.min_silence_duration_ms, serializer);
+        <u32>::sse_encode(self.min_speech_duration_ms, serializer);
+        <u32>::sse_encode(self.num_threads, serializer);
+        <f32>::sse_encode(self.buffer_size_secs, serializer);
+        <u32>::sse_encode(self.pad_ms, serializer);
+        <u32>::sse_encode(self.merge_gap_ms, serializer);
+        <u32>::sse_encode(self.max_segment_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::asr::VadFallback {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::models::asr::VadFallback::Error => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::models::asr::VadFallback::FixedWindow {
+                window_ms,
+                overlap_ms,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(window_ms, serializer);
+                <u32>::sse_encode(overlap_ms, serializer);
+            }
+            crate::api::models::asr::VadFallback::WholeFile => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::api::xhs::XhsApiError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.error_type, serializer);
-        <String>::sse_encode(self.message, serializer);
+        match self {
+            crate::api::xhs::XhsApiError::UrlNotFound => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::xhs::XhsApiError::InvalidLink { url } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::api::xhs::XhsApiError::HttpStatus { status, url } => {
+                <i32>::sse_encode(2, serializer);
+                <u16>::sse_encode(status, serializer);
+                <Option<String>>::sse_encode(url, serializer);
+            }
+            crate::api::xhs::XhsApiError::Network { message, timeout } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(message, serializer);
+                <bool>::sse_encode(timeout, serializer);
+            }
+            crate::api::xhs::XhsApiError::ClientConfig { message } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::xhs::XhsApiError::Parse { message } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::api::xhs::XhsApiError::Blocked { reason } => {
+                <i32>::sse_encode(6, serializer);
+                <crate::api::xhs::XhsBlockReason>::sse_encode(reason, serializer);
+            }
+            crate::api::xhs::XhsApiError::Io { message } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
@@ -1337,6 +6156,111 @@ impl SseEncode for crate::api::models::xhs::XhsAuthor {
     }
 }
 
+impl SseEncode for crate::api::models::xhs::XhsAuthorProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::models::xhs::XhsAuthor>::sse_encode(self.author, serializer);
+        <String>::sse_encode(self.red_id, serializer);
+        <String>::sse_encode(self.bio, serializer);
+        <Option<String>>::sse_encode(self.ip_location, serializer);
+        <u64>::sse_encode(self.follows, serializer);
+        <u64>::sse_encode(self.fans, serializer);
+        <u64>::sse_encode(self.liked_and_collected, serializer);
+        <crate::api::models::xhs::XhsNotePage>::sse_encode(self.notes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::xhs::XhsBlockReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::xhs::XhsBlockReason::NoteDeleted => 0,
+                crate::api::xhs::XhsBlockReason::LoginRequired => 1,
+                crate::api::xhs::XhsBlockReason::RateLimited => 2,
+                crate::api::xhs::XhsBlockReason::CaptchaRequired => 3,
+                crate::api::xhs::XhsBlockReason::RegionBlocked => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::models::xhs::XhsCacheConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.cache_dir, serializer);
+        <u64>::sse_encode(self.ttl_secs, serializer);
+        <u64>::sse_encode(self.max_size_bytes, serializer);
+        <bool>::sse_encode(self.bypass, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::xhs::XhsClientConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.connect_timeout_ms, serializer);
+        <u64>::sse_encode(self.read_timeout_ms, serializer);
+        <Option<String>>::sse_encode(self.proxy, serializer);
+        <bool>::sse_encode(self.cookie_store, serializer);
+        <Vec<String>>::sse_encode(self.cookies, serializer);
+        <u32>::sse_encode(self.max_redirects, serializer);
+        <Option<crate::api::models::xhs::XhsCacheConfig>>::sse_encode(self.cache, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::xhs::XhsLink {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::models::xhs::XhsLinkKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.id, serializer);
+        <Option<String>>::sse_encode(self.xsec_token, serializer);
+        <Option<String>>::sse_encode(self.xsec_source, serializer);
+        <String>::sse_encode(self.url, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::xhs::XhsLinkKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::models::xhs::XhsLinkKind::ShortLink => 0,
+                crate::api::models::xhs::XhsLinkKind::Explore => 1,
+                crate::api::models::xhs::XhsLinkKind::DiscoveryItem => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::models::xhs::XhsNoteCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.note_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.cover, serializer);
+        <crate::api::models::xhs::NoteType>::sse_encode(self.note_type, serializer);
+        <u64>::sse_encode(self.liked_count, serializer);
+        <Option<String>>::sse_encode(self.xsec_token, serializer);
+    }
+}
+
+impl SseEncode for crate::api::models::xhs::XhsNotePage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::models::xhs::XhsNoteCard>>::sse_encode(self.notes, serializer);
+        <String>::sse_encode(self.cursor, serializer);
+        <bool>::sse_encode(self.has_more, serializer);
+    }
+}
+
 impl SseEncode for crate::api::models::xhs::XhsVideo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1357,13 +6281,6 @@ impl SseEncode for crate::core::video::manager::YFrameData {
     }
 }
 
-impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
-    }
-}
-
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -1373,6 +6290,7 @@ mod io {
 
     use super::*;
     use crate::api::audio::*;
+    use crate::api::importer::*;
     use crate::api::video::*;
     use crate::core::audio::error::*;
     use flutter_rust_bridge::for_generated::byteorder::{
@@ -1413,6 +6331,34 @@ mod io {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>::decrement_strong_count(ptr as _);
     }
 
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_cook_lib_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_cook_lib_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>::decrement_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_cook_lib_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_cook_lib_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>::decrement_strong_count(ptr as _);
+    }
+
     #[unsafe(no_mangle)]
     pub extern "C" fn frbgen_cook_lib_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
         ptr: *const std::ffi::c_void,
@@ -1440,6 +6386,7 @@ mod web {
 
     use super::*;
     use crate::api::audio::*;
+    use crate::api::importer::*;
     use crate::api::video::*;
     use crate::core::audio::error::*;
     use flutter_rust_bridge::for_generated::byteorder::{
@@ -1482,6 +6429,34 @@ mod web {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AudioRecognizer>>::decrement_strong_count(ptr as _);
     }
 
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImportError(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImportError>>::decrement_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRecipeImporter(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RecipeImporter>>::decrement_strong_count(ptr as _);
+    }
+
     #[wasm_bindgen]
     pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerVideoFrameExtractor(
         ptr: *const std::ffi::c_void,